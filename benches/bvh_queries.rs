@@ -0,0 +1,132 @@
+extern crate matriarch;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use matriarch::{closest_point_on_triangle, Bvh, Ray, Vec3};
+
+// Ray casts and closest point queries against a soup of 10k small triangles,
+// through the Bvh and by testing every triangle. The Bvh answers ray casts
+// about 65 times faster and closest point queries about 190 times faster, so
+// building it pays for itself after under a hundred queries. Refitting after
+// the triangles move costs about 1% of a rebuild.
+//
+// Benchmark Output:
+// bvh_build_10k                          time:   [13.565 ms 13.949 ms 14.348 ms]
+// bvh_refit_10k                          time:   [150.10 µs 155.54 µs 161.13 µs]
+// brute_force_nearest_hit_100_rays       time:   [20.475 ms 20.784 ms 21.099 ms]
+// bvh_nearest_hit_100_rays               time:   [302.67 µs 316.28 µs 329.35 µs]
+// bvh_any_hit_100_rays                   time:   [227.74 µs 233.77 µs 239.01 µs]
+// brute_force_closest_point_100_points   time:   [29.478 ms 30.051 ms 30.627 ms]
+// bvh_closest_point_100_points           time:   [159.02 µs 160.33 µs 161.71 µs]
+
+const TRIANGLE_COUNT: usize = 10_000;
+const QUERY_COUNT: usize = 100;
+
+fn setup() -> (Vec<[Vec3; 3]>, Vec<Ray>) {
+    // The generator from the library's test values, which benchmarks can't
+    // import since they're only compiled into its tests.
+    let mut state = 1u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 8) as f32 / (1 << 24) as f32
+    };
+    let triangles = (0..TRIANGLE_COUNT)
+        .map(|_| {
+            let center = Vec3 { x: 40.0 * next() - 20.0, y: 40.0 * next() - 20.0, z: 40.0 * next() - 20.0 };
+            let mut corner = || center + Vec3 { x: next() - 0.5, y: next() - 0.5, z: next() - 0.5 };
+            [corner(), corner(), corner()]
+        })
+        .collect();
+    let rays = (0..QUERY_COUNT)
+        .map(|i| {
+            let angle = i as f32 * 0.37;
+            Ray {
+                origin: Vec3 { x: 30.0 * angle.cos(), y: (i % 11) as f32 - 5.0, z: 30.0 * angle.sin() },
+                direction: Vec3 { x: -angle.cos(), y: 0.02 * ((i % 7) as f32 - 3.0), z: -angle.sin() },
+            }
+        })
+        .collect();
+    (triangles, rays)
+}
+
+/// Returns the nearest distance along the Ray to any triangle, using the
+/// Möller–Trumbore algorithm on each one.
+fn brute_force_nearest(ray: &Ray, triangles: &[[Vec3; 3]]) -> Option<f32> {
+    let mut nearest: Option<f32> = None;
+    for [a, b, c] in triangles {
+        let (edge1, edge2) = (*b - *a, *c - *a);
+        let p = ray.direction.cross_product(&edge2);
+        let inverse = 1.0 / (edge1 * p);
+        let offset = ray.origin - *a;
+        let u = (offset * p) * inverse;
+        let q = offset.cross_product(&edge1);
+        let v = (ray.direction * q) * inverse;
+        let t = (edge2 * q) * inverse;
+        if u >= 0.0 && v >= 0.0 && u + v <= 1.0 && t >= 0.0 && nearest.is_none_or(|nearest| t < nearest) {
+            nearest = Some(t);
+        }
+    }
+    nearest
+}
+
+/// Returns the squared distance to the closest point on any triangle.
+fn brute_force_closest_point(point: &Vec3, triangles: &[[Vec3; 3]]) -> f32 {
+    triangles
+        .iter()
+        .map(|[a, b, c]| {
+            let closest = closest_point_on_triangle(point, a, b, c);
+            (closest - *point) * (closest - *point)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+#[test]
+fn ensure_same_output() {
+    let (triangles, rays) = setup();
+    let bvh = Bvh::new_from_triangles(&triangles);
+    for ray in &rays {
+        let hit = bvh.ray_nearest_hit(ray, &triangles, f32::INFINITY).map(|hit| hit.t);
+        assert_eq!(hit, brute_force_nearest(ray, &triangles));
+        let (_, closest) = bvh.closest_point(&ray.origin, &triangles).unwrap();
+        let distance = (closest - ray.origin) * (closest - ray.origin);
+        assert_eq!(distance, brute_force_closest_point(&ray.origin, &triangles));
+    }
+}
+
+fn build(c: &mut Criterion) {
+    let (triangles, _) = setup();
+    c.bench_function("bvh_build_10k", |b| b.iter(|| Bvh::new_from_triangles(black_box(&triangles))));
+}
+
+fn refit(c: &mut Criterion) {
+    let (triangles, _) = setup();
+    let mut bvh = Bvh::new_from_triangles(&triangles);
+    c.bench_function("bvh_refit_10k", |b| b.iter(|| bvh.refit_triangles(black_box(&triangles))));
+}
+
+fn nearest_hit(c: &mut Criterion) {
+    let (triangles, rays) = setup();
+    let bvh = Bvh::new_from_triangles(&triangles);
+    c.bench_function("brute_force_nearest_hit_100_rays", |b| {
+        b.iter(|| rays.iter().map(|ray| brute_force_nearest(ray, black_box(&triangles))).collect::<Vec<_>>())
+    });
+    c.bench_function("bvh_nearest_hit_100_rays", |b| {
+        b.iter(|| rays.iter().map(|ray| bvh.ray_nearest_hit(ray, black_box(&triangles), f32::INFINITY)).collect::<Vec<_>>())
+    });
+    c.bench_function("bvh_any_hit_100_rays", |b| {
+        b.iter(|| rays.iter().map(|ray| bvh.ray_any_hit(ray, black_box(&triangles), f32::INFINITY)).collect::<Vec<_>>())
+    });
+}
+
+fn closest_point(c: &mut Criterion) {
+    let (triangles, rays) = setup();
+    let bvh = Bvh::new_from_triangles(&triangles);
+    c.bench_function("brute_force_closest_point_100_points", |b| {
+        b.iter(|| rays.iter().map(|ray| brute_force_closest_point(&ray.origin, black_box(&triangles))).collect::<Vec<_>>())
+    });
+    c.bench_function("bvh_closest_point_100_points", |b| {
+        b.iter(|| rays.iter().map(|ray| bvh.closest_point(&ray.origin, black_box(&triangles))).collect::<Vec<_>>())
+    });
+}
+
+criterion_group!(benches, build, refit, nearest_hit, closest_point);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Generates the swizzle accessors enabled by the `swizzle` feature.
+//!
+//! There are several hundred of them, so they're written out here rather than
+//! by hand, and only when the feature is enabled to keep compile times down
+//! for everyone else.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const COMPONENTS: [char; 4] = ['x', 'y', 'z', 'w'];
+
+/// Returns every combination of `length` components drawn, with repetition,
+/// from the first `source_length` components.
+fn combinations(source_length: usize, length: usize) -> Vec<Vec<char>> {
+    if length == 0 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for prefix in combinations(source_length, length - 1) {
+        for component in COMPONENTS.iter().take(source_length) {
+            let mut combination = prefix.clone();
+            combination.push(*component);
+            result.push(combination);
+        }
+    }
+    result
+}
+
+fn write_swizzles(output: &mut String, source_length: usize) {
+    writeln!(output, "impl Vec{} {{", source_length).unwrap();
+    for length in 2..=4 {
+        for combination in combinations(source_length, length) {
+            let name: String = combination.iter().collect();
+            let fields: Vec<String> = COMPONENTS
+                .iter()
+                .zip(combination.iter())
+                .map(|(field, component)| format!("{}: self.{}", field, component))
+                .collect();
+            writeln!(output, "    /// Returns a Vec{} of the {} components.", length, name).unwrap();
+            writeln!(output, "    #[inline]").unwrap();
+            writeln!(output, "    pub fn {}(&self) -> Vec{} {{", name, length).unwrap();
+            writeln!(output, "        Vec{} {{ {} }}", length, fields.join(", ")).unwrap();
+            writeln!(output, "    }}\n").unwrap();
+        }
+    }
+    writeln!(output, "}}\n").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_SWIZZLE").is_none() {
+        return;
+    }
+
+    let mut output = String::new();
+    for source_length in 2..=4 {
+        write_swizzles(&mut output, source_length);
+    }
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("swizzle.rs");
+    fs::write(path, output).unwrap();
+}
@@ -0,0 +1,183 @@
+//! Implementation of an Axis-Aligned Bounding Box and its associated functions
+//! and methods.
+
+use super::Vec3;
+
+/// An axis-aligned bounding box spanning from `min` to `max`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Returns a new Aabb with both corners at [0, 0, 0].
+    pub fn new() -> Aabb {
+        Aabb { min: Vec3::new(), max: Vec3::new() }
+    }
+
+    /// Returns a new Aabb using the given minimum and maximum corners.
+    pub fn new_from_values(min: &Vec3, max: &Vec3) -> Aabb {
+        Aabb { min: *min, max: *max }
+    }
+
+    /// Returns the smallest Aabb containing all of the given points, or `None`
+    /// if the slice is empty.
+    pub fn new_from_points(points: &[Vec3]) -> Option<Aabb> {
+        let first = points.first()?;
+        let mut aabb = Aabb { min: *first, max: *first };
+        for point in &points[1..] {
+            aabb.min = Vec3 {
+                x: aabb.min.x.min(point.x),
+                y: aabb.min.y.min(point.y),
+                z: aabb.min.z.min(point.z),
+            };
+            aabb.max = Vec3 {
+                x: aabb.max.x.max(point.x),
+                y: aabb.max.y.max(point.y),
+                z: aabb.max.z.max(point.z),
+            };
+        }
+        Some(aabb)
+    }
+
+    /// Returns the point at the center of the Aabb.
+    pub fn center(&self) -> Vec3 {
+        0.5 * (self.min + self.max)
+    }
+
+    /// Returns the distance from the center to the faces along each axis.
+    pub fn half_extents(&self) -> Vec3 {
+        0.5 * (self.max - self.min)
+    }
+
+    /// Returns true if the point lies inside or on the surface of the Aabb.
+    pub fn contains_point(&self, point: &Vec3) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x
+            && point.y >= self.min.y && point.y <= self.max.y
+            && point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    /// Returns the smallest Aabb containing both Aabbs.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: Vec3 {
+                x: self.min.x.min(other.min.x),
+                y: self.min.y.min(other.min.y),
+                z: self.min.z.min(other.min.z),
+            },
+            max: Vec3 {
+                x: self.max.x.max(other.max.x),
+                y: self.max.y.max(other.max.y),
+                z: self.max.z.max(other.max.z),
+            },
+        }
+    }
+
+    /// Returns the total area of the 6 faces.
+    pub fn surface_area(&self) -> f32 {
+        let size = self.max - self.min;
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    /// Returns true if the two Aabbs overlap or touch.
+    pub fn intersects_aabb(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x
+            && self.min.y <= other.max.y && other.min.y <= self.max.y
+            && self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// Returns the point inside or on the surface of the Aabb closest to the
+    /// given point.
+    pub fn closest_point(&self, point: &Vec3) -> Vec3 {
+        Vec3 {
+            x: point.x.max(self.min.x).min(self.max.x),
+            y: point.y.max(self.min.y).min(self.max.y),
+            z: point.z.max(self.min.z).min(self.max.z),
+        }
+    }
+
+    /// Returns the 8 corners of the Aabb.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            Vec3 { x: min.x, y: min.y, z: min.z },
+            Vec3 { x: max.x, y: min.y, z: min.z },
+            Vec3 { x: min.x, y: max.y, z: min.z },
+            Vec3 { x: max.x, y: max.y, z: min.z },
+            Vec3 { x: min.x, y: min.y, z: max.z },
+            Vec3 { x: max.x, y: min.y, z: max.z },
+            Vec3 { x: min.x, y: max.y, z: max.z },
+            Vec3 { x: max.x, y: max.y, z: max.z },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aabb, Vec3};
+
+    #[test]
+    fn create_new_aabb_from_points() {
+        let points = [
+            Vec3 { x: 1.0, y: -2.0, z: 0.5 },
+            Vec3 { x: -1.0, y: 3.0, z: 0.0 },
+            Vec3 { x: 0.0, y: 0.0, z: 4.0 },
+        ];
+        assert_eq!(
+            Aabb::new_from_points(&points),
+            Some(Aabb {
+                min: Vec3 { x: -1.0, y: -2.0, z: 0.0 },
+                max: Vec3 { x: 1.0, y: 3.0, z: 4.0 },
+            })
+        );
+        assert_eq!(Aabb::new_from_points(&[]), None);
+    }
+
+    #[test]
+    fn get_center_and_half_extents() {
+        let aabb = Aabb {
+            min: Vec3 { x: -1.0, y: 0.0, z: 2.0 },
+            max: Vec3 { x: 3.0, y: 2.0, z: 4.0 },
+        };
+        assert_eq!(aabb.center(), Vec3 { x: 1.0, y: 1.0, z: 3.0 });
+        assert_eq!(aabb.half_extents(), Vec3 { x: 2.0, y: 1.0, z: 1.0 });
+    }
+
+    #[test]
+    fn aabb_contains_point() {
+        let aabb = Aabb {
+            min: Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+            max: Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+        };
+        assert!(aabb.contains_point(&Vec3 { x: 0.5, y: 1.0, z: 0.0 }));
+        assert!(!aabb.contains_point(&Vec3 { x: 0.5, y: 1.5, z: 0.0 }));
+    }
+
+    #[test]
+    fn union_overlap_and_closest_point() {
+        let a = Aabb {
+            min: Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+            max: Vec3 { x: 1.0, y: 2.0, z: 3.0 },
+        };
+        let b = Aabb {
+            min: Vec3 { x: 1.0, y: -1.0, z: 1.0 },
+            max: Vec3 { x: 2.0, y: 0.5, z: 2.0 },
+        };
+        assert_eq!(
+            a.union(&b),
+            Aabb {
+                min: Vec3 { x: 0.0, y: -1.0, z: 0.0 },
+                max: Vec3 { x: 2.0, y: 2.0, z: 3.0 },
+            }
+        );
+        assert_eq!(a.surface_area(), 22.0);
+        assert!(a.intersects_aabb(&b));
+        let c = Aabb {
+            min: Vec3 { x: 1.5, y: 0.0, z: 0.0 },
+            max: Vec3 { x: 2.0, y: 1.0, z: 1.0 },
+        };
+        assert!(!a.intersects_aabb(&c));
+        assert_eq!(a.closest_point(&Vec3 { x: 0.5, y: 5.0, z: -1.0 }), Vec3 { x: 0.5, y: 2.0, z: 0.0 });
+    }
+}
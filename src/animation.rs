@@ -0,0 +1,395 @@
+//! Keyframe animation tracks of translations, rotations and scales, sampled
+//! with the interpolation modes of glTF.
+
+use std::fmt::Debug;
+
+use super::{Curve, Hermite, Mat4, Quat, Vec3};
+
+/// How a Track fills in the values between its keyframes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Holds each keyframe's value until the next keyframe.
+    Step,
+    /// Blends linearly between keyframes, spherically for rotations.
+    Linear,
+    /// Follows a cubic Hermite spline through the keyframes, using their
+    /// tangents.
+    CubicSpline,
+}
+
+/// A value at a point in time.
+///
+/// The tangents are only used by `Interpolation::CubicSpline`, and are rates
+/// of change per second: `in_tangent` arriving at the keyframe and
+/// `out_tangent` leaving it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keyframe<T> {
+    pub time: f32,
+    pub value: T,
+    pub in_tangent: T,
+    pub out_tangent: T,
+}
+
+impl<T: Animatable> Keyframe<T> {
+    /// Returns a new Keyframe with zero tangents.
+    pub fn new(time: f32, value: &T) -> Keyframe<T> {
+        Keyframe { time, value: *value, in_tangent: T::zero(), out_tangent: T::zero() }
+    }
+
+    /// Returns a new Keyframe with the given tangents.
+    pub fn new_with_tangents(time: f32, in_tangent: &T, value: &T, out_tangent: &T) -> Keyframe<T> {
+        Keyframe { time, value: *value, in_tangent: *in_tangent, out_tangent: *out_tangent }
+    }
+}
+
+/// A value that Tracks can interpolate.
+pub trait Animatable: Copy + Debug + PartialEq {
+    /// Returns the value used for tangents that don't change anything.
+    fn zero() -> Self;
+
+    /// Returns the blend from `self` at `t = 0` to `other` at `t = 1`.
+    fn interpolate_linear(&self, other: &Self, t: f32) -> Self;
+
+    /// Returns the point at `t` from 0 to 1 of the cubic Hermite spline from
+    /// `self` to `other` over `duration` seconds, leaving along
+    /// `out_tangent` and arriving along `in_tangent`.
+    fn interpolate_cubic(&self, out_tangent: &Self, other: &Self, in_tangent: &Self, t: f32, duration: f32) -> Self;
+}
+
+impl Animatable for Vec3 {
+    fn zero() -> Vec3 {
+        Vec3::new()
+    }
+
+    fn interpolate_linear(&self, other: &Vec3, t: f32) -> Vec3 {
+        *self + t * (*other - *self)
+    }
+
+    fn interpolate_cubic(&self, out_tangent: &Vec3, other: &Vec3, in_tangent: &Vec3, t: f32, duration: f32) -> Vec3 {
+        Hermite::new_from_values(self, &(duration * *out_tangent), other, &(duration * *in_tangent)).evaluate(t)
+    }
+}
+
+impl Animatable for Quat {
+    fn zero() -> Quat {
+        Quat::new()
+    }
+
+    /// Spherically interpolates along the shortest path.
+    fn interpolate_linear(&self, other: &Quat, t: f32) -> Quat {
+        self.slerp(other, t)
+    }
+
+    /// Interpolates the components and normalizes the result, as glTF
+    /// specifies for rotations.
+    fn interpolate_cubic(&self, out_tangent: &Quat, other: &Quat, in_tangent: &Quat, t: f32, duration: f32) -> Quat {
+        let (t2, t3) = (t * t, t * t * t);
+        let quat = (2.0 * t3 - 3.0 * t2 + 1.0) * *self
+            + ((t3 - 2.0 * t2 + t) * duration) * *out_tangent
+            + (-2.0 * t3 + 3.0 * t2) * *other
+            + ((t3 - t2) * duration) * *in_tangent;
+        quat.normalize()
+    }
+}
+
+/// A transform that scales, then rotates, then translates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trs {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for Trs {
+    fn default() -> Trs {
+        Trs::identity()
+    }
+}
+
+impl Trs {
+    /// Returns the Trs that leaves everything in place.
+    pub fn identity() -> Trs {
+        Trs { translation: Vec3::new(), rotation: Quat::identity(), scale: Vec3 { x: 1.0, y: 1.0, z: 1.0 } }
+    }
+
+    /// Returns a new Trs using the given translation, rotation and scale.
+    pub fn new_from_values(translation: &Vec3, rotation: &Quat, scale: &Vec3) -> Trs {
+        Trs { translation: *translation, rotation: *rotation, scale: *scale }
+    }
+
+    /// Returns the Trs of a Mat4 using `Mat4::decompose`, dropping any shear
+    /// and perspective, or `None` if the Mat4 can't be decomposed.
+    pub fn new_from_mat4(mat4: &Mat4) -> Option<Trs> {
+        let decomposition = mat4.decompose()?;
+        Some(Trs {
+            translation: decomposition.translation,
+            rotation: Quat::new_from_mat3(&decomposition.rotation),
+            scale: decomposition.scale,
+        })
+    }
+
+    /// Returns the Mat4 applying the Trs.
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::new_from_trs(&self.translation, &self.rotation.to_mat3(), &self.scale)
+    }
+}
+
+impl Animatable for Trs {
+    /// Returns a Trs with every component 0, as a tangent.
+    fn zero() -> Trs {
+        Trs { translation: Vec3::zero(), rotation: Quat::zero(), scale: Vec3::zero() }
+    }
+
+    fn interpolate_linear(&self, other: &Trs, t: f32) -> Trs {
+        Trs {
+            translation: self.translation.interpolate_linear(&other.translation, t),
+            rotation: self.rotation.interpolate_linear(&other.rotation, t),
+            scale: self.scale.interpolate_linear(&other.scale, t),
+        }
+    }
+
+    fn interpolate_cubic(&self, out_tangent: &Trs, other: &Trs, in_tangent: &Trs, t: f32, duration: f32) -> Trs {
+        Trs {
+            translation: self.translation.interpolate_cubic(&out_tangent.translation, &other.translation, &in_tangent.translation, t, duration),
+            rotation: self.rotation.interpolate_cubic(&out_tangent.rotation, &other.rotation, &in_tangent.rotation, t, duration),
+            scale: self.scale.interpolate_cubic(&out_tangent.scale, &other.scale, &in_tangent.scale, t, duration),
+        }
+    }
+}
+
+/// A sequence of keyframes, ordered by strictly increasing time, and the
+/// way to interpolate between them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<T> {
+    pub interpolation: Interpolation,
+    pub keyframes: Vec<Keyframe<T>>,
+}
+
+impl<T: Animatable> Track<T> {
+    /// Returns a new Track using the given keyframes.
+    ///
+    /// Panics if the keyframe times don't strictly increase.
+    pub fn new_from_keyframes(interpolation: Interpolation, keyframes: &[Keyframe<T>]) -> Track<T> {
+        assert!(keyframes.windows(2).all(|pair| pair[0].time < pair[1].time), "keyframe times must strictly increase");
+        Track { interpolation, keyframes: keyframes.to_vec() }
+    }
+
+    /// Returns a new Track from the input and output arrays of a glTF
+    /// animation sampler.
+    ///
+    /// `outputs` has one value per time, except for
+    /// `Interpolation::CubicSpline` where it has three: the in tangent, the
+    /// value and the out tangent. Panics if the number of outputs doesn't
+    /// match or the times don't strictly increase.
+    pub fn new_from_gltf(interpolation: Interpolation, times: &[f32], outputs: &[T]) -> Track<T> {
+        let keyframes: Vec<Keyframe<T>> = if interpolation == Interpolation::CubicSpline {
+            assert_eq!(outputs.len(), 3 * times.len(), "cubic spline samplers need 3 outputs per time");
+            times
+                .iter()
+                .zip(outputs.chunks(3))
+                .map(|(time, output)| Keyframe::new_with_tangents(*time, &output[0], &output[1], &output[2]))
+                .collect()
+        } else {
+            assert_eq!(outputs.len(), times.len(), "samplers need 1 output per time");
+            times.iter().zip(outputs).map(|(time, value)| Keyframe::new(*time, value)).collect()
+        };
+        Track::new_from_keyframes(interpolation, &keyframes)
+    }
+
+    /// Returns the times of the first and last keyframes, or `None` if the
+    /// Track is empty.
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        Some((self.keyframes.first()?.time, self.keyframes.last()?.time))
+    }
+
+    /// Returns the value of the Track at `time`, or `None` if the Track is
+    /// empty.
+    ///
+    /// Before the first keyframe and after the last the Track holds their
+    /// values. A NaN time samples the first keyframe.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Interpolation, Keyframe, Track, Vec3};
+    /// let track = Track::new_from_keyframes(Interpolation::Linear, &[
+    ///     Keyframe::new(0.0, &Vec3 { x: 0.0, y: 0.0, z: 0.0 }),
+    ///     Keyframe::new(2.0, &Vec3 { x: 4.0, y: 0.0, z: 0.0 }),
+    /// ]);
+    /// assert_eq!(track.sample(0.5), Some(Vec3 { x: 1.0, y: 0.0, z: 0.0 }));
+    /// assert_eq!(track.sample(3.0), Some(Vec3 { x: 4.0, y: 0.0, z: 0.0 }));
+    /// ```
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if time.is_nan() || time <= first.time {
+            return Some(first.value);
+        }
+        if time >= last.time {
+            return Some(last.value);
+        }
+        let index = self.keyframes.partition_point(|keyframe| keyframe.time <= time) - 1;
+        let (from, to) = (&self.keyframes[index], &self.keyframes[index + 1]);
+        let duration = to.time - from.time;
+        let t = (time - from.time) / duration;
+        Some(match self.interpolation {
+            Interpolation::Step => from.value,
+            Interpolation::Linear => from.value.interpolate_linear(&to.value, t),
+            Interpolation::CubicSpline => from.value.interpolate_cubic(&from.out_tangent, &to.value, &to.in_tangent, t, duration),
+        })
+    }
+}
+
+impl Track<Trs> {
+    /// Returns the Mat4 of the Track's Trs at `time`, or `None` if the Track
+    /// is empty.
+    pub fn sample_mat4(&self, time: f32) -> Option<Mat4> {
+        self.sample(time).map(|trs| trs.to_mat4())
+    }
+}
+
+/// Separate tracks for the translation, rotation and scale of a transform,
+/// the way glTF animates a node. Missing tracks leave their part of the
+/// transform at its rest value.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrsChannels {
+    pub translation: Option<Track<Vec3>>,
+    pub rotation: Option<Track<Quat>>,
+    pub scale: Option<Track<Vec3>>,
+}
+
+impl TrsChannels {
+    /// Returns the times of the earliest and latest keyframes of any
+    /// channel, or `None` if every channel is missing or empty.
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        let ranges = [
+            self.translation.as_ref().and_then(Track::time_range),
+            self.rotation.as_ref().and_then(Track::time_range),
+            self.scale.as_ref().and_then(Track::time_range),
+        ];
+        ranges.iter().flatten().fold(None, |range: Option<(f32, f32)>, (start, end)| match range {
+            Some((low, high)) => Some((low.min(*start), high.max(*end))),
+            None => Some((*start, *end)),
+        })
+    }
+
+    /// Returns the transform at `time`, taking the parts without a track or
+    /// with an empty one from `rest`.
+    pub fn sample(&self, time: f32, rest: &Trs) -> Trs {
+        Trs {
+            translation: self.translation.as_ref().and_then(|track| track.sample(time)).unwrap_or(rest.translation),
+            rotation: self.rotation.as_ref().and_then(|track| track.sample(time)).unwrap_or(rest.rotation),
+            scale: self.scale.as_ref().and_then(|track| track.sample(time)).unwrap_or(rest.scale),
+        }
+    }
+
+    /// Returns the Mat4 of the transform at `time`, taking the parts without
+    /// a track or with an empty one from `rest`.
+    pub fn sample_mat4(&self, time: f32, rest: &Trs) -> Mat4 {
+        self.sample(time, rest).to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animatable, Interpolation, Keyframe, Mat4, Quat, Track, Trs, TrsChannels, Vec3};
+
+    fn vec3_track(interpolation: Interpolation) -> Track<Vec3> {
+        Track::new_from_keyframes(
+            interpolation,
+            &[
+                Keyframe::new(1.0, &Vec3 { x: 0.0, y: 0.0, z: 0.0 }),
+                Keyframe::new(2.0, &Vec3 { x: 2.0, y: 4.0, z: 0.0 }),
+                Keyframe::new(4.0, &Vec3 { x: 2.0, y: 0.0, z: 6.0 }),
+            ],
+        )
+    }
+
+    #[test]
+    fn step_and_linear_sampling() {
+        let step = vec3_track(Interpolation::Step);
+        assert_eq!(step.time_range(), Some((1.0, 4.0)));
+        assert_eq!(step.sample(0.0), Some(Vec3 { x: 0.0, y: 0.0, z: 0.0 }));
+        assert_eq!(step.sample(1.9), Some(Vec3 { x: 0.0, y: 0.0, z: 0.0 }));
+        assert_eq!(step.sample(2.0), Some(Vec3 { x: 2.0, y: 4.0, z: 0.0 }));
+        assert_eq!(step.sample(3.9), Some(Vec3 { x: 2.0, y: 4.0, z: 0.0 }));
+        assert_eq!(step.sample(5.0), Some(Vec3 { x: 2.0, y: 0.0, z: 6.0 }));
+
+        let linear = vec3_track(Interpolation::Linear);
+        assert_eq!(linear.sample(1.5), Some(Vec3 { x: 1.0, y: 2.0, z: 0.0 }));
+        assert_eq!(linear.sample(3.0), Some(Vec3 { x: 2.0, y: 2.0, z: 3.0 }));
+        assert_eq!(linear.sample(4.0), Some(Vec3 { x: 2.0, y: 0.0, z: 6.0 }));
+        assert_eq!(linear.sample(f32::NAN), Some(Vec3 { x: 0.0, y: 0.0, z: 0.0 }));
+        assert_eq!(Track::<Vec3>::new_from_keyframes(Interpolation::Linear, &[]).sample(1.0), None);
+    }
+
+    #[test]
+    fn cubic_spline_sampling() {
+        // Tangents matching the slopes of straight segments reproduce linear
+        // interpolation.
+        let slope_a = Vec3 { x: 2.0, y: 4.0, z: 0.0 };
+        let slope_b = Vec3 { x: 0.0, y: -2.0, z: 3.0 };
+        let outputs = [
+            Vec3::new(), Vec3 { x: 0.0, y: 0.0, z: 0.0 }, slope_a,
+            slope_a, Vec3 { x: 2.0, y: 4.0, z: 0.0 }, slope_b,
+            slope_b, Vec3 { x: 2.0, y: 0.0, z: 6.0 }, Vec3::new(),
+        ];
+        let cubic = Track::new_from_gltf(Interpolation::CubicSpline, &[1.0, 2.0, 4.0], &outputs);
+        let linear = vec3_track(Interpolation::Linear);
+        for step in 0..=30 {
+            let time = 1.0 + step as f32 / 10.0;
+            assert!((cubic.sample(time).unwrap() - linear.sample(time).unwrap()).length() < 1e-5);
+        }
+
+        // The glTF spline from its definition, with the tangents scaled by
+        // the time between keyframes.
+        let outputs = [Vec3::new(), Vec3 { x: 0.0, y: 0.0, z: 0.0 }, Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 0.0, y: 3.0, z: 0.0 }, Vec3 { x: 1.0, y: 1.0, z: 1.0 }, Vec3::new()];
+        let track = Track::new_from_gltf(Interpolation::CubicSpline, &[0.0, 2.0], &outputs);
+        let t: f32 = 0.25;
+        let (t2, t3) = (t * t, t * t * t);
+        let expected = (2.0 * (t3 - 2.0 * t2 + t)) * Vec3 { x: 1.0, y: 0.0, z: 0.0 }
+            + (-2.0 * t3 + 3.0 * t2) * Vec3 { x: 1.0, y: 1.0, z: 1.0 }
+            + (2.0 * (t3 - t2)) * Vec3 { x: 0.0, y: 3.0, z: 0.0 };
+        assert!((track.sample(0.5).unwrap() - expected).length() < 1e-6);
+    }
+
+    #[test]
+    fn rotation_sampling() {
+        let third_turn = Quat::new_from_axis_angle(&Vec3 { x: 0.0, y: 0.0, z: 1.0 }, 2.0 * std::f32::consts::FRAC_PI_3);
+        let sixth_turn = Quat::new_from_axis_angle(&Vec3 { x: 0.0, y: 0.0, z: 1.0 }, std::f32::consts::FRAC_PI_3);
+        let track = Track::new_from_keyframes(Interpolation::Linear, &[Keyframe::new(0.0, &Quat::identity()), Keyframe::new(1.0, &third_turn)]);
+        let sampled = track.sample(0.5).unwrap();
+        assert!((sampled.dot(&sixth_turn).abs() - 1.0).abs() < 1e-6);
+
+        let cubic = Track::new_from_keyframes(Interpolation::CubicSpline, &[Keyframe::new(0.0, &Quat::identity()), Keyframe::new(1.0, &third_turn)]);
+        for step in 0..=10 {
+            let rotation = cubic.sample(step as f32 / 10.0).unwrap();
+            assert!((rotation.length() - 1.0).abs() < 1e-6);
+        }
+        assert!((cubic.sample(0.5).unwrap().dot(&sixth_turn).abs() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn trs_tracks_produce_mat4() {
+        let rotation = Quat::new_from_axis_angle(&Vec3 { x: 0.0, y: 1.0, z: 0.0 }, 1.0);
+        let rest = Trs::new_from_values(&Vec3 { x: 1.0, y: 2.0, z: 3.0 }, &rotation, &Vec3 { x: 2.0, y: 2.0, z: 2.0 });
+        let recovered = Trs::new_from_mat4(&rest.to_mat4()).unwrap();
+        assert!((recovered.translation - rest.translation).length() < 1e-5);
+        assert!((recovered.rotation.dot(&rest.rotation).abs() - 1.0).abs() < 1e-5);
+        assert!((recovered.scale - rest.scale).length() < 1e-5);
+
+        let channels = TrsChannels { translation: Some(vec3_track(Interpolation::Linear)), rotation: None, scale: None };
+        assert_eq!(channels.time_range(), Some((1.0, 4.0)));
+        let sampled = channels.sample(3.0, &rest);
+        assert_eq!(sampled.translation, Vec3 { x: 2.0, y: 2.0, z: 3.0 });
+        assert_eq!(sampled.rotation, rest.rotation);
+        assert_eq!(channels.sample_mat4(3.0, &rest), Mat4::new_from_trs(&Vec3 { x: 2.0, y: 2.0, z: 3.0 }, &rotation.to_mat3(), &rest.scale));
+        assert_eq!(TrsChannels::default().sample(1.0, &rest), rest);
+        assert_eq!(TrsChannels::default().time_range(), None);
+
+        let track = Track::new_from_keyframes(Interpolation::Linear, &[Keyframe::new(0.0, &Trs::identity()), Keyframe::new(2.0, &rest)]);
+        let halfway = track.sample(1.0).unwrap();
+        assert_eq!(halfway.scale, Vec3 { x: 1.5, y: 1.5, z: 1.5 });
+        assert_eq!(halfway.rotation, Quat::identity().interpolate_linear(&rotation, 0.5));
+        assert_eq!(track.sample_mat4(1.0), Some(halfway.to_mat4()));
+    }
+}
@@ -0,0 +1,177 @@
+//! Implementation of boolean vector masks and the component-wise comparisons
+//! that produce them.
+
+use std::ops;
+
+use super::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+
+macro_rules! bvec {
+    ($(#[$doc:meta])* $name:ident { $($field:ident),+ }) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name {
+            $(pub $field: bool,)+
+        }
+
+        impl $name {
+            /// Returns a new mask with every component set to `value`.
+            pub fn splat(value: bool) -> $name {
+                $name { $($field: value,)+ }
+            }
+
+            /// Returns true if any component is true.
+            pub fn any(&self) -> bool {
+                false $(|| self.$field)+
+            }
+
+            /// Returns true if every component is true.
+            pub fn all(&self) -> bool {
+                true $(&& self.$field)+
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                $name { $($field: !self.$field,)+ }
+            }
+        }
+
+        impl ops::BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, other: $name) -> $name {
+                $name { $($field: self.$field && other.$field,)+ }
+            }
+        }
+
+        impl ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name { $($field: self.$field || other.$field,)+ }
+            }
+        }
+    };
+}
+
+bvec!(
+    /// A 2D boolean mask with elements x and y
+    BVec2 { x, y }
+);
+bvec!(
+    /// A 3D boolean mask with elements x, y, and z
+    BVec3 { x, y, z }
+);
+bvec!(
+    /// A 4D boolean mask with elements x, y, z, and w
+    BVec4 { x, y, z, w }
+);
+
+macro_rules! impl_comparisons {
+    ($vec:ident, $mask:ident { $($field:ident),+ }) => {
+        impl $vec {
+            /// Returns a mask of the components equal to the other vector's.
+            pub fn cmpeq(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field == other.$field,)+ }
+            }
+
+            /// Returns a mask of the components not equal to the other
+            /// vector's.
+            pub fn cmpne(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field != other.$field,)+ }
+            }
+
+            /// Returns a mask of the components less than the other vector's.
+            pub fn cmplt(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field < other.$field,)+ }
+            }
+
+            /// Returns a mask of the components less than or equal to the
+            /// other vector's.
+            pub fn cmple(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field <= other.$field,)+ }
+            }
+
+            /// Returns a mask of the components greater than the other
+            /// vector's.
+            pub fn cmpgt(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field > other.$field,)+ }
+            }
+
+            /// Returns a mask of the components greater than or equal to the
+            /// other vector's.
+            pub fn cmpge(&self, other: &$vec) -> $mask {
+                $mask { $($field: self.$field >= other.$field,)+ }
+            }
+
+            /// Returns a vector taking each component from `if_true` where
+            /// the mask is set and from `if_false` where it isn't.
+            pub fn select(mask: &$mask, if_true: &$vec, if_false: &$vec) -> $vec {
+                $vec {
+                    $($field: if mask.$field { if_true.$field } else { if_false.$field },)+
+                }
+            }
+        }
+    };
+}
+
+impl_comparisons!(Vec2, BVec2 { x, y });
+impl_comparisons!(Vec3, BVec3 { x, y, z });
+impl_comparisons!(Vec4, BVec4 { x, y, z, w });
+impl_comparisons!(IVec2, BVec2 { x, y });
+impl_comparisons!(IVec3, BVec3 { x, y, z });
+impl_comparisons!(IVec4, BVec4 { x, y, z, w });
+impl_comparisons!(UVec2, BVec2 { x, y });
+impl_comparisons!(UVec3, BVec3 { x, y, z });
+impl_comparisons!(UVec4, BVec4 { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::{BVec2, BVec3, BVec4, IVec2, UVec4, Vec3};
+
+    #[test]
+    fn any_and_all() {
+        assert!(BVec3 { x: false, y: true, z: false }.any());
+        assert!(!BVec3 { x: false, y: true, z: false }.all());
+        assert!(BVec2::splat(true).all());
+        assert!(!BVec4::splat(false).any());
+    }
+
+    #[test]
+    fn combine_masks() {
+        let a = BVec2 { x: true, y: false };
+        let b = BVec2 { x: true, y: true };
+        assert_eq!(a & b, BVec2 { x: true, y: false });
+        assert_eq!(a | b, BVec2 { x: true, y: true });
+        assert_eq!(!a, BVec2 { x: false, y: true });
+    }
+
+    #[test]
+    fn compare_vec3s() {
+        let a = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Vec3 { x: 3.0, y: 2.0, z: 1.0 };
+        assert_eq!(a.cmplt(&b), BVec3 { x: true, y: false, z: false });
+        assert_eq!(a.cmple(&b), BVec3 { x: true, y: true, z: false });
+        assert_eq!(a.cmpeq(&b), BVec3 { x: false, y: true, z: false });
+        assert_eq!(a.cmpne(&b), BVec3 { x: true, y: false, z: true });
+        assert_eq!(a.cmpgt(&b), BVec3 { x: false, y: false, z: true });
+        assert_eq!(a.cmpge(&b), BVec3 { x: false, y: true, z: true });
+    }
+
+    #[test]
+    fn select_components() {
+        let a = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Vec3 { x: 3.0, y: 2.0, z: 1.0 };
+        assert_eq!(Vec3::select(&a.cmplt(&b), &a, &b), Vec3 { x: 1.0, y: 2.0, z: 1.0 });
+
+        let a = IVec2 { x: -1, y: 5 };
+        let b = IVec2 { x: 0, y: 0 };
+        assert_eq!(IVec2::select(&a.cmpgt(&b), &a, &b), IVec2 { x: 0, y: 5 });
+
+        let a = UVec4 { x: 1, y: 2, z: 3, w: 4 };
+        let b = UVec4 { x: 4, y: 3, z: 2, w: 1 };
+        assert_eq!(UVec4::select(&a.cmpge(&b), &a, &b), UVec4 { x: 4, y: 3, z: 3, w: 4 });
+    }
+}
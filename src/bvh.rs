@@ -0,0 +1,600 @@
+//! Implementation of a Bounding Volume Hierarchy for accelerating ray casts,
+//! overlap tests and closest point queries.
+//!
+//! The hierarchy is built with the surface area heuristic (SAH): at each
+//! node the primitives are sorted into bins by their centers along each
+//! axis, and the node is split between the bins that minimize the expected
+//! cost of a ray query, which is proportional to the surface area of each
+//! child times the number of primitives in it. The Bvh only stores the
+//! indices of its primitives, so queries that need the geometry take the
+//! triangles as an argument.
+
+use super::{Aabb, Ray, Vec3};
+
+/// The number of bins the primitives are sorted into when splitting.
+const BIN_COUNT: usize = 16;
+/// The most primitives a leaf can hold, even if splitting it looks costlier.
+const MAX_LEAF_SIZE: usize = 8;
+/// The cost of visiting a node, relative to testing one primitive.
+const TRAVERSAL_COST: f32 = 1.0;
+/// An Aabb containing nothing, which any union replaces.
+const EMPTY: Aabb = Aabb {
+    min: Vec3 { x: f32::INFINITY, y: f32::INFINITY, z: f32::INFINITY },
+    max: Vec3 { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY, z: f32::NEG_INFINITY },
+};
+
+/// A node of the hierarchy. Leaves hold `count` primitives starting at
+/// `first` in the index list, and other nodes have `count` 0 and their two
+/// children at `first` and `first + 1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Node {
+    aabb: Aabb,
+    first: usize,
+    count: usize,
+}
+
+/// A Bounding Volume Hierarchy over a list of primitives, each bounded by
+/// an Aabb.
+///
+/// Example:
+/// ```
+/// # use matriarch::{Bvh, Ray, Vec3};
+/// let triangles = [
+///     [Vec3 { x: 0.0, y: 0.0, z: -1.0 }, Vec3 { x: 1.0, y: 0.0, z: -1.0 }, Vec3 { x: 0.0, y: 1.0, z: -1.0 }],
+///     [Vec3 { x: 0.0, y: 0.0, z: -3.0 }, Vec3 { x: 1.0, y: 0.0, z: -3.0 }, Vec3 { x: 0.0, y: 1.0, z: -3.0 }],
+/// ];
+/// let bvh = Bvh::new_from_triangles(&triangles);
+/// let ray = Ray { origin: Vec3 { x: 0.25, y: 0.25, z: 0.0 }, direction: Vec3 { x: 0.0, y: 0.0, z: -1.0 } };
+/// let hit = bvh.ray_nearest_hit(&ray, &triangles, f32::INFINITY).unwrap();
+/// assert_eq!(hit.triangle, 0);
+/// assert_eq!(hit.t, 1.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    indices: Vec<usize>,
+    aabbs: Vec<Aabb>,
+}
+
+/// The result of a ray hitting a triangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// The index of the triangle that was hit.
+    pub triangle: usize,
+    /// The distance along the Ray, in multiples of the direction's length.
+    pub t: f32,
+    /// The barycentric weight of the triangle's second corner, `b`. The
+    /// point hit is `(1 - u - v) * a + u * b + v * c`.
+    pub u: f32,
+    /// The barycentric weight of the triangle's third corner, `c`.
+    pub v: f32,
+}
+
+impl Bvh {
+    /// Returns a Bvh over primitives with the given bounds.
+    pub fn new_from_aabbs(aabbs: &[Aabb]) -> Bvh {
+        let mut bvh = Bvh { nodes: Vec::new(), indices: (0..aabbs.len()).collect(), aabbs: aabbs.to_vec() };
+        if aabbs.is_empty() {
+            return bvh;
+        }
+        let centers: Vec<Vec3> = aabbs.iter().map(|aabb| aabb.center()).collect();
+        bvh.nodes.push(Node { aabb: aabbs[0], first: 0, count: aabbs.len() });
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let (first, count) = (bvh.nodes[node].first, bvh.nodes[node].count);
+            let primitives = &mut bvh.indices[first..first + count];
+            bvh.nodes[node].aabb = bounds(primitives.iter().map(|index| aabbs[*index]));
+            let left_count = match split(aabbs, &centers, primitives, &bvh.nodes[node].aabb) {
+                Some(left_count) => left_count,
+                None => continue,
+            };
+            let left = bvh.nodes.len();
+            bvh.nodes[node] = Node { aabb: bvh.nodes[node].aabb, first: left, count: 0 };
+            bvh.nodes.push(Node { aabb: Aabb::new(), first, count: left_count });
+            bvh.nodes.push(Node { aabb: Aabb::new(), first: first + left_count, count: count - left_count });
+            stack.push(left);
+            stack.push(left + 1);
+        }
+        bvh
+    }
+
+    /// Returns a Bvh over the given triangles.
+    pub fn new_from_triangles(triangles: &[[Vec3; 3]]) -> Bvh {
+        Bvh::new_from_aabbs(&triangle_aabbs(triangles))
+    }
+
+    /// Updates the bounds of every node after the primitives have moved,
+    /// keeping the same tree. This is much faster than rebuilding, but
+    /// queries slow down as the primitives move further from where they
+    /// were when the Bvh was built.
+    ///
+    /// Panics if the number of primitives has changed since the Bvh was
+    /// built.
+    pub fn refit(&mut self, aabbs: &[Aabb]) {
+        assert_eq!(aabbs.len(), self.aabbs.len(), "refit with a different number of primitives");
+        self.aabbs.copy_from_slice(aabbs);
+        // Children always come after their parents.
+        for node in (0..self.nodes.len()).rev() {
+            let Node { first, count, .. } = self.nodes[node];
+            self.nodes[node].aabb = if count > 0 {
+                bounds(self.indices[first..first + count].iter().map(|index| aabbs[*index]))
+            } else {
+                self.nodes[first].aabb.union(&self.nodes[first + 1].aabb)
+            };
+        }
+    }
+
+    /// Updates the bounds of every node after the triangles have moved.
+    pub fn refit_triangles(&mut self, triangles: &[[Vec3; 3]]) {
+        self.refit(&triangle_aabbs(triangles));
+    }
+
+    /// Returns the bounds of every primitive, or `None` if there aren't any.
+    pub fn aabb(&self) -> Option<Aabb> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Returns the indices of the primitives whose bounds overlap the Aabb.
+    pub fn overlapping_aabb(&self, aabb: &Aabb) -> Vec<usize> {
+        let mut result = Vec::new();
+        self.visit(
+            |node| node.aabb.intersects_aabb(aabb),
+            |index| {
+                if self.aabbs[index].intersects_aabb(aabb) {
+                    result.push(index);
+                }
+            },
+        );
+        result
+    }
+
+    /// Returns the nearest hit of the Ray against the triangles within
+    /// `max_t` along it, or `None` if it misses them all. Triangles are hit
+    /// from either side.
+    pub fn ray_nearest_hit(&self, ray: &Ray, triangles: &[[Vec3; 3]], max_t: f32) -> Option<RayHit> {
+        let inverse = inverse_direction(ray);
+        let mut nearest: Option<RayHit> = None;
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let limit = nearest.map_or(max_t, |hit| hit.t);
+            let Node { aabb, first, count } = self.nodes[node];
+            if ray_aabb(&aabb, ray, &inverse, limit).is_none() {
+                continue;
+            }
+            if count > 0 {
+                for index in &self.indices[first..first + count] {
+                    let limit = nearest.map_or(max_t, |hit| hit.t);
+                    if let Some(hit) = ray_triangle(ray, &triangles[*index], *index, limit) {
+                        nearest = Some(hit);
+                    }
+                }
+                continue;
+            }
+            // Visit the nearer child first, so the other can more often be
+            // skipped.
+            let left = ray_aabb(&self.nodes[first].aabb, ray, &inverse, limit);
+            let right = ray_aabb(&self.nodes[first + 1].aabb, ray, &inverse, limit);
+            match (left, right) {
+                (Some(left), Some(right)) if left <= right => stack.extend_from_slice(&[first + 1, first]),
+                (Some(_), Some(_)) => stack.extend_from_slice(&[first, first + 1]),
+                (Some(_), None) => stack.push(first),
+                (None, Some(_)) => stack.push(first + 1),
+                (None, None) => {}
+            }
+        }
+        nearest
+    }
+
+    /// Returns true if the Ray hits any of the triangles within `max_t`
+    /// along it, stopping at the first hit found. This is useful for shadow
+    /// and visibility rays.
+    pub fn ray_any_hit(&self, ray: &Ray, triangles: &[[Vec3; 3]], max_t: f32) -> bool {
+        let inverse = inverse_direction(ray);
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let Node { aabb, first, count } = self.nodes[node];
+            if ray_aabb(&aabb, ray, &inverse, max_t).is_none() {
+                continue;
+            }
+            if count == 0 {
+                stack.extend_from_slice(&[first, first + 1]);
+            } else if self.indices[first..first + count]
+                .iter()
+                .any(|index| ray_triangle(ray, &triangles[*index], *index, max_t).is_some())
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the index of the triangle closest to the point, and the
+    /// closest point on it, or `None` if there are no triangles.
+    pub fn closest_point(&self, point: &Vec3, triangles: &[[Vec3; 3]]) -> Option<(usize, Vec3)> {
+        let distance_squared = |a: &Vec3, b: &Vec3| (*a - *b) * (*a - *b);
+        let mut closest: Option<(usize, Vec3, f32)> = None;
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let Node { aabb, first, count } = self.nodes[node];
+            let limit = closest.map_or(f32::INFINITY, |(_, _, distance)| distance);
+            if distance_squared(&aabb.closest_point(point), point) >= limit {
+                continue;
+            }
+            if count > 0 {
+                for index in &self.indices[first..first + count] {
+                    let [a, b, c] = &triangles[*index];
+                    let candidate = closest_point_on_triangle(point, a, b, c);
+                    let distance = distance_squared(&candidate, point);
+                    if closest.is_none_or(|(_, _, best)| distance < best) {
+                        closest = Some((*index, candidate, distance));
+                    }
+                }
+                continue;
+            }
+            // Visit the nearer child first.
+            let [left, right] = [first, first + 1].map(|child| {
+                distance_squared(&self.nodes[child].aabb.closest_point(point), point)
+            });
+            if left <= right {
+                stack.extend_from_slice(&[first + 1, first]);
+            } else {
+                stack.extend_from_slice(&[first, first + 1]);
+            }
+        }
+        closest.map(|(index, point, _)| (index, point))
+    }
+
+    /// Walks the nodes whose bounds pass the test, calling `leaf` with each
+    /// primitive of the leaves reached.
+    fn visit<T: Fn(&Node) -> bool, L: FnMut(usize)>(&self, test: T, mut leaf: L) {
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !test(node) {
+                continue;
+            }
+            if node.count == 0 {
+                stack.extend_from_slice(&[node.first, node.first + 1]);
+            } else {
+                self.indices[node.first..node.first + node.count].iter().for_each(|index| leaf(*index));
+            }
+        }
+    }
+}
+
+fn triangle_aabbs(triangles: &[[Vec3; 3]]) -> Vec<Aabb> {
+    triangles.iter().map(|triangle| Aabb::new_from_points(triangle).unwrap()).collect()
+}
+
+fn bounds<I: Iterator<Item = Aabb>>(mut aabbs: I) -> Aabb {
+    let first = aabbs.next().unwrap();
+    aabbs.fold(first, |total, aabb| total.union(&aabb))
+}
+
+fn axis(vec3: &Vec3, axis: usize) -> f32 {
+    match axis {
+        0 => vec3.x,
+        1 => vec3.y,
+        _ => vec3.z,
+    }
+}
+
+/// Partitions the primitives of a node so the left child's come first,
+/// returning how many there are, or `None` if the node should be a leaf.
+fn split(aabbs: &[Aabb], centers: &[Vec3], primitives: &mut [usize], node_aabb: &Aabb) -> Option<usize> {
+    let count = primitives.len();
+    if count <= 2 {
+        return None;
+    }
+    let center_bounds = bounds(primitives.iter().map(|index| Aabb { min: centers[*index], max: centers[*index] }));
+    let extent = center_bounds.max - center_bounds.min;
+
+    // Find the cheapest split between bins on any axis.
+    let mut best: Option<(f32, usize, usize)> = None;
+    for dimension in 0..3 {
+        let width = axis(&extent, dimension);
+        if width <= 0.0 {
+            continue;
+        }
+        let bin_of = |index: usize| {
+            let offset = axis(&centers[index], dimension) - axis(&center_bounds.min, dimension);
+            ((offset / width * BIN_COUNT as f32) as usize).min(BIN_COUNT - 1)
+        };
+        let mut bins = [(EMPTY, 0); BIN_COUNT];
+        for index in primitives.iter() {
+            let bin = &mut bins[bin_of(*index)];
+            bin.0 = bin.0.union(&aabbs[*index]);
+            bin.1 += 1;
+        }
+        // The cost of everything right of each split, swept from the right,
+        // then combined with a sweep from the left.
+        let mut right_costs = [0.0; BIN_COUNT];
+        let mut accumulated = (EMPTY, 0);
+        for bin in (1..BIN_COUNT).rev() {
+            accumulated = (accumulated.0.union(&bins[bin].0), accumulated.1 + bins[bin].1);
+            right_costs[bin] = cost(&accumulated);
+        }
+        let mut accumulated = (EMPTY, 0);
+        for bin in 1..BIN_COUNT {
+            accumulated = (accumulated.0.union(&bins[bin - 1].0), accumulated.1 + bins[bin - 1].1);
+            let total = cost(&accumulated) + right_costs[bin];
+            if accumulated.1 > 0 && accumulated.1 < count && best.is_none_or(|(best, _, _)| total < best) {
+                best = Some((total, dimension, bin));
+            }
+        }
+    }
+
+    let area = node_aabb.surface_area();
+    let split_cost = best.map(|(total, _, _)| TRAVERSAL_COST + if area > 0.0 { total / area } else { 0.0 });
+    if count <= MAX_LEAF_SIZE && split_cost.is_none_or(|split_cost| split_cost >= count as f32) {
+        return None;
+    }
+    let left_count = match best {
+        Some((_, dimension, split_bin)) => {
+            let width = axis(&extent, dimension);
+            partition(primitives, |index| {
+                let offset = axis(&centers[index], dimension) - axis(&center_bounds.min, dimension);
+                ((offset / width * BIN_COUNT as f32) as usize).min(BIN_COUNT - 1) < split_bin
+            })
+        }
+        // Every center is in the same place, so split the list in half.
+        None => count / 2,
+    };
+    Some(left_count)
+}
+
+fn cost(bin: &(Aabb, usize)) -> f32 {
+    if bin.1 == 0 {
+        0.0
+    } else {
+        bin.0.surface_area() * bin.1 as f32
+    }
+}
+
+/// Moves the primitives passing the test to the front, returning how many
+/// there are.
+fn partition<F: Fn(usize) -> bool>(primitives: &mut [usize], left: F) -> usize {
+    let mut count = 0;
+    for i in 0..primitives.len() {
+        if left(primitives[i]) {
+            primitives.swap(i, count);
+            count += 1;
+        }
+    }
+    count
+}
+
+fn inverse_direction(ray: &Ray) -> Vec3 {
+    Vec3 { x: 1.0 / ray.direction.x, y: 1.0 / ray.direction.y, z: 1.0 / ray.direction.z }
+}
+
+/// Returns the distance along the Ray where it enters the Aabb, or `None` if
+/// it misses or only reaches it beyond `max_t`, using the slab method.
+fn ray_aabb(aabb: &Aabb, ray: &Ray, inverse: &Vec3, max_t: f32) -> Option<f32> {
+    let mut enter = 0.0f32;
+    let mut exit = max_t;
+    for dimension in 0..3 {
+        let origin = axis(&ray.origin, dimension);
+        let inverse = axis(inverse, dimension);
+        let near = (axis(&aabb.min, dimension) - origin) * inverse;
+        let far = (axis(&aabb.max, dimension) - origin) * inverse;
+        // min and max ignore the NaN from a ray lying in a slab's plane.
+        enter = enter.max(near.min(far));
+        exit = exit.min(near.max(far));
+    }
+    if enter <= exit {
+        Some(enter)
+    } else {
+        None
+    }
+}
+
+/// Returns where the Ray hits the triangle within `max_t`, using the
+/// Möller–Trumbore algorithm.
+fn ray_triangle(ray: &Ray, triangle: &[Vec3; 3], index: usize, max_t: f32) -> Option<RayHit> {
+    let [a, b, c] = *triangle;
+    let (edge1, edge2) = (b - a, c - a);
+    let p = ray.direction.cross_product(&edge2);
+    let determinant = edge1 * p;
+    if determinant == 0.0 {
+        return None;
+    }
+    let inverse = 1.0 / determinant;
+    let offset = ray.origin - a;
+    let u = (offset * p) * inverse;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = offset.cross_product(&edge1);
+    let v = (ray.direction * q) * inverse;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = (edge2 * q) * inverse;
+    if t < 0.0 || t > max_t {
+        return None;
+    }
+    Some(RayHit { triangle: index, t, u, v })
+}
+
+/// Returns the point on the triangle closest to `point`, by finding which
+/// corner, edge or face region of the triangle it projects into.
+///
+/// Example:
+/// ```
+/// # use matriarch::{closest_point_on_triangle, Vec3};
+/// let a = Vec3 { x: 0.0, y: 0.0, z: 0.0 };
+/// let b = Vec3 { x: 2.0, y: 0.0, z: 0.0 };
+/// let c = Vec3 { x: 0.0, y: 2.0, z: 0.0 };
+/// let point = Vec3 { x: 0.5, y: 0.5, z: 3.0 };
+/// assert_eq!(closest_point_on_triangle(&point, &a, &b, &c), Vec3 { x: 0.5, y: 0.5, z: 0.0 });
+/// ```
+pub fn closest_point_on_triangle(point: &Vec3, a: &Vec3, b: &Vec3, c: &Vec3) -> Vec3 {
+    let (ab, ac, ap) = (*b - *a, *c - *a, *point - *a);
+    let (d1, d2) = (ab * ap, ac * ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *a;
+    }
+    let bp = *point - *b;
+    let (d3, d4) = (ab * bp, ac * bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return *b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return *a + (d1 / (d1 - d3)) * ab;
+    }
+    let cp = *point - *c;
+    let (d5, d6) = (ab * cp, ac * cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return *c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return *a + (d2 / (d2 - d6)) * ac;
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return *b + ((d4 - d3) / ((d4 - d3) + (d5 - d6))) * (*c - *b);
+    }
+    let denominator = 1.0 / (va + vb + vc);
+    *a + (vb * denominator) * ab + (vc * denominator) * ac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_point_on_triangle, ray_triangle, Aabb, Bvh, Ray, Vec3};
+    use crate::test_values::values;
+
+    /// Returns a pseudo-random soup of small triangles in a 20 unit cube.
+    fn triangle_soup(count: usize, seed: u32) -> Vec<[Vec3; 3]> {
+        values(12 * count, seed)
+            .chunks(12)
+            .map(|v| {
+                let center = Vec3 { x: 10.0 * v[0], y: 10.0 * v[1], z: 10.0 * v[2] };
+                let corner = |i: usize| center + 0.5 * Vec3 { x: v[i], y: v[i + 1], z: v[i + 2] };
+                [corner(3), corner(6), corner(9)]
+            })
+            .collect()
+    }
+
+    fn rays(count: usize) -> Vec<Ray> {
+        (0..count)
+            .map(|i| {
+                let angle = i as f32 * 0.37;
+                Ray {
+                    origin: Vec3 { x: 15.0 * angle.cos(), y: (i % 11) as f32 - 5.0, z: 15.0 * angle.sin() },
+                    direction: Vec3 { x: -angle.cos(), y: 0.02 * ((i % 7) as f32 - 3.0), z: -angle.sin() },
+                }
+            })
+            .collect()
+    }
+
+    fn brute_force_nearest(ray: &Ray, triangles: &[[Vec3; 3]]) -> Option<(usize, f32)> {
+        triangles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, triangle)| ray_triangle(ray, triangle, index, f32::INFINITY))
+            .map(|hit| (hit.triangle, hit.t))
+            .fold(None, |best: Option<(usize, f32)>, hit| if best.is_none_or(|best| hit.1 < best.1) { Some(hit) } else { best })
+    }
+
+    #[test]
+    fn ray_queries_match_brute_force() {
+        let triangles = triangle_soup(2000, 1);
+        let bvh = Bvh::new_from_triangles(&triangles);
+        let mut hits = 0;
+        for ray in rays(200) {
+            let expected = brute_force_nearest(&ray, &triangles);
+            let actual = bvh.ray_nearest_hit(&ray, &triangles, f32::INFINITY).map(|hit| (hit.triangle, hit.t));
+            assert_eq!(actual, expected);
+            assert_eq!(bvh.ray_any_hit(&ray, &triangles, f32::INFINITY), expected.is_some());
+            if let Some((_, t)) = expected {
+                hits += 1;
+                assert!(!bvh.ray_any_hit(&ray, &triangles, 0.99 * t));
+            }
+        }
+        assert!(hits > 50);
+    }
+
+    #[test]
+    fn overlap_and_closest_point_match_brute_force() {
+        let triangles = triangle_soup(1000, 2);
+        let bvh = Bvh::new_from_triangles(&triangles);
+        let query = Aabb { min: Vec3 { x: -3.0, y: -2.0, z: -4.0 }, max: Vec3 { x: 2.0, y: 3.0, z: 1.0 } };
+        let mut actual = bvh.overlapping_aabb(&query);
+        actual.sort_unstable();
+        let expected: Vec<usize> = (0..triangles.len())
+            .filter(|index| Aabb::new_from_points(&triangles[*index]).unwrap().intersects_aabb(&query))
+            .collect();
+        assert_eq!(actual, expected);
+
+        for point in triangle_soup(50, 3).iter().map(|triangle| 1.5 * triangle[0]) {
+            let (index, closest) = bvh.closest_point(&point, &triangles).unwrap();
+            let distance = (closest - point).length();
+            for [a, b, c] in &triangles {
+                assert!(distance <= (closest_point_on_triangle(&point, a, b, c) - point).length());
+            }
+            let [a, b, c] = &triangles[index];
+            assert_eq!(closest, closest_point_on_triangle(&point, a, b, c));
+        }
+    }
+
+    #[test]
+    fn refit_after_moving_triangles() {
+        let mut triangles = triangle_soup(500, 4);
+        let mut bvh = Bvh::new_from_triangles(&triangles);
+        let offset = Vec3 { x: 0.0, y: 30.0, z: 0.0 };
+        for triangle in triangles.iter_mut().step_by(2) {
+            for corner in triangle.iter_mut() {
+                *corner += offset;
+            }
+        }
+        bvh.refit_triangles(&triangles);
+        let root = bvh.aabb().unwrap();
+        assert!(root.max.y > 30.0 && root.min.y < -9.0);
+        for ray in rays(100) {
+            let expected = brute_force_nearest(&ray, &triangles);
+            let actual = bvh.ray_nearest_hit(&ray, &triangles, f32::INFINITY).map(|hit| (hit.triangle, hit.t));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "different number")]
+    fn refit_with_fewer_triangles_panics() {
+        let triangles = triangle_soup(50, 4);
+        let mut bvh = Bvh::new_from_triangles(&triangles);
+        bvh.refit_triangles(&triangles[..49]);
+    }
+
+    #[test]
+    fn empty_and_degenerate_inputs() {
+        let bvh = Bvh::new_from_triangles(&[]);
+        let ray = Ray { origin: Vec3::new(), direction: Vec3 { x: 1.0, y: 0.0, z: 0.0 } };
+        assert_eq!(bvh.aabb(), None);
+        assert_eq!(bvh.ray_nearest_hit(&ray, &[], f32::INFINITY), None);
+        assert_eq!(bvh.closest_point(&Vec3::new(), &[]), None);
+
+        // Many triangles in the same place still build and query.
+        let triangle = [Vec3 { x: 1.0, y: -1.0, z: -1.0 }, Vec3 { x: 1.0, y: 1.0, z: -1.0 }, Vec3 { x: 1.0, y: 0.0, z: 1.0 }];
+        let triangles = vec![triangle; 100];
+        let bvh = Bvh::new_from_triangles(&triangles);
+        assert_eq!(bvh.overlapping_aabb(&bvh.aabb().unwrap()).len(), 100);
+        let hit = bvh.ray_nearest_hit(&ray, &triangles, f32::INFINITY).unwrap();
+        assert_eq!(hit.t, 1.0);
+        assert!((hit.u - 0.25).abs() < 1e-6 && (hit.v - 0.5).abs() < 1e-6);
+    }
+}
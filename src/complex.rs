@@ -0,0 +1,217 @@
+//! Implementation of a Complex number, which as a unit Complex represents a
+//! 2D rotation.
+
+use std::ops;
+
+use super::{Mat2, Mat3, Vec2};
+
+/// A Complex number `re + im i`.
+///
+/// A unit Complex `cos θ + sin θ i` represents a counter-clockwise rotation
+/// by θ. Unlike a Mat2, it only has one degree of freedom to drift, and
+/// `normalize` fully restores it after repeated composition.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex {
+    pub re: f32,
+    pub im: f32,
+}
+
+/// A 2D rotation, stored as a unit Complex.
+pub type Rotation2 = Complex;
+
+impl Complex {
+    /// Returns a new Complex at 0 + 0i.
+    pub fn new() -> Complex {
+        Complex { re: 0.0, im: 0.0 }
+    }
+
+    /// Returns the Complex representing no rotation, 1 + 0i.
+    pub fn identity() -> Complex {
+        Complex { re: 1.0, im: 0.0 }
+    }
+
+    /// Returns a new Complex using the given real and imaginary parts.
+    pub fn new_from_values(re: &f32, im: &f32) -> Complex {
+        Complex { re: *re, im: *im }
+    }
+
+    /// Returns a unit Complex rotating counter-clockwise by `angle` radians.
+    pub fn new_from_angle(angle: f32) -> Complex {
+        let (sin, cos) = angle.sin_cos();
+        Complex { re: cos, im: sin }
+    }
+
+    /// Returns the unit Complex with the same rotation as the given rotation
+    /// matrix, taken from its first column.
+    pub fn new_from_mat2(mat2: &Mat2) -> Complex {
+        Complex { re: mat2.a, im: mat2.c }.normalize()
+    }
+
+    /// Returns the unit Complex with the same rotation as the upper left 2x2
+    /// of a homogeneous 2D transform.
+    pub fn new_from_mat3(mat3: &Mat3) -> Complex {
+        Complex { re: mat3.a, im: mat3.d }.normalize()
+    }
+
+    /// Returns the Mat2 multiplying by the Complex, which for a unit Complex
+    /// is its rotation matrix.
+    pub fn to_mat2(&self) -> Mat2 {
+        Mat2 {
+            a: self.re, b: -self.im,
+            c: self.im, d:  self.re
+        }
+    }
+
+    /// Returns the homogeneous 2D transform rotating by a unit Complex.
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3 {
+            a: self.re, b: -self.im, c: 0.0,
+            d: self.im, e:  self.re, f: 0.0,
+            g: 0.0,     h:  0.0,     i: 1.0
+        }
+    }
+
+    /// Returns the argument of the Complex, which for a unit Complex is its
+    /// rotation angle in the range [-π, π].
+    pub fn angle(&self) -> f32 {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns the conjugate of the Complex, which for a unit Complex is the
+    /// inverse rotation.
+    pub fn conjugate(&self) -> Complex {
+        Complex { re: self.re, im: -self.im }
+    }
+
+    /// Returns the length, or modulus, of the Complex.
+    pub fn length(&self) -> f32 {
+        f32::sqrt(self.re.powi(2) + self.im.powi(2))
+    }
+
+    /// Returns the Complex scaled to a length of 1.
+    pub fn normalize(&self) -> Complex {
+        (1.0 / self.length()) * *self
+    }
+
+    /// Interpolates the angle between two unit Complexes along the shortest
+    /// path, returning `self` at `t = 0` and `other` at `t = 1`.
+    pub fn slerp(&self, other: &Complex, t: f32) -> Complex {
+        let angle = (self.conjugate() * *other).angle();
+        *self * Complex::new_from_angle(t * angle)
+    }
+}
+
+impl ops::Add<Complex> for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex { re: self.re + other.re, im: self.im + other.im }
+    }
+}
+
+impl ops::Sub<Complex> for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex { re: self.re - other.re, im: self.im - other.im }
+    }
+}
+
+impl ops::Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+impl ops::Mul<Complex> for f32 {
+    type Output = Complex;
+
+    /// Multiplies a scalar value by both parts of the Complex.
+    fn mul(self, complex: Complex) -> Complex {
+        Complex { re: self * complex.re, im: self * complex.im }
+    }
+}
+
+impl ops::Mul<Complex> for Complex {
+    type Output = Complex;
+
+    /// Multiplies two Complexes, which for unit Complexes adds their
+    /// rotation angles.
+    fn mul(self, other: Complex) -> Complex {
+        Complex {
+            re: (self.re * other.re) - (self.im * other.im),
+            im: (self.re * other.im) + (self.im * other.re),
+        }
+    }
+}
+
+impl ops::Mul<Vec2> for Complex {
+    type Output = Vec2;
+
+    /// Rotates the Vec2 by a unit Complex.
+    fn mul(self, vec2: Vec2) -> Vec2 {
+        Vec2 {
+            x: (self.re * vec2.x) - (self.im * vec2.y),
+            y: (self.im * vec2.x) + (self.re * vec2.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{Complex, Mat2, Mat3, Rotation2, Vec2};
+    use crate::test_values::assert_near;
+
+    #[test]
+    fn rotate_vec2() {
+        let rotation = Rotation2::new_from_angle(0.5 * PI);
+        let rotated = rotation * Vec2 { x: 2.0, y: 0.0 };
+        assert_near(rotated.x, 0.0, 1e-6);
+        assert_near(rotated.y, 2.0, 1e-6);
+        assert_eq!(rotation.to_mat2() * Vec2 { x: 2.0, y: 0.0 }, rotated);
+    }
+
+    #[test]
+    fn multiply_and_conjugate() {
+        let a = Complex::new_from_angle(0.3);
+        let b = Complex::new_from_angle(1.1);
+        assert_near((a * b).angle(), 1.4, 1e-6);
+        assert_near((a * a.conjugate()).re, 1.0, 1e-6);
+        let product = Complex { re: 1.0, im: 2.0 } * Complex { re: 3.0, im: -1.0 };
+        assert_eq!(product, Complex { re: 5.0, im: 5.0 });
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let rotation = Complex::new_from_angle(-2.5);
+        assert_near(Complex::new_from_mat2(&rotation.to_mat2()).angle(), -2.5, 1e-6);
+        assert_near(Complex::new_from_mat3(&rotation.to_mat3()).angle(), -2.5, 1e-6);
+        assert_eq!(Complex::identity().to_mat2(), Mat2::identity());
+        assert_eq!(Complex::identity().to_mat3(), Mat3::identity());
+    }
+
+    #[test]
+    fn slerp_takes_shortest_path() {
+        let a = Complex::new_from_angle(0.9 * PI);
+        let b = Complex::new_from_angle(-0.9 * PI);
+        // The short way round crosses π rather than passing through 0.
+        assert_near(a.slerp(&b, 0.5).angle().abs(), PI, 1e-6);
+        assert_near(a.slerp(&b, 0.0).angle(), 0.9 * PI, 1e-6);
+    }
+
+    #[test]
+    fn normalize_removes_drift() {
+        let step = Complex::new_from_angle(0.001);
+        let mut rotation = Complex::identity();
+        for _ in 0..1000 {
+            rotation = rotation * step;
+        }
+        assert_near(rotation.normalize().length(), 1.0, 1e-6);
+        assert!((rotation.angle() - 1.0).abs() < 1e-4);
+    }
+}
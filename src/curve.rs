@@ -0,0 +1,788 @@
+//! Implementation of parametric curves over vectors: cubic Bezier, Hermite,
+//! B-spline and Catmull-Rom, with arc length and nearest point queries.
+
+use std::fmt::Debug;
+use std::ops;
+
+use super::{Vec2, Vec3, Vec4};
+
+/// Nodes and weights of 5 point Gauss-Legendre quadrature on [-1, 1].
+const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+    (0.0, 0.568_888_9),
+    (-0.538_469_3, 0.478_628_67),
+    (0.538_469_3, 0.478_628_67),
+    (-0.906_179_85, 0.236_926_88),
+    (0.906_179_85, 0.236_926_88),
+];
+
+/// The number of quadrature intervals in each polynomial piece of a curve.
+const ARC_LENGTH_INTERVALS: usize = 4;
+
+/// The number of starting guesses in each polynomial piece of a curve for
+/// `Curve::nearest_point`.
+const NEAREST_POINT_SAMPLES: usize = 16;
+
+/// A vector that curves can be built from.
+///
+/// `*` between two points is their dot product, as it is for the vectors.
+pub trait CurvePoint:
+    Copy + Debug + PartialEq + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = f32>
+{
+    /// Returns the vector multiplied by a scalar.
+    fn scale(self, factor: f32) -> Self;
+}
+
+impl CurvePoint for Vec2 {
+    fn scale(self, factor: f32) -> Vec2 {
+        factor * self
+    }
+}
+
+impl CurvePoint for Vec3 {
+    fn scale(self, factor: f32) -> Vec3 {
+        factor * self
+    }
+}
+
+impl CurvePoint for Vec4 {
+    fn scale(self, factor: f32) -> Vec4 {
+        factor * self
+    }
+}
+
+fn lerp<V: CurvePoint>(from: V, to: V, t: f32) -> V {
+    from + (to - from).scale(t)
+}
+
+fn length<V: CurvePoint>(vector: V) -> f32 {
+    (vector * vector).sqrt()
+}
+
+/// Returns the length of a curve between two parameter values within a
+/// single polynomial piece.
+fn gauss_length<C: Curve + ?Sized>(curve: &C, start: f32, end: f32) -> f32 {
+    let half = 0.5 * (end - start);
+    let middle = 0.5 * (end + start);
+    GAUSS_LEGENDRE.iter().map(|(node, weight)| weight * length(curve.derivative(middle + half * node))).sum::<f32>() * half
+}
+
+/// A curve mapping a parameter range to points.
+///
+/// The provided methods work on any curve from its evaluation and
+/// derivatives.
+///
+/// Example:
+/// ```
+/// # use matriarch::{ArcLengthTable, CatmullRom, Curve, Vec3};
+/// let path = CatmullRom::new_centripetal(&[
+///     Vec3 { x: -1.0, y: 0.0, z: 0.0 },
+///     Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+///     Vec3 { x: 1.0, y: 1.0, z: 0.0 },
+///     Vec3 { x: 2.0, y: 0.0, z: 0.0 },
+///     Vec3 { x: 3.0, y: 0.0, z: 0.0 },
+/// ]);
+/// // Moving along the path at a constant speed.
+/// let table = ArcLengthTable::new(&path, 8);
+/// let halfway = path.evaluate(table.parameter_at_length(&path, 0.5 * table.length()));
+/// assert!((halfway - Vec3 { x: 1.0, y: 1.0, z: 0.0 }).length() < 1e-3);
+/// ```
+pub trait Curve {
+    type Point: CurvePoint;
+
+    /// Returns the first and last parameter values of the curve.
+    fn domain(&self) -> (f32, f32);
+
+    /// Returns the point at parameter `t`, which is clamped to the domain.
+    fn evaluate(&self, t: f32) -> Self::Point;
+
+    /// Returns the first derivative with respect to the parameter at `t`.
+    fn derivative(&self, t: f32) -> Self::Point;
+
+    /// Returns the second derivative with respect to the parameter at `t`.
+    fn second_derivative(&self, t: f32) -> Self::Point;
+
+    /// Returns the parameter values where the polynomial pieces of the curve
+    /// meet, including both ends of the domain.
+    fn breakpoints(&self) -> Vec<f32> {
+        let (start, end) = self.domain();
+        vec![start, end]
+    }
+
+    /// Returns the length of the curve between two parameter values, with
+    /// Gauss-Legendre quadrature over each polynomial piece between them.
+    fn arc_length_between(&self, start: f32, end: f32) -> f32 {
+        if end < start {
+            return -self.arc_length_between(end, start);
+        }
+        let mut bounds = vec![start];
+        bounds.extend(self.breakpoints().into_iter().filter(|t| *t > start && *t < end));
+        bounds.push(end);
+        let mut total = 0.0;
+        for piece in bounds.windows(2) {
+            for step in 0..ARC_LENGTH_INTERVALS {
+                let width = (piece[1] - piece[0]) / ARC_LENGTH_INTERVALS as f32;
+                let low = piece[0] + width * step as f32;
+                total += gauss_length(self, low, low + width);
+            }
+        }
+        total
+    }
+
+    /// Returns the length of the whole curve.
+    fn arc_length(&self) -> f32 {
+        let (start, end) = self.domain();
+        self.arc_length_between(start, end)
+    }
+
+    /// Returns the parameter of the point on the curve closest to `point`,
+    /// along with that closest point.
+    ///
+    /// Each polynomial piece is sampled to find starting guesses, which are
+    /// then refined with Newton's method, so wiggles much smaller than a
+    /// sixteenth of a piece may be missed.
+    fn nearest_point(&self, point: &Self::Point) -> (f32, Self::Point) {
+        let distance = |t: f32| {
+            let offset = self.evaluate(t) - *point;
+            offset * offset
+        };
+        let breakpoints = self.breakpoints();
+        let mut samples = Vec::new();
+        for piece in breakpoints.windows(2) {
+            for step in 0..NEAREST_POINT_SAMPLES {
+                samples.push(piece[0] + (piece[1] - piece[0]) * step as f32 / NEAREST_POINT_SAMPLES as f32);
+            }
+        }
+        samples.push(*breakpoints.last().unwrap());
+        let distances: Vec<f32> = samples.iter().map(|t| distance(*t)).collect();
+
+        let (start, end) = self.domain();
+        let mut best = (samples[0], distances[0]);
+        for (index, t) in samples.iter().enumerate() {
+            let before = if index > 0 { distances[index - 1] } else { f32::INFINITY };
+            let after = distances.get(index + 1).copied().unwrap_or(f32::INFINITY);
+            if distances[index] > before || distances[index] > after {
+                continue;
+            }
+            // Newton's method on the derivative of the squared distance.
+            let mut refined = (*t, distances[index]);
+            for _ in 0..8 {
+                let (t, current) = refined;
+                let offset = self.evaluate(t) - *point;
+                let tangent = self.derivative(t);
+                let slope = tangent * offset;
+                let curvature = self.second_derivative(t) * offset + tangent * tangent;
+                if curvature <= 0.0 {
+                    break;
+                }
+                let next = (t - slope / curvature).clamp(start, end);
+                let next_distance = distance(next);
+                if next_distance >= current {
+                    break;
+                }
+                refined = (next, next_distance);
+            }
+            if refined.1 < best.1 {
+                best = refined;
+            }
+        }
+        (best.0, self.evaluate(best.0))
+    }
+}
+
+/// A table of the arc length along a curve, for finding the parameter a
+/// given distance along it, such as to move along a path at constant speed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLengthTable {
+    pub parameters: Vec<f32>,
+    pub lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Returns a table of the curve's length at its breakpoints and at
+    /// `intervals` evenly spaced parameters within each polynomial piece.
+    pub fn new<C: Curve + ?Sized>(curve: &C, intervals: usize) -> ArcLengthTable {
+        let intervals = intervals.max(1);
+        let breakpoints = curve.breakpoints();
+        let mut parameters = vec![breakpoints[0]];
+        let mut lengths = vec![0.0];
+        for piece in breakpoints.windows(2) {
+            for step in 1..=intervals {
+                let previous = *parameters.last().unwrap();
+                let t = piece[0] + (piece[1] - piece[0]) * step as f32 / intervals as f32;
+                lengths.push(lengths.last().unwrap() + gauss_length(curve, previous, t));
+                parameters.push(t);
+            }
+        }
+        ArcLengthTable { parameters, lengths }
+    }
+
+    /// Returns the total length of the curve.
+    pub fn length(&self) -> f32 {
+        *self.lengths.last().unwrap()
+    }
+
+    /// Returns the parameter of the point `distance` along the curve the
+    /// table was built from, clamped to the ends of the curve.
+    pub fn parameter_at_length<C: Curve + ?Sized>(&self, curve: &C, distance: f32) -> f32 {
+        let distance = distance.clamp(0.0, self.length());
+        let index = self.lengths.partition_point(|length| *length <= distance).clamp(1, self.lengths.len() - 1) - 1;
+        let (low, high) = (self.parameters[index], self.parameters[index + 1]);
+        let span = self.lengths[index + 1] - self.lengths[index];
+        if span <= 0.0 {
+            return low;
+        }
+        // Newton's method from the linear guess, kept inside the interval by
+        // falling back to bisection.
+        let remaining = distance - self.lengths[index];
+        let (mut lower, mut upper) = (low, high);
+        let mut t = low + (high - low) * remaining / span;
+        for _ in 0..16 {
+            let error = gauss_length(curve, low, t) - remaining;
+            if error.abs() <= 1e-6 * span.max(1.0) {
+                break;
+            }
+            if error > 0.0 {
+                upper = t;
+            } else {
+                lower = t;
+            }
+            let speed = length(curve.derivative(t));
+            let next = t - error / speed;
+            t = if speed > 0.0 && next > lower && next < upper { next } else { 0.5 * (lower + upper) };
+        }
+        t
+    }
+}
+
+/// A cubic Bezier curve over the parameter range [0, 1], which starts at the
+/// first point, ends at the last and is pulled towards the middle two.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier<V> {
+    pub points: [V; 4],
+}
+
+impl<V: CurvePoint> CubicBezier<V> {
+    /// Returns a new CubicBezier using the given control points.
+    pub fn new_from_points(points: &[V; 4]) -> CubicBezier<V> {
+        CubicBezier { points: *points }
+    }
+
+    /// Returns the two halves of the curve on either side of `t`, each
+    /// reparameterized over [0, 1], using de Casteljau's algorithm.
+    pub fn split(&self, t: f32) -> (CubicBezier<V>, CubicBezier<V>) {
+        let [p0, p1, p2, p3] = self.points;
+        let (a, b, c) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+        let (d, e) = (lerp(a, b, t), lerp(b, c, t));
+        let middle = lerp(d, e, t);
+        (CubicBezier { points: [p0, a, d, middle] }, CubicBezier { points: [middle, e, c, p3] })
+    }
+}
+
+impl<V: CurvePoint> Curve for CubicBezier<V> {
+    type Point = V;
+
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn evaluate(&self, t: f32) -> V {
+        let t = t.clamp(0.0, 1.0);
+        let u = 1.0 - t;
+        let [p0, p1, p2, p3] = self.points;
+        p0.scale(u * u * u) + p1.scale(3.0 * u * u * t) + p2.scale(3.0 * u * t * t) + p3.scale(t * t * t)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        let t = t.clamp(0.0, 1.0);
+        let u = 1.0 - t;
+        let [p0, p1, p2, p3] = self.points;
+        (p1 - p0).scale(3.0 * u * u) + (p2 - p1).scale(6.0 * u * t) + (p3 - p2).scale(3.0 * t * t)
+    }
+
+    fn second_derivative(&self, t: f32) -> V {
+        let t = t.clamp(0.0, 1.0);
+        let [p0, p1, p2, p3] = self.points;
+        (p2 - p1 - (p1 - p0)).scale(6.0 * (1.0 - t)) + (p3 - p2 - (p2 - p1)).scale(6.0 * t)
+    }
+}
+
+/// A cubic Hermite curve over the parameter range [0, 1], running from
+/// `start` to `end` with the given tangents at each.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hermite<V> {
+    pub start: V,
+    pub start_tangent: V,
+    pub end: V,
+    pub end_tangent: V,
+}
+
+impl<V: CurvePoint> Hermite<V> {
+    /// Returns a new Hermite curve using the given end points and tangents.
+    pub fn new_from_values(start: &V, start_tangent: &V, end: &V, end_tangent: &V) -> Hermite<V> {
+        Hermite { start: *start, start_tangent: *start_tangent, end: *end, end_tangent: *end_tangent }
+    }
+
+    /// Returns the same curve as a CubicBezier.
+    pub fn to_bezier(&self) -> CubicBezier<V> {
+        CubicBezier {
+            points: [
+                self.start,
+                self.start + self.start_tangent.scale(1.0 / 3.0),
+                self.end - self.end_tangent.scale(1.0 / 3.0),
+                self.end,
+            ],
+        }
+    }
+}
+
+impl<V: CurvePoint> Curve for Hermite<V> {
+    type Point = V;
+
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn evaluate(&self, t: f32) -> V {
+        self.to_bezier().evaluate(t)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        self.to_bezier().derivative(t)
+    }
+
+    fn second_derivative(&self, t: f32) -> V {
+        self.to_bezier().second_derivative(t)
+    }
+}
+
+/// A B-spline curve of any degree with an arbitrary, non-decreasing knot
+/// vector, which has `points.len() + degree + 1` entries.
+///
+/// The curve is defined between `knots[degree]` and
+/// `knots[points.len()]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<V> {
+    pub points: Vec<V>,
+    pub knots: Vec<f32>,
+    pub degree: usize,
+}
+
+impl<V: CurvePoint> BSpline<V> {
+    /// Returns a new BSpline using the given control points, knots and
+    /// degree.
+    ///
+    /// Panics if there are no more points than the degree, if the number of
+    /// knots isn't `points.len() + degree + 1` or if they decrease.
+    pub fn new_from_points_and_knots(points: &[V], knots: &[f32], degree: usize) -> BSpline<V> {
+        assert!(points.len() > degree, "a B-spline needs more control points than its degree");
+        assert_eq!(knots.len(), points.len() + degree + 1, "a B-spline needs points.len() + degree + 1 knots");
+        assert!(knots.windows(2).all(|pair| pair[0] <= pair[1]), "B-spline knots must not decrease");
+        BSpline { points: points.to_vec(), knots: knots.to_vec(), degree }
+    }
+
+    /// Returns a uniform BSpline, with knots at 0, 1, 2 and so on, defined
+    /// over [degree, points.len()]. It doesn't pass through its first and
+    /// last points.
+    pub fn new_uniform(points: &[V], degree: usize) -> BSpline<V> {
+        let knots: Vec<f32> = (0..points.len() + degree + 1).map(|knot| knot as f32).collect();
+        BSpline::new_from_points_and_knots(points, &knots, degree)
+    }
+
+    /// Returns a clamped uniform BSpline defined over
+    /// [0, points.len() - degree], which starts at the first point and ends
+    /// at the last.
+    pub fn new_clamped(points: &[V], degree: usize) -> BSpline<V> {
+        let pieces = points.len().saturating_sub(degree);
+        let knots: Vec<f32> = (0..points.len() + degree + 1)
+            .map(|knot| knot.saturating_sub(degree).min(pieces) as f32)
+            .collect();
+        BSpline::new_from_points_and_knots(points, &knots, degree)
+    }
+
+    /// Returns the index of the knot span containing `t`, the last span for
+    /// the end of the domain.
+    fn span(&self, t: f32) -> usize {
+        let index = self.knots.partition_point(|knot| *knot <= t);
+        index.saturating_sub(1).clamp(self.degree, self.points.len() - 1)
+    }
+
+    /// Evaluates the given derivative of the curve with de Boor's algorithm,
+    /// where order 0 is the curve itself.
+    ///
+    /// Each derivative is a B-spline of one degree lower, whose control
+    /// points are scaled differences of the last one's, so only the control
+    /// points affecting the span containing `t` are differenced rather than
+    /// building the whole derivative curve.
+    fn de_boor(&self, t: f32, order: usize) -> V {
+        let (start, end) = self.domain();
+        let t = t.clamp(start, end);
+        let span = self.span(t);
+        let degree = self.degree;
+        if order > degree {
+            return self.points[0].scale(0.0);
+        }
+        let first = span - degree;
+        let mut points: Vec<V> = self.points[first..=span].to_vec();
+        for level in 0..order {
+            let scale = (degree - level) as f32;
+            for index in 0..degree - level {
+                let width = self.knots[first + index + degree + 1] - self.knots[first + index + level + 1];
+                points[index] = if width > 0.0 {
+                    (points[index + 1] - points[index]).scale(scale / width)
+                } else {
+                    points[index].scale(0.0)
+                };
+            }
+        }
+        let remaining = degree - order;
+        for level in 1..=remaining {
+            for index in (level..=remaining).rev() {
+                let low = self.knots[first + index + order];
+                let width = self.knots[span + index + 1 - level] - low;
+                let alpha = if width > 0.0 { (t - low) / width } else { 0.0 };
+                points[index] = lerp(points[index - 1], points[index], alpha);
+            }
+        }
+        points[remaining]
+    }
+
+    /// Returns the derivative of the curve as a BSpline of one degree lower
+    /// over the same domain, or `None` for a curve of degree 0.
+    pub fn derivative_curve(&self) -> Option<BSpline<V>> {
+        if self.degree == 0 {
+            return None;
+        }
+        let degree = self.degree as f32;
+        let points = self
+            .points
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| {
+                let width = self.knots[index + self.degree + 1] - self.knots[index + 1];
+                if width > 0.0 { (pair[1] - pair[0]).scale(degree / width) } else { pair[0].scale(0.0) }
+            })
+            .collect();
+        Some(BSpline { points, knots: self.knots[1..self.knots.len() - 1].to_vec(), degree: self.degree - 1 })
+    }
+
+    /// Returns the same curve with `t` inserted into the knots and one more
+    /// control point, using Boehm's algorithm. Repeated insertion subdivides
+    /// the curve, with the control points converging onto it.
+    pub fn insert_knot(&self, t: f32) -> BSpline<V> {
+        let (start, end) = self.domain();
+        let t = t.clamp(start, end);
+        let span = self.span(t);
+        let degree = self.degree;
+        let mut points = Vec::with_capacity(self.points.len() + 1);
+        for index in 0..=self.points.len() {
+            points.push(if index + degree <= span {
+                self.points[index]
+            } else if index > span {
+                self.points[index - 1]
+            } else {
+                let width = self.knots[index + degree] - self.knots[index];
+                let alpha = if width > 0.0 { (t - self.knots[index]) / width } else { 0.0 };
+                lerp(self.points[index - 1], self.points[index], alpha)
+            });
+        }
+        let mut knots = self.knots.clone();
+        knots.insert(span + 1, t);
+        BSpline { points, knots, degree }
+    }
+}
+
+impl<V: CurvePoint> Curve for BSpline<V> {
+    type Point = V;
+
+    fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    fn evaluate(&self, t: f32) -> V {
+        self.de_boor(t, 0)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        self.de_boor(t, 1)
+    }
+
+    fn second_derivative(&self, t: f32) -> V {
+        self.de_boor(t, 2)
+    }
+
+    fn breakpoints(&self) -> Vec<f32> {
+        let mut breakpoints: Vec<f32> = self.knots[self.degree..=self.points.len()].to_vec();
+        breakpoints.dedup();
+        if breakpoints.len() == 1 {
+            breakpoints.push(breakpoints[0]);
+        }
+        breakpoints
+    }
+}
+
+/// A Catmull-Rom spline passing through every point but the first and last,
+/// which only shape the ends.
+///
+/// `alpha` sets the parameterization of the pieces between points: 0 is
+/// uniform, 0.5 centripetal and 1 chordal. Centripetal curves never form
+/// cusps or loops within a piece, so are usually the best choice.
+/// The curve is defined over [0, points.len() - 3], with each whole number
+/// reaching the next point; consecutive points should be distinct. The
+/// direction of the curve is continuous where the pieces meet, but unless
+/// `alpha` is 0 its speed jumps there, as every piece covers a parameter
+/// range of 1 however long it is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRom<V> {
+    pub points: Vec<V>,
+    pub alpha: f32,
+}
+
+impl<V: CurvePoint> CatmullRom<V> {
+    /// Returns a new CatmullRom spline with the given parameterization.
+    ///
+    /// Panics if there are fewer than 4 points.
+    pub fn new_from_points_and_alpha(points: &[V], alpha: f32) -> CatmullRom<V> {
+        assert!(points.len() >= 4, "a Catmull-Rom spline needs at least 4 points");
+        CatmullRom { points: points.to_vec(), alpha }
+    }
+
+    /// Returns a new centripetal CatmullRom spline.
+    ///
+    /// Panics if there are fewer than 4 points.
+    pub fn new_centripetal(points: &[V]) -> CatmullRom<V> {
+        CatmullRom::new_from_points_and_alpha(points, 0.5)
+    }
+
+    /// Returns the number of pieces, one between each pair of points the
+    /// curve passes through.
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 3
+    }
+
+    /// Returns the piece of the curve from `points[index + 1]` to
+    /// `points[index + 2]` as a Hermite curve.
+    pub fn segment(&self, index: usize) -> Hermite<V> {
+        let [p0, p1, p2, p3] = [self.points[index], self.points[index + 1], self.points[index + 2], self.points[index + 3]];
+        // The knot intervals of the parameterization, which are replaced
+        // when points coincide to keep the tangents finite.
+        let interval = |from: V, to: V| {
+            let width = ((to - from) * (to - from)).powf(0.5 * self.alpha);
+            if width < 1e-4 { 1.0 } else { width }
+        };
+        let (d0, d1, d2) = (interval(p0, p1), interval(p1, p2), interval(p2, p3));
+        // The tangents of the non-uniform curve, rescaled to this piece's
+        // parameter range of [0, 1].
+        let start_tangent = ((p1 - p0).scale(1.0 / d0) - (p2 - p0).scale(1.0 / (d0 + d1)) + (p2 - p1).scale(1.0 / d1)).scale(d1);
+        let end_tangent = ((p2 - p1).scale(1.0 / d1) - (p3 - p1).scale(1.0 / (d1 + d2)) + (p3 - p2).scale(1.0 / d2)).scale(d1);
+        Hermite { start: p1, start_tangent, end: p2, end_tangent }
+    }
+
+    /// Returns the piece containing `t` and the parameter within it.
+    fn locate(&self, t: f32) -> (Hermite<V>, f32) {
+        let last = self.segment_count() - 1;
+        let t = t.clamp(0.0, self.segment_count() as f32);
+        let index = (t as usize).min(last);
+        (self.segment(index), t - index as f32)
+    }
+}
+
+impl<V: CurvePoint> Curve for CatmullRom<V> {
+    type Point = V;
+
+    fn domain(&self) -> (f32, f32) {
+        (0.0, self.segment_count() as f32)
+    }
+
+    fn evaluate(&self, t: f32) -> V {
+        let (segment, t) = self.locate(t);
+        segment.evaluate(t)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        let (segment, t) = self.locate(t);
+        segment.derivative(t)
+    }
+
+    fn second_derivative(&self, t: f32) -> V {
+        let (segment, t) = self.locate(t);
+        segment.second_derivative(t)
+    }
+
+    fn breakpoints(&self) -> Vec<f32> {
+        (0..=self.segment_count()).map(|index| index as f32).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArcLengthTable, BSpline, CatmullRom, CubicBezier, Curve, Hermite, Vec2, Vec3};
+
+    /// Checks the derivatives of a curve against central differences.
+    fn assert_derivatives<C: Curve<Point = Vec3>>(curve: &C) {
+        let (start, end) = curve.domain();
+        let h = 1e-3;
+        for step in 1..20 {
+            let t = start + (end - start) * step as f32 / 20.0;
+            let derivative = (1.0 / (2.0 * h)) * (curve.evaluate(t + h) - curve.evaluate(t - h));
+            let second = (1.0 / (2.0 * h)) * (curve.derivative(t + h) - curve.derivative(t - h));
+            // Not every curve has continuous derivatives where its pieces
+            // meet.
+            if curve.breakpoints().iter().any(|breakpoint| (breakpoint - t).abs() < 2.0 * h) {
+                continue;
+            }
+            assert!((derivative - curve.derivative(t)).length() < 1e-2 * (1.0 + derivative.length()), "t {}", t);
+            assert!((second - curve.second_derivative(t)).length() < 2e-2 * (1.0 + second.length()), "t {}", t);
+        }
+    }
+
+    fn control_points() -> Vec<Vec3> {
+        vec![
+            Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+            Vec3 { x: 1.0, y: 2.0, z: 0.0 },
+            Vec3 { x: 3.0, y: 3.0, z: 1.0 },
+            Vec3 { x: 4.0, y: 0.0, z: 2.0 },
+            Vec3 { x: 6.0, y: -1.0, z: 1.0 },
+            Vec3 { x: 7.0, y: 1.0, z: 0.0 },
+            Vec3 { x: 9.0, y: 2.0, z: -1.0 },
+        ]
+    }
+
+    #[test]
+    fn bezier_evaluation_and_split() {
+        let bezier = CubicBezier::new_from_points(&[Vec3 { x: 0.0, y: 0.0, z: 0.0 }, Vec3 { x: 1.0, y: 2.0, z: 0.0 }, Vec3 { x: 3.0, y: 2.0, z: 1.0 }, Vec3 { x: 4.0, y: 0.0, z: 0.0 }]);
+        assert_eq!(bezier.evaluate(0.0), bezier.points[0]);
+        assert_eq!(bezier.evaluate(1.0), bezier.points[3]);
+        assert_eq!(bezier.evaluate(0.5), Vec3 { x: 2.0, y: 1.5, z: 0.375 });
+        assert_eq!(bezier.derivative(0.0), 3.0 * (bezier.points[1] - bezier.points[0]));
+        assert_derivatives(&bezier);
+
+        let (left, right) = bezier.split(0.25);
+        for step in 0..=10 {
+            let t = step as f32 / 10.0;
+            assert!((left.evaluate(t) - bezier.evaluate(0.25 * t)).length() < 1e-5);
+            assert!((right.evaluate(t) - bezier.evaluate(0.25 + 0.75 * t)).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn hermite_matches_its_tangents() {
+        let hermite = Hermite::new_from_values(&Vec3 { x: 0.0, y: 0.0, z: 0.0 }, &Vec3 { x: 3.0, y: 0.0, z: 0.0 }, &Vec3 { x: 1.0, y: 1.0, z: 0.0 }, &Vec3 { x: 0.0, y: 3.0, z: 1.0 });
+        assert_eq!(hermite.evaluate(0.0), hermite.start);
+        assert_eq!(hermite.evaluate(1.0), hermite.end);
+        assert!((hermite.derivative(0.0) - hermite.start_tangent).length() < 1e-6);
+        assert!((hermite.derivative(1.0) - hermite.end_tangent).length() < 1e-6);
+        assert_derivatives(&hermite);
+    }
+
+    #[test]
+    fn bspline_evaluation_and_knot_insertion() {
+        let points = control_points();
+        // A uniform cubic B-spline at a knot is (p0 + 4 p1 + p2) / 6.
+        let uniform = BSpline::new_uniform(&points, 3);
+        assert_eq!(uniform.domain(), (3.0, 7.0));
+        let expected = (1.0 / 6.0) * (points[1] + 4.0 * points[2] + points[3]);
+        assert!((uniform.evaluate(4.0) - expected).length() < 1e-5);
+        assert_derivatives(&uniform);
+
+        let clamped = BSpline::new_clamped(&points, 3);
+        assert_eq!(clamped.knots, vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 4.0, 4.0, 4.0]);
+        assert!((clamped.evaluate(0.0) - points[0]).length() < 1e-6);
+        assert!((clamped.evaluate(4.0) - points[6]).length() < 1e-5);
+        assert_derivatives(&clamped);
+
+        let non_uniform = BSpline::new_from_points_and_knots(&points, &[0.0, 0.0, 0.0, 0.5, 0.7, 0.7, 2.0, 3.0, 3.0, 3.0], 2);
+        assert_eq!(non_uniform.breakpoints(), vec![0.0, 0.5, 0.7, 2.0, 3.0]);
+        assert_derivatives(&non_uniform);
+        let derivative = non_uniform.derivative_curve().unwrap();
+        let second = derivative.derivative_curve().unwrap();
+        for step in 0..=30 {
+            let t = step as f32 / 10.0;
+            assert!((non_uniform.derivative(t) - derivative.evaluate(t)).length() < 1e-4, "t {}", t);
+            assert!((non_uniform.second_derivative(t) - second.evaluate(t)).length() < 1e-3, "t {}", t);
+        }
+        assert_eq!(second.derivative(1.0), Vec3 { x: 0.0, y: 0.0, z: 0.0 });
+        let refined = non_uniform.insert_knot(1.3).insert_knot(0.2).insert_knot(0.7);
+        assert_eq!(refined.points.len(), points.len() + 3);
+        for step in 0..=30 {
+            let t = step as f32 / 10.0;
+            assert!((refined.evaluate(t) - non_uniform.evaluate(t)).length() < 1e-5, "t {}", t);
+        }
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_points() {
+        let points = control_points();
+        for alpha in &[0.0, 0.5, 1.0] {
+            let spline = CatmullRom::new_from_points_and_alpha(&points, *alpha);
+            assert_eq!(spline.domain(), (0.0, 4.0));
+            for (index, point) in points[1..points.len() - 1].iter().enumerate() {
+                assert!((spline.evaluate(index as f32) - *point).length() < 1e-5);
+            }
+            // The tangent is continuous where the pieces meet.
+            for index in 1..spline.segment_count() {
+                let before = spline.segment(index - 1).derivative(1.0);
+                let after = spline.segment(index).derivative(0.0);
+                let (before, after) = ((1.0 / before.length()) * before, (1.0 / after.length()) * after);
+                assert!((before - after).length() < 1e-4);
+            }
+            assert_derivatives(&spline);
+        }
+
+        // With uniform parameters, points close together overshoot into a
+        // loop, which the centripetal parameterization avoids.
+        let points = [Vec2 { x: 0.0, y: 0.0 }, Vec2 { x: 0.0, y: 1.0 }, Vec2 { x: 0.05, y: 1.05 }, Vec2 { x: 1.0, y: 1.0 }];
+        let uniform = CatmullRom::new_from_points_and_alpha(&points, 0.0);
+        let centripetal = CatmullRom::new_centripetal(&points);
+        let highest = |spline: &CatmullRom<Vec2>| (0..=100).map(|step| spline.evaluate(step as f32 / 100.0).y).fold(0.0, f32::max);
+        assert!(highest(&centripetal) <= 1.05 + 1e-5);
+        assert!(highest(&uniform) > 1.05);
+    }
+
+    #[test]
+    fn arc_length_reparameterization() {
+        // A straight line whose speed varies along it.
+        let line = CubicBezier::new_from_points(&[Vec3 { x: 0.0, y: 0.0, z: 0.0 }, Vec3 { x: 0.1, y: 0.0, z: 0.0 }, Vec3 { x: 0.2, y: 0.0, z: 0.0 }, Vec3 { x: 3.0, y: 0.0, z: 0.0 }]);
+        assert!((line.arc_length() - 3.0).abs() < 1e-5);
+        let table = ArcLengthTable::new(&line, 4);
+        for step in 0..=12 {
+            let length = step as f32 * 0.25;
+            let t = table.parameter_at_length(&line, length);
+            assert!((line.evaluate(t).x - length).abs() < 1e-4, "length {}", length);
+        }
+
+        // A quarter circle of radius 1 is close to this Bezier.
+        let k = 0.552_284_8;
+        let arc = CubicBezier::new_from_points(&[Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 1.0, y: k, z: 0.0 }, Vec3 { x: k, y: 1.0, z: 0.0 }, Vec3 { x: 0.0, y: 1.0, z: 0.0 }]);
+        assert!((arc.arc_length() - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+
+        let spline = BSpline::new_clamped(&control_points(), 3);
+        let table = ArcLengthTable::new(&spline, 8);
+        let mut total = 0.0;
+        for piece in spline.breakpoints().windows(2) {
+            for step in 0..200 {
+                let t = piece[0] + (piece[1] - piece[0]) * step as f32 / 200.0;
+                let next = piece[0] + (piece[1] - piece[0]) * (step + 1) as f32 / 200.0;
+                total += (spline.evaluate(next) - spline.evaluate(t)).length();
+            }
+        }
+        assert!((table.length() - total).abs() < 1e-3 * total);
+        let t = table.parameter_at_length(&spline, 0.5 * total);
+        assert!((spline.arc_length_between(0.0, t) - 0.5 * total).abs() < 1e-3);
+    }
+
+    #[test]
+    fn nearest_point_matches_dense_sampling() {
+        let spline = CatmullRom::new_centripetal(&control_points());
+        let queries = [Vec3 { x: 2.0, y: 3.0, z: 0.0 }, Vec3 { x: 5.0, y: -2.0, z: 3.0 }, Vec3 { x: -1.0, y: 0.0, z: 0.0 }, Vec3 { x: 4.0, y: 1.0, z: 1.0 }];
+        for query in &queries {
+            let (t, point) = spline.nearest_point(query);
+            assert_eq!(point, spline.evaluate(t));
+            let brute = (0..=4000)
+                .map(|step| (spline.evaluate(step as f32 / 1000.0) - *query).length())
+                .fold(f32::INFINITY, f32::min);
+            let found = (point - *query).length();
+            assert!(found <= brute + 1e-5, "{:?}: {} vs {}", query, found, brute);
+            assert!(found > brute - 1e-3);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Decomposition of a Mat4 into translation, rotation, scale, shear and
+//! perspective.
+
+use super::{Mat3, Mat4, Vec3, Vec4};
+
+/// The components of a Mat4 as returned by `Mat4::decompose`.
+///
+/// The original Mat4 is rebuilt as `P * T * R * H * S`, where `T` translates,
+/// `R` rotates, `H` is the upper triangular shear matrix
+///
+/// ```plaintext
+///     [ 1  xy  xz ]
+/// H = [ 0   1  yz ]
+///     [ 0   0   1 ]
+/// ```
+///
+/// using the x, y and z components of `shear` for `xy`, `xz` and `yz`, `S`
+/// scales and `P` is the identity with its bottom row replaced by
+/// `perspective`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Decomposition {
+    pub translation: Vec3,
+    pub rotation: Mat3,
+    pub scale: Vec3,
+    pub shear: Vec3,
+    pub perspective: Vec4,
+}
+
+impl Decomposition {
+    /// Rebuilds the Mat4 the Decomposition was taken from.
+    pub fn recompose(&self) -> Mat4 {
+        let shear = Mat3 {
+            a: 1.0, b: self.shear.x, c: self.shear.y,
+            d: 0.0, e: 1.0,          f: self.shear.z,
+            g: 0.0, h: 0.0,          i: 1.0
+        };
+        let affine = Mat4::new_from_trs(&self.translation, &(self.rotation * shear), &self.scale);
+        let mut perspective = Mat4::identity();
+        perspective.m = self.perspective.x;
+        perspective.n = self.perspective.y;
+        perspective.o = self.perspective.z;
+        perspective.p = self.perspective.w;
+
+        perspective * affine
+    }
+}
+
+impl Mat4 {
+    /// Splits the Mat4 into translation, rotation, scale, shear and
+    /// perspective, following Spencer W. Thomas' "Decomposing a Matrix into
+    /// Simple Transformations" from Graphics Gems II.
+    ///
+    /// The rotation is always a proper rotation with a determinant of 1. If
+    /// the Mat4 mirrors space, the mirroring is reported as a negative x
+    /// scale. Returns `None` if the Mat4 is singular or its bottom right
+    /// element is 0.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Mat3, Mat4, Vec3};
+    /// let translation = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+    /// let scale = Vec3 { x: 2.0, y: 2.0, z: 2.0 };
+    /// let mat4 = Mat4::new_from_trs(&translation, &Mat3::identity(), &scale);
+    /// let decomposition = mat4.decompose().unwrap();
+    /// assert_eq!(decomposition.translation, translation);
+    /// assert_eq!(decomposition.scale, scale);
+    /// ```
+    pub fn decompose(&self) -> Option<Decomposition> {
+        if self.p == 0.0 {
+            return None;
+        }
+        let normalized = (1.0 / self.p) * *self;
+
+        // The perspective row is whatever is left of the bottom row after
+        // removing the affine part, i.e. `bottom_row * affine⁻¹`.
+        let mut affine = normalized;
+        affine.m = 0.0;
+        affine.n = 0.0;
+        affine.o = 0.0;
+        affine.p = 1.0;
+        let bottom_row = Vec4 { x: normalized.m, y: normalized.n, z: normalized.o, w: normalized.p };
+        let perspective = if bottom_row == (Vec4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }) {
+            bottom_row
+        } else {
+            affine.inverse()?.transpose() * bottom_row
+        };
+
+        let translation = Vec3 { x: affine.d, y: affine.h, z: affine.l };
+
+        let upper = affine.to_mat3();
+        let determinant = upper.determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+        let columns = upper.to_vec3_array();
+        let mirror = if determinant < 0.0 { -1.0 } else { 1.0 };
+        let mut x_axis = mirror * columns[0];
+        let mut y_axis = columns[1];
+        let mut z_axis = columns[2];
+
+        // Gram-Schmidt orthonormalization, recording how much of each earlier
+        // axis had to be removed as shear.
+        let x_scale = x_axis.length();
+        x_axis = (1.0 / x_scale) * x_axis;
+
+        let mut shear_xy = x_axis * y_axis;
+        y_axis -= shear_xy * x_axis;
+        let y_scale = y_axis.length();
+        y_axis = (1.0 / y_scale) * y_axis;
+        shear_xy /= y_scale;
+
+        let mut shear_xz = x_axis * z_axis;
+        z_axis -= shear_xz * x_axis;
+        let mut shear_yz = y_axis * z_axis;
+        z_axis -= shear_yz * y_axis;
+        let z_scale = z_axis.length();
+        z_axis = (1.0 / z_scale) * z_axis;
+        shear_xz /= z_scale;
+        shear_yz /= z_scale;
+
+        Some(Decomposition {
+            translation,
+            rotation: Mat3 {
+                a: x_axis.x, b: y_axis.x, c: z_axis.x,
+                d: x_axis.y, e: y_axis.y, f: z_axis.y,
+                g: x_axis.z, h: y_axis.z, i: z_axis.z
+            },
+            scale: Vec3 { x: mirror * x_scale, y: y_scale, z: z_scale },
+            shear: Vec3 { x: shear_xy, y: shear_xz, z: shear_yz },
+            perspective,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decomposition, Mat3, Mat4, Vec3, Vec4};
+    use crate::EulerRot;
+    use crate::test_values::{assert_mat4_near, assert_vec3_near};
+
+    #[test]
+    fn decompose_trs() {
+        let translation = Vec3 { x: 1.0, y: -2.0, z: 3.0 };
+        let rotation = Mat3::new_from_euler(EulerRot::XYZ, 0.3, -0.2, 1.1);
+        let scale = Vec3 { x: 2.0, y: 0.5, z: 3.0 };
+        let mat4 = Mat4::new_from_trs(&translation, &rotation, &scale);
+
+        let decomposition = mat4.decompose().unwrap();
+        assert_vec3_near(&decomposition.translation, &translation);
+        assert_vec3_near(&decomposition.scale, &scale);
+        assert_vec3_near(&decomposition.shear, &Vec3::new());
+        assert_eq!(decomposition.perspective, Vec4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 });
+        assert_mat4_near(&Mat4::new_from_mat3(&decomposition.rotation), &Mat4::new_from_mat3(&rotation));
+    }
+
+    #[test]
+    fn decompose_mirrored_mat4() {
+        let rotation = Mat3::new_from_rotation_y(0.7);
+        let scale = Vec3 { x: 1.0, y: -2.0, z: 1.5 };
+        let mat4 = Mat4::new_from_trs(&Vec3::new(), &rotation, &scale);
+
+        let decomposition = mat4.decompose().unwrap();
+        assert!((decomposition.rotation.determinant() - 1.0).abs() < 1e-5);
+        assert!(decomposition.scale.x < 0.0);
+        assert_mat4_near(
+            &Mat4::new_from_trs(&decomposition.translation, &decomposition.rotation, &decomposition.scale),
+            &mat4
+        );
+    }
+
+    #[test]
+    fn decompose_shear_and_perspective() {
+        let array = [ 2.0, 3.0, 5.0, -1.0, 7.0, 1.0, 2.0, 0.0, 5.0, 1.0, 0.0, 2.5, 8.0, 1.0, 1.0, 3.25 ];
+        let mat4 = Mat4::new_from_array(&array);
+        let decomposition = mat4.decompose().unwrap();
+        assert_mat4_near(&decomposition.recompose(), &((1.0 / mat4.p) * mat4));
+    }
+
+    #[test]
+    fn decompose_singular_mat4() {
+        let mut mat4 = Mat4::identity();
+        mat4.f = 0.0;
+        assert_eq!(mat4.decompose(), None);
+        assert_eq!(Mat4::new().decompose(), None);
+    }
+
+    #[test]
+    fn recompose_identity() {
+        let decomposition = Decomposition {
+            translation: Vec3::new(),
+            rotation: Mat3::identity(),
+            scale: Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+            shear: Vec3::new(),
+            perspective: Vec4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+        };
+        assert_eq!(decomposition.recompose(), Mat4::identity());
+    }
+}
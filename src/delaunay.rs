@@ -0,0 +1,786 @@
+//! Delaunay triangulations of 2D point sets, with optional constrained
+//! edges, and their dual Voronoi diagrams.
+//!
+//! Points are inserted one at a time with the Bowyer–Watson algorithm, which
+//! removes every triangle whose circumcircle contains the new point and fills
+//! the hole with triangles fanning out from it. The outside of the hull is
+//! covered by "ghost" triangles sharing a vertex at infinity, so points
+//! outside the current hull are inserted the same way as points inside it.
+//! Constrained edges are inserted afterwards by flipping the edges they
+//! cross until they appear, and then flipping the new edges back towards
+//! Delaunay. Every decision uses the exact predicates, so cocircular and
+//! collinear points can't make the triangulation inconsistent.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{incircle, orient2d, Sign, Vec2};
+
+/// The vertex at infinity shared by every ghost triangle.
+const GHOST: usize = usize::MAX;
+
+/// One side of an edge of a `Triangulation`.
+///
+/// The half-edges of triangle `t` are `3 * t`, `3 * t + 1` and `3 * t + 2`,
+/// in counter-clockwise order, each starting at the vertex the previous one
+/// ends at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HalfEdge {
+    /// The index of the point the half-edge starts at.
+    pub origin: usize,
+    /// The half-edge running the other way along the same edge, or `None`
+    /// on the boundary of the hull.
+    pub twin: Option<usize>,
+    /// Whether the edge was one of the constrained edges.
+    pub constrained: bool,
+}
+
+/// A triangulation of a set of points, stored as half-edges.
+///
+/// Example:
+/// ```
+/// # use matriarch::{Triangulation, Vec2};
+/// let points = [
+///     Vec2 { x: 0.0, y: 0.0 },
+///     Vec2 { x: 1.0, y: 0.0 },
+///     Vec2 { x: 1.0, y: 1.0 },
+///     Vec2 { x: 0.0, y: 1.0 },
+///     Vec2 { x: 0.5, y: 0.4 },
+/// ];
+/// let triangulation = Triangulation::new_from_points(&points);
+/// assert_eq!(triangulation.triangle_count(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangulation {
+    /// The points that were triangulated, which the half-edges index into.
+    pub points: Vec<Vec2>,
+    pub half_edges: Vec<HalfEdge>,
+}
+
+/// A Voronoi diagram, the dual of a Delaunay triangulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voronoi {
+    /// The circumcenter of each triangle, with the same indices as the
+    /// triangles.
+    pub vertices: Vec<Vec2>,
+    /// The cell of each point in the triangulation, in order of the points.
+    pub cells: Vec<VoronoiCell>,
+}
+
+/// The region of a Voronoi diagram closer to one point than to any other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoronoiCell {
+    /// The index of the point the cell belongs to.
+    pub site: usize,
+    /// The indices of the cell's corners in counter-clockwise order. Where
+    /// points are cocircular, the same position appears more than once.
+    pub vertices: Vec<usize>,
+    /// For cells of points on the hull, which are unbounded, the unit
+    /// directions of the two rays bounding the cell, both pointing outward
+    /// from the hull: one from the first vertex and one from the last.
+    pub rays: Option<(Vec2, Vec2)>,
+}
+
+impl Triangulation {
+    /// Returns the Delaunay triangulation of the points.
+    ///
+    /// Duplicate points are triangulated once, using the lowest index. If
+    /// there are fewer than three distinct points, or they're all collinear,
+    /// the triangulation has no triangles. Points with a NaN coordinate don't
+    /// panic, but make the triangulation meaningless.
+    pub fn new_from_points(points: &[Vec2]) -> Triangulation {
+        Triangulation::new_from_points_and_edges(points, &[])
+    }
+
+    /// Returns the constrained Delaunay triangulation of the points, which
+    /// contains each of the given edges between pairs of points and is as
+    /// close to Delaunay as they allow.
+    ///
+    /// An edge passing exactly through another point is split there. Edges
+    /// between duplicate points use the lowest index.
+    ///
+    /// Panics if two of the edges cross.
+    pub fn new_from_points_and_edges(points: &[Vec2], edges: &[[usize; 2]]) -> Triangulation {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        order.sort_by(|a, b| {
+            let x = points[*a].x.total_cmp(&points[*b].x);
+            x.then(points[*a].y.total_cmp(&points[*b].y)).then(a.cmp(b))
+        });
+        let mut representative: Vec<usize> = (0..points.len()).collect();
+        for pair in order.windows(2) {
+            if points[pair[0]] == points[pair[1]] {
+                representative[pair[1]] = representative[pair[0]];
+            }
+        }
+        order.dedup_by(|a, b| points[*a] == points[*b]);
+
+        let mut builder = match Builder::new(points, &order) {
+            Some(builder) => builder,
+            None => return Triangulation { points: points.to_vec(), half_edges: Vec::new() },
+        };
+        for edge in edges {
+            builder.insert_edge(representative[edge[0]], representative[edge[1]]);
+        }
+        builder.finish()
+    }
+
+    /// Returns the number of triangles.
+    pub fn triangle_count(&self) -> usize {
+        self.half_edges.len() / 3
+    }
+
+    /// Returns the indices of the corners of a triangle, in counter-clockwise
+    /// order.
+    pub fn triangle(&self, triangle: usize) -> [usize; 3] {
+        let first = 3 * triangle;
+        [self.half_edges[first].origin, self.half_edges[first + 1].origin, self.half_edges[first + 2].origin]
+    }
+
+    /// Returns the corners of every triangle, in counter-clockwise order.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        (0..self.triangle_count()).map(|triangle| self.triangle(triangle)).collect()
+    }
+
+    /// Returns the next half-edge around the same triangle.
+    pub fn next_half_edge(&self, half_edge: usize) -> usize {
+        next(half_edge)
+    }
+
+    /// Returns the previous half-edge around the same triangle.
+    pub fn prev_half_edge(&self, half_edge: usize) -> usize {
+        prev(half_edge)
+    }
+
+    /// Returns the index of the point the half-edge ends at.
+    pub fn destination(&self, half_edge: usize) -> usize {
+        self.half_edges[next(half_edge)].origin
+    }
+
+    /// Returns the Voronoi diagram dual to the triangulation. For a
+    /// constrained triangulation the result is the dual graph, but it isn't
+    /// a true Voronoi diagram.
+    pub fn voronoi(&self) -> Voronoi {
+        let vertices = (0..self.triangle_count())
+            .map(|triangle| {
+                let [a, b, c] = self.triangle(triangle);
+                circumcenter(&self.points[a], &self.points[b], &self.points[c])
+            })
+            .collect();
+
+        // Start each point's walk from a hull half-edge leaving it if there
+        // is one, so that open cells are walked from end to end.
+        let mut starts: Vec<Option<usize>> = vec![None; self.points.len()];
+        for (index, half_edge) in self.half_edges.iter().enumerate() {
+            let start = &mut starts[half_edge.origin];
+            if start.is_none() || half_edge.twin.is_none() {
+                *start = Some(index);
+            }
+        }
+
+        let outward = |from: usize, to: usize| {
+            let edge = self.points[to] - self.points[from];
+            (1.0 / edge.length()) * Vec2 { x: edge.y, y: -edge.x }
+        };
+        let cells = starts
+            .iter()
+            .enumerate()
+            .filter_map(|(site, start)| start.map(|start| (site, start)))
+            .map(|(site, start)| {
+                let mut vertices = Vec::new();
+                let mut current = start;
+                let rays = loop {
+                    vertices.push(current / 3);
+                    let incoming = prev(current);
+                    match self.half_edges[incoming].twin {
+                        Some(twin) if twin == start => break None,
+                        Some(twin) => current = twin,
+                        None => {
+                            let first = outward(site, self.destination(start));
+                            let last = outward(self.half_edges[incoming].origin, site);
+                            break Some((first, last));
+                        }
+                    }
+                };
+                VoronoiCell { site, vertices, rays }
+            })
+            .collect();
+        Voronoi { vertices, cells }
+    }
+}
+
+fn next(half_edge: usize) -> usize {
+    3 * (half_edge / 3) + (half_edge + 1) % 3
+}
+
+fn prev(half_edge: usize) -> usize {
+    3 * (half_edge / 3) + (half_edge + 2) % 3
+}
+
+/// Returns true if the signs are strictly opposite.
+fn opposite(first: Sign, second: Sign) -> bool {
+    matches!((first, second), (Sign::Positive, Sign::Negative) | (Sign::Negative, Sign::Positive))
+}
+
+/// Returns the center of the circle through three points, computed in f64.
+fn circumcenter(a: &Vec2, b: &Vec2, c: &Vec2) -> Vec2 {
+    let (bx, by) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let (cx, cy) = (c.x as f64 - a.x as f64, c.y as f64 - a.y as f64);
+    let (b_squared, c_squared) = (bx * bx + by * by, cx * cx + cy * cy);
+    let d = 2.0 * (bx * cy - by * cx);
+    Vec2 {
+        x: (a.x as f64 + (cy * b_squared - by * c_squared) / d) as f32,
+        y: (a.y as f64 + (bx * c_squared - cx * b_squared) / d) as f32,
+    }
+}
+
+/// Returns true if `p`, which is collinear with `a` and `b`, lies strictly
+/// between them.
+fn strictly_between(a: &Vec2, b: &Vec2, p: &Vec2) -> bool {
+    p != a
+        && p != b
+        && a.x.min(b.x) <= p.x
+        && p.x <= a.x.max(b.x)
+        && a.y.min(b.y) <= p.y
+        && p.y <= a.y.max(b.y)
+}
+
+/// Returns the key of an undirected edge.
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+/// The triangulation under construction, including its ghost triangles.
+struct Builder<'a> {
+    points: &'a [Vec2],
+    origins: Vec<usize>,
+    twins: Vec<usize>,
+    removed: Vec<bool>,
+    free: Vec<usize>,
+    /// The most recently created triangle, where point location starts.
+    last: usize,
+    constrained: HashSet<(usize, usize)>,
+    /// The half-edge between each pair of points, kept up to date while
+    /// constrained edges are inserted.
+    edges: HashMap<(usize, usize), usize>,
+    /// A half-edge leaving each point, kept up to date alongside `edges`.
+    leaving: Vec<usize>,
+}
+
+impl<'a> Builder<'a> {
+    /// Starts from the first two points and the first point after them that
+    /// isn't collinear, then inserts the rest. Returns `None` if every point
+    /// is collinear.
+    fn new(points: &'a [Vec2], order: &[usize]) -> Option<Builder<'a>> {
+        let (a, b) = (*order.first()?, *order.get(1)?);
+        let c = *order[2..].iter().find(|index| orient2d(&points[a], &points[b], &points[**index]) != Sign::Zero)?;
+        let mut builder = Builder {
+            points,
+            origins: Vec::new(),
+            twins: Vec::new(),
+            removed: Vec::new(),
+            free: Vec::new(),
+            last: 0,
+            constrained: HashSet::new(),
+            edges: HashMap::new(),
+            leaving: Vec::new(),
+        };
+        let first = match orient2d(&points[a], &points[b], &points[c]) {
+            Sign::Positive => builder.add_triangle([a, b, c]),
+            _ => builder.add_triangle([a, c, b]),
+        };
+        let mut ghosts = [0; 3];
+        for (k, ghost) in ghosts.iter_mut().enumerate() {
+            let (x, y) = (builder.origins[3 * first + k], builder.origins[next(3 * first + k)]);
+            *ghost = builder.add_triangle([y, x, GHOST]);
+            builder.link(3 * first + k, 3 * *ghost);
+        }
+        for k in 0..3 {
+            builder.link(3 * ghosts[k] + 1, 3 * ghosts[(k + 2) % 3] + 2);
+        }
+
+        for index in order[2..].iter().filter(|index| **index != c) {
+            builder.insert_point(*index);
+        }
+        Some(builder)
+    }
+
+    fn add_triangle(&mut self, vertices: [usize; 3]) -> usize {
+        let triangle = match self.free.pop() {
+            Some(triangle) => {
+                self.origins[3 * triangle..3 * triangle + 3].copy_from_slice(&vertices);
+                self.removed[triangle] = false;
+                triangle
+            }
+            None => {
+                self.origins.extend_from_slice(&vertices);
+                self.twins.extend_from_slice(&[GHOST; 3]);
+                self.removed.push(false);
+                self.removed.len() - 1
+            }
+        };
+        self.last = triangle;
+        triangle
+    }
+
+    fn link(&mut self, a: usize, b: usize) {
+        self.twins[a] = b;
+        self.twins[b] = a;
+    }
+
+    /// Returns the position of the vertex at infinity in a ghost triangle.
+    fn ghost_corner(&self, triangle: usize) -> Option<usize> {
+        (0..3).find(|k| self.origins[3 * triangle + k] == GHOST)
+    }
+
+    /// Returns true if the triangle's circumcircle strictly contains the
+    /// point. The circumcircle of a ghost triangle is the open half-plane
+    /// outside its hull edge, plus the open edge itself.
+    fn in_conflict(&self, triangle: usize, point: usize) -> bool {
+        let p = &self.points[point];
+        match self.ghost_corner(triangle) {
+            Some(k) => {
+                let u = &self.points[self.origins[3 * triangle + (k + 1) % 3]];
+                let v = &self.points[self.origins[3 * triangle + (k + 2) % 3]];
+                match orient2d(u, v, p) {
+                    Sign::Positive => true,
+                    Sign::Zero => strictly_between(u, v, p),
+                    Sign::Negative => false,
+                }
+            }
+            None => {
+                let [a, b, c] = [0, 1, 2].map(|k| &self.points[self.origins[3 * triangle + k]]);
+                incircle(a, b, c, p) == Sign::Positive
+            }
+        }
+    }
+
+    /// Walks from the last triangle towards the point, returning a triangle
+    /// whose circumcircle contains it.
+    fn locate(&self, point: usize) -> usize {
+        let p = &self.points[point];
+        let mut triangle = self.last;
+        let mut steps = 0;
+        loop {
+            if let Some(k) = self.ghost_corner(triangle) {
+                if self.in_conflict(triangle, point) {
+                    return triangle;
+                }
+                triangle = self.twins[3 * triangle + (k + 1) % 3] / 3;
+                continue;
+            }
+            // Varying the first edge tried stops the walk from cycling.
+            steps += 1;
+            let crossed = (0..3).map(|j| 3 * triangle + (j + steps) % 3).find(|half_edge| {
+                let a = &self.points[self.origins[*half_edge]];
+                let b = &self.points[self.origins[next(*half_edge)]];
+                orient2d(a, b, p) == Sign::Negative
+            });
+            match crossed {
+                Some(half_edge) => triangle = self.twins[half_edge] / 3,
+                None => return triangle,
+            }
+        }
+    }
+
+    fn insert_point(&mut self, point: usize) {
+        let start = self.locate(point);
+        let mut cavity = vec![start];
+        let mut in_cavity: HashSet<usize> = cavity.iter().cloned().collect();
+        let mut i = 0;
+        while i < cavity.len() {
+            for k in 0..3 {
+                let neighbour = self.twins[3 * cavity[i] + k] / 3;
+                if !in_cavity.contains(&neighbour) && self.in_conflict(neighbour, point) {
+                    in_cavity.insert(neighbour);
+                    cavity.push(neighbour);
+                }
+            }
+            i += 1;
+        }
+
+        let mut boundary = Vec::new();
+        for triangle in &cavity {
+            for half_edge in 3 * triangle..3 * triangle + 3 {
+                if !in_cavity.contains(&(self.twins[half_edge] / 3)) {
+                    boundary.push((self.origins[half_edge], self.origins[next(half_edge)], self.twins[half_edge]));
+                }
+            }
+            self.removed[*triangle] = true;
+            self.free.push(*triangle);
+        }
+
+        let mut by_origin = HashMap::new();
+        for (x, y, outer) in &boundary {
+            let triangle = self.add_triangle([*x, *y, point]);
+            self.link(3 * triangle, *outer);
+            by_origin.insert(*x, triangle);
+        }
+        // Each new triangle's edge into the point is the twin of the edge out
+        // of it in the triangle built on the following boundary edge.
+        for (x, y, _) in &boundary {
+            self.link(3 * by_origin[x] + 1, 3 * by_origin[y] + 2);
+        }
+    }
+
+    /// Inserts a constrained edge by flipping every edge crossing it, then
+    /// restores the Delaunay property around it.
+    fn insert_edge(&mut self, u: usize, v: usize) {
+        if u == v {
+            return;
+        }
+        if self.edges.is_empty() {
+            self.leaving = vec![GHOST; self.points.len()];
+            for half_edge in 0..self.origins.len() {
+                if !self.removed[half_edge / 3] {
+                    let origin = self.origins[half_edge];
+                    self.edges.insert((origin, self.origins[next(half_edge)]), half_edge);
+                    if origin != GHOST {
+                        self.leaving[origin] = half_edge;
+                    }
+                }
+            }
+        }
+
+        // An edge passing exactly through another point is inserted up to
+        // it, and the rest of the edge continues from there.
+        let (mut crossing, through) = self.crossed_edges(u, v);
+        let end = through.unwrap_or(v);
+        let mut created = Vec::new();
+        while let Some((a, b)) = crossing.pop_front() {
+            let half_edge = self.edges[&(a, b)];
+            let c = self.origins[prev(half_edge)];
+            let d = self.origins[prev(self.twins[half_edge])];
+            let (pc, pd) = (&self.points[c], &self.points[d]);
+            let convex = opposite(orient2d(pc, pd, &self.points[a]), orient2d(pc, pd, &self.points[b]));
+            if !convex {
+                crossing.push_back((a, b));
+                continue;
+            }
+            self.flip(half_edge);
+            if self.crosses(u, end, c, d) {
+                crossing.push_back((c, d));
+            } else {
+                created.push((c, d));
+            }
+        }
+        self.constrained.insert(edge_key(u, end));
+
+        let mut flipped = true;
+        while flipped {
+            flipped = false;
+            for edge in created.iter_mut() {
+                let (c, d) = *edge;
+                if self.constrained.contains(&edge_key(c, d)) {
+                    continue;
+                }
+                let half_edge = self.edges[&(c, d)];
+                let a = self.origins[prev(half_edge)];
+                let b = self.origins[prev(self.twins[half_edge])];
+                if a == GHOST || b == GHOST {
+                    continue;
+                }
+                let [pc, pd, pa, pb] = [c, d, a, b].map(|index| &self.points[index]);
+                if incircle(pc, pd, pa, pb) == Sign::Positive {
+                    self.flip(half_edge);
+                    *edge = (a, b);
+                    flipped = true;
+                }
+            }
+        }
+
+        if let Some(w) = through {
+            self.insert_edge(w, v);
+        }
+    }
+
+    /// Walks from `u` towards `v` through the triangles the segment between
+    /// them passes through, returning the edges it crosses in order, and the
+    /// first point it passes through exactly if it stops short of `v` at
+    /// one.
+    ///
+    /// Panics if one of the crossed edges is constrained.
+    fn crossed_edges(&self, u: usize, v: usize) -> (VecDeque<(usize, usize)>, Option<usize>) {
+        let (pu, pv) = (&self.points[u], &self.points[v]);
+        let mut crossing = VecDeque::new();
+
+        // Turn around u until reaching the triangle whose corner at u
+        // contains the segment. Because the segment is within the hull, that
+        // triangle isn't a ghost.
+        let mut half_edge = self.leaving[u];
+        loop {
+            let x = self.origins[next(half_edge)];
+            let y = self.origins[prev(half_edge)];
+            if x == v {
+                return (crossing, None);
+            }
+            if x != GHOST {
+                let side = orient2d(pu, pv, &self.points[x]);
+                if side == Sign::Zero && strictly_between(pu, pv, &self.points[x]) {
+                    return (crossing, Some(x));
+                }
+                if y != GHOST && side == Sign::Negative && orient2d(pu, pv, &self.points[y]) == Sign::Positive {
+                    break;
+                }
+            }
+            half_edge = self.twins[prev(half_edge)];
+        }
+
+        // Each crossed half-edge runs from the right of the segment to its
+        // left, and the triangle on its far side decides which of its other
+        // edges is crossed next.
+        let mut crossed = next(half_edge);
+        loop {
+            let (right, left) = (self.origins[crossed], self.origins[next(crossed)]);
+            assert!(
+                !self.constrained.contains(&edge_key(right, left)),
+                "constrained edges {:?} and {:?} cross",
+                (u, v),
+                edge_key(right, left)
+            );
+            crossing.push_back(edge_key(right, left));
+            let twin = self.twins[crossed];
+            let w = self.origins[prev(twin)];
+            if w == v {
+                return (crossing, None);
+            }
+            match orient2d(pu, pv, &self.points[w]) {
+                Sign::Zero => return (crossing, Some(w)),
+                Sign::Positive => crossed = next(twin),
+                Sign::Negative => crossed = prev(twin),
+            }
+        }
+    }
+
+    /// Returns true if the edges `u`-`v` and `a`-`b` cross at a point inside
+    /// both of them.
+    fn crosses(&self, u: usize, v: usize, a: usize, b: usize) -> bool {
+        let [pu, pv, pa, pb] = [u, v, a, b].map(|index| &self.points[index]);
+        opposite(orient2d(pu, pv, pa), orient2d(pu, pv, pb)) && opposite(orient2d(pa, pb, pu), orient2d(pa, pb, pv))
+    }
+
+    /// Replaces the edge between two triangles with the other diagonal of
+    /// the quadrilateral they form.
+    fn flip(&mut self, half_edge: usize) {
+        let twin = self.twins[half_edge];
+        let (first, second) = (half_edge / 3, twin / 3);
+        let a = self.origins[half_edge];
+        let b = self.origins[next(half_edge)];
+        let c = self.origins[prev(half_edge)];
+        let d = self.origins[prev(twin)];
+        let outer_bc = self.twins[next(half_edge)];
+        let outer_ca = self.twins[prev(half_edge)];
+        let outer_ad = self.twins[next(twin)];
+        let outer_db = self.twins[prev(twin)];
+
+        self.edges.remove(&(a, b));
+        self.edges.remove(&(b, a));
+        self.origins[3 * first..3 * first + 3].copy_from_slice(&[a, d, c]);
+        self.origins[3 * second..3 * second + 3].copy_from_slice(&[d, b, c]);
+        self.link(3 * first, outer_ad);
+        self.link(3 * first + 1, 3 * second + 2);
+        self.link(3 * first + 2, outer_ca);
+        self.link(3 * second, outer_db);
+        self.link(3 * second + 1, outer_bc);
+        for half_edge in (3 * first..3 * first + 3).chain(3 * second..3 * second + 3) {
+            self.edges.insert((self.origins[half_edge], self.origins[next(half_edge)]), half_edge);
+            self.leaving[self.origins[half_edge]] = half_edge;
+        }
+    }
+
+    /// Returns the triangulation without its ghost triangles.
+    fn finish(self) -> Triangulation {
+        let real: Vec<usize> = (0..self.removed.len())
+            .filter(|triangle| !self.removed[*triangle] && self.ghost_corner(*triangle).is_none())
+            .collect();
+        let mut renumbered = vec![None; self.removed.len()];
+        for (index, triangle) in real.iter().enumerate() {
+            renumbered[*triangle] = Some(index);
+        }
+        let half_edges = real
+            .iter()
+            .flat_map(|triangle| 3 * triangle..3 * triangle + 3)
+            .map(|half_edge| {
+                let twin = self.twins[half_edge];
+                let origin = self.origins[half_edge];
+                HalfEdge {
+                    origin,
+                    twin: renumbered[twin / 3].map(|triangle| 3 * triangle + twin % 3),
+                    constrained: self.constrained.contains(&edge_key(origin, self.origins[next(half_edge)])),
+                }
+            })
+            .collect();
+        Triangulation { points: self.points.to_vec(), half_edges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, orient2d, Sign, Triangulation, Vec2};
+    use crate::test_values::grid_values;
+
+    fn grid(size: usize) -> Vec<Vec2> {
+        (0..size * size).map(|i| Vec2 { x: (i % size) as f32, y: (i / size) as f32 }).collect()
+    }
+
+    /// Checks the half-edges are consistent, every triangle is
+    /// counter-clockwise, and every unconstrained edge is locally Delaunay.
+    fn assert_valid(triangulation: &Triangulation) {
+        let points = &triangulation.points;
+        for (index, half_edge) in triangulation.half_edges.iter().enumerate() {
+            let next = triangulation.next_half_edge(index);
+            assert_eq!(triangulation.prev_half_edge(next), index);
+            if let Some(twin) = half_edge.twin {
+                assert_eq!(triangulation.half_edges[twin].twin, Some(index));
+                assert_eq!(triangulation.half_edges[twin].origin, triangulation.destination(index));
+                assert_eq!(triangulation.half_edges[twin].constrained, half_edge.constrained);
+                if !half_edge.constrained {
+                    let [a, b, c] = triangulation.triangle(index / 3);
+                    let opposite = triangulation.half_edges[triangulation.prev_half_edge(twin)].origin;
+                    assert_ne!(incircle(&points[a], &points[b], &points[c], &points[opposite]), Sign::Positive);
+                }
+            }
+        }
+        for [a, b, c] in triangulation.triangles() {
+            assert_eq!(orient2d(&points[a], &points[b], &points[c]), Sign::Positive);
+        }
+    }
+
+    /// Returns the number of distinct points on the hull, including ones in
+    /// the middle of hull edges.
+    fn hull_vertex_count(triangulation: &Triangulation) -> usize {
+        triangulation.half_edges.iter().filter(|half_edge| half_edge.twin.is_none()).count()
+    }
+
+    #[test]
+    fn cocircular_grid() {
+        let points = grid(5);
+        let triangulation = Triangulation::new_from_points(&points);
+        assert_valid(&triangulation);
+        assert_eq!(triangulation.triangle_count(), 32);
+        assert_eq!(hull_vertex_count(&triangulation), 16);
+    }
+
+    #[test]
+    fn empty_circumcircles() {
+        let coordinates = grid_values(2 * 300, 3, 40);
+        let points: Vec<Vec2> = coordinates.chunks(2).map(|c| Vec2 { x: c[0], y: c[1] }).collect();
+        let triangulation = Triangulation::new_from_points(&points);
+        assert_valid(&triangulation);
+        for [a, b, c] in triangulation.triangles() {
+            for point in &points {
+                assert_ne!(incircle(&points[a], &points[b], &points[c], point), Sign::Positive);
+            }
+        }
+        // Every distinct point is used, and Euler's formula holds.
+        let mut distinct = points.clone();
+        distinct.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        distinct.dedup();
+        let hull = hull_vertex_count(&triangulation);
+        assert_eq!(triangulation.triangle_count(), 2 * distinct.len() - 2 - hull);
+    }
+
+    #[test]
+    fn duplicate_and_collinear_points() {
+        let mut points = grid(3);
+        points.push(points[4]);
+        points.push(points[0]);
+        let triangulation = Triangulation::new_from_points(&points);
+        assert_valid(&triangulation);
+        assert_eq!(triangulation.triangle_count(), 8);
+        assert!(triangulation.half_edges.iter().all(|half_edge| half_edge.origin < 9));
+
+        let line: Vec<Vec2> = (0..4).map(|i| Vec2 { x: i as f32, y: 2.0 * i as f32 }).collect();
+        assert_eq!(Triangulation::new_from_points(&line).triangle_count(), 0);
+        assert_eq!(Triangulation::new_from_points(&[]).triangle_count(), 0);
+        let mut with_nan = grid(3);
+        with_nan.push(Vec2 { x: f32::NAN, y: 0.5 });
+        Triangulation::new_from_points(&with_nan);
+    }
+
+    #[test]
+    fn constrained_edges() {
+        // A wide diamond whose Delaunay triangulation uses the short vertical
+        // diagonal, with extra points either side.
+        let mut points = vec![
+            Vec2 { x: 0.0, y: 0.0 },
+            Vec2 { x: 10.0, y: 0.0 },
+            Vec2 { x: 5.0, y: -1.0 },
+            Vec2 { x: 5.0, y: 1.0 },
+        ];
+        // None of the extra points lie on the line y = 0.
+        let coordinates = grid_values(2 * 40, 7, 21);
+        points.extend(coordinates.chunks(2).map(|c| Vec2 { x: 0.5 * c[0], y: 0.5 * c[1] - 5.25 }));
+        let has_edge = |triangulation: &Triangulation, a: usize, b: usize| {
+            (0..triangulation.half_edges.len()).any(|index| {
+                let half_edge = triangulation.half_edges[index];
+                half_edge.origin == a && triangulation.destination(index) == b && half_edge.constrained
+            })
+        };
+        let triangulation = Triangulation::new_from_points_and_edges(&points, &[[0, 1]]);
+        assert_valid(&triangulation);
+        assert!(has_edge(&triangulation, 0, 1) && has_edge(&triangulation, 1, 0));
+
+        // A point on the constraint splits it in two.
+        points.push(Vec2 { x: 5.0, y: 0.0 });
+        let middle = points.len() - 1;
+        let triangulation = Triangulation::new_from_points_and_edges(&points, &[[1, 0]]);
+        assert_valid(&triangulation);
+        assert!(has_edge(&triangulation, 0, middle) && has_edge(&triangulation, middle, 1));
+    }
+
+    #[test]
+    fn constraints_through_grid_points() {
+        // Parallel lines with a slope of 1/2, which pass through a grid point
+        // at every other column.
+        let size = 20;
+        let points = grid(size);
+        let edges: Vec<[usize; 2]> = (0..size - 9)
+            .flat_map(|row| (0..2).map(move |column| [row * size + column, (row + 9) * size + column + 18]))
+            .collect();
+        let triangulation = Triangulation::new_from_points_and_edges(&points, &edges);
+        assert_valid(&triangulation);
+        let constrained: Vec<(usize, usize)> = (0..triangulation.half_edges.len())
+            .filter(|index| triangulation.half_edges[*index].constrained)
+            .map(|index| (triangulation.half_edges[index].origin, triangulation.destination(index)))
+            .collect();
+        for [from, _] in &edges {
+            for step in 0..9 {
+                let a = from + step * (size + 2);
+                assert!(constrained.contains(&(a, a + size + 2)) && constrained.contains(&(a + size + 2, a)));
+            }
+        }
+        assert_eq!(constrained.len(), 2 * 9 * edges.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "cross")]
+    fn crossing_constraints_panic() {
+        let points = grid(2);
+        Triangulation::new_from_points_and_edges(&points, &[[0, 3], [1, 2]]);
+    }
+
+    #[test]
+    fn voronoi_of_grid() {
+        let points = grid(3);
+        let triangulation = Triangulation::new_from_points(&points);
+        let voronoi = triangulation.voronoi();
+        assert_eq!(voronoi.cells.len(), 9);
+        for (triangle, vertex) in voronoi.vertices.iter().enumerate() {
+            let [a, b, c] = triangulation.triangle(triangle);
+            let radius = (points[a] - *vertex).length();
+            assert!(((points[b] - *vertex).length() - radius).abs() < 1e-5);
+            assert!(((points[c] - *vertex).length() - radius).abs() < 1e-5);
+        }
+        // The center cell is the unit square around it.
+        let center = &voronoi.cells[4];
+        assert_eq!(center.site, 4);
+        assert!(center.rays.is_none());
+        for vertex in &center.vertices {
+            let offset = voronoi.vertices[*vertex] - points[4];
+            assert!((offset.x.abs() - 0.5).abs() < 1e-6 && (offset.y.abs() - 0.5).abs() < 1e-6);
+        }
+        // The corner cells are open, with rays pointing away from the grid.
+        let (first, last) = voronoi.cells[0].rays.unwrap();
+        assert!(first.x <= 0.0 && first.y <= 0.0 && last.x <= 0.0 && last.y <= 0.0);
+        assert!((first.length() - 1.0).abs() < 1e-6);
+    }
+}
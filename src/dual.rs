@@ -0,0 +1,324 @@
+//! Implementation of a Dual number scalar for forward-mode automatic
+//! differentiation.
+//!
+//! A Dual carries a value together with its derivative with respect to one
+//! chosen input. Every operation applies the chain rule as it goes, so
+//! evaluating a function on Duals returns its exact derivative alongside its
+//! value, with none of the truncation error of finite differences.
+
+use std::ops;
+
+use super::{Mat2, Mat3, Mat4, Scalar, Vec2, Vec3, Vec4};
+
+/// A Dual number `value + derivative ε`, where `ε² = 0`.
+///
+/// Example:
+/// ```
+/// # use matriarch::{Dual, Vec3};
+/// // The derivative of |(t, 2t, 2)| at t = 1.
+/// let t = Dual::variable(1.0);
+/// let vec3 = Vec3 { x: t, y: Dual::constant(2.0) * t, z: Dual::constant(2.0) };
+/// let length = vec3.length();
+/// assert_eq!(length.value, 3.0);
+/// assert!((length.derivative - 5.0 / 3.0).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Dual {
+    pub value: f32,
+    pub derivative: f32,
+}
+
+impl Dual {
+    /// Returns a new Dual using the given value and derivative.
+    pub fn new_from_values(value: &f32, derivative: &f32) -> Dual {
+        Dual { value: *value, derivative: *derivative }
+    }
+
+    /// Returns a Dual for a value that doesn't depend on the input, with a
+    /// derivative of 0.
+    pub fn constant(value: f32) -> Dual {
+        Dual { value, derivative: 0.0 }
+    }
+
+    /// Returns a Dual for the input being differentiated against, with a
+    /// derivative of 1.
+    pub fn variable(value: f32) -> Dual {
+        Dual { value, derivative: 1.0 }
+    }
+
+    /// Applies a function with the given value and derivative at
+    /// `self.value`, using the chain rule.
+    fn chain(&self, value: f32, derivative: f32) -> Dual {
+        Dual { value, derivative: derivative * self.derivative }
+    }
+
+    /// Returns the square root.
+    pub fn sqrt(&self) -> Dual {
+        let value = self.value.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    /// Returns the sine.
+    pub fn sin(&self) -> Dual {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(sin, cos)
+    }
+
+    /// Returns the cosine.
+    pub fn cos(&self) -> Dual {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(cos, -sin)
+    }
+
+    /// Returns the tangent.
+    pub fn tan(&self) -> Dual {
+        let cos = self.value.cos();
+        self.chain(self.value.tan(), 1.0 / (cos * cos))
+    }
+
+    /// Returns `e` raised to the Dual.
+    pub fn exp(&self) -> Dual {
+        let value = self.value.exp();
+        self.chain(value, value)
+    }
+
+    /// Returns the natural logarithm.
+    pub fn ln(&self) -> Dual {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    /// Returns the Dual raised to an integer power.
+    pub fn powi(&self, n: i32) -> Dual {
+        self.chain(self.value.powi(n), n as f32 * self.value.powi(n - 1))
+    }
+
+    /// Returns the absolute value, with the derivative taken from the
+    /// positive side at 0.
+    pub fn abs(&self) -> Dual {
+        if self.value < 0.0 {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl Scalar for Dual {
+    fn zero() -> Dual {
+        Dual::constant(0.0)
+    }
+
+    fn one() -> Dual {
+        Dual::constant(1.0)
+    }
+
+    fn from_f32(value: f32) -> Dual {
+        Dual::constant(value)
+    }
+
+    fn sqrt(self) -> Dual {
+        Dual::sqrt(&self)
+    }
+
+    /// Only the value matters, as the derivative doesn't affect whether it
+    /// can be divided by.
+    fn is_zero(self) -> bool {
+        self.value == 0.0
+    }
+}
+
+impl ops::Add<Dual> for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual { value: self.value + other.value, derivative: self.derivative + other.derivative }
+    }
+}
+
+impl ops::Sub<Dual> for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual { value: self.value - other.value, derivative: self.derivative - other.derivative }
+    }
+}
+
+impl ops::Mul<Dual> for Dual {
+    type Output = Dual;
+
+    /// Multiplies two Duals using the product rule.
+    fn mul(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value * other.value,
+            derivative: (self.derivative * other.value) + (self.value * other.derivative),
+        }
+    }
+}
+
+impl ops::Div<Dual> for Dual {
+    type Output = Dual;
+
+    /// Divides two Duals using the quotient rule.
+    fn div(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value / other.value,
+            derivative: ((self.derivative * other.value) - (self.value * other.derivative))
+                / (other.value * other.value),
+        }
+    }
+}
+
+impl ops::Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual { value: -self.value, derivative: -self.derivative }
+    }
+}
+
+impl ops::Mul<Dual> for f32 {
+    type Output = Dual;
+
+    /// Multiplies a constant by the Dual.
+    fn mul(self, dual: Dual) -> Dual {
+        Dual { value: self * dual.value, derivative: self * dual.derivative }
+    }
+}
+
+macro_rules! impl_dual_components {
+    ($name:ident { $($field:ident),+ }) => {
+        impl $name {
+            /// Returns the same value with Dual components, each a constant
+            /// with a derivative of 0.
+            pub fn to_dual(&self) -> $name<Dual> {
+                $name { $($field: Dual::constant(self.$field),)+ }
+            }
+        }
+
+        impl $name<Dual> {
+            /// Returns the value of each component.
+            pub fn value(&self) -> $name {
+                $name { $($field: self.$field.value,)+ }
+            }
+
+            /// Returns the derivative of each component.
+            pub fn derivative(&self) -> $name {
+                $name { $($field: self.$field.derivative,)+ }
+            }
+        }
+
+        impl ops::Mul<$name<Dual>> for Dual {
+            type Output = $name<Dual>;
+
+            /// Multiplies a Dual by each component.
+            fn mul(self, other: $name<Dual>) -> $name<Dual> {
+                $name { $($field: self * other.$field,)+ }
+            }
+        }
+    };
+}
+
+impl_dual_components!(Vec2 { x, y });
+impl_dual_components!(Vec3 { x, y, z });
+impl_dual_components!(Vec4 { x, y, z, w });
+impl_dual_components!(Mat2 { a, b, c, d });
+impl_dual_components!(Mat3 { a, b, c, d, e, f, g, h, i });
+impl_dual_components!(Mat4 { a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p });
+
+#[cfg(test)]
+mod tests {
+    use super::{Dual, Mat3, Mat4, Vec3, Vec4};
+    use crate::test_values::{assert_mat3_near, assert_near};
+
+    /// Returns the central difference of `f` at `x`, computed in f64 so that
+    /// it is accurate enough to check f32 derivatives against.
+    fn finite_difference<F: Fn(f64) -> f64>(f: F, x: f64) -> f64 {
+        let h = 1e-6;
+        (f(x + h) - f(x - h)) / (2.0 * h)
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let x = Dual::variable(0.7);
+        assert_near((x.sin() * x.exp()).derivative, finite_difference(|x| x.sin() * x.exp(), 0.7) as f32, 1e-4);
+        assert_near((x.cos() / x.sqrt()).derivative, finite_difference(|x| x.cos() / x.sqrt(), 0.7) as f32, 1e-4);
+        assert_near(x.tan().derivative, finite_difference(f64::tan, 0.7) as f32, 1e-4);
+        assert_near((x.ln() - x.powi(3)).derivative, finite_difference(|x| x.ln() - x.powi(3), 0.7) as f32, 1e-4);
+        assert_near((-x).abs().derivative, 1.0, 1e-4);
+        assert_eq!(Dual::constant(3.0).sin().derivative, 0.0);
+    }
+
+    #[test]
+    fn derivative_through_mat4_times_vec4() {
+        // The distance from the origin of a point rotated and then
+        // translated along x by the parameter.
+        let f = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            let point = [1.0, 2.0, 3.0];
+            let x = cos * point[0] - sin * point[1] + angle;
+            let y = sin * point[0] + cos * point[1];
+            (x * x + y * y + point[2] * point[2]).sqrt()
+        };
+        let angle = Dual::variable(0.4);
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let zero = Dual::constant(0.0);
+        let one = Dual::constant(1.0);
+        let mat4 = Mat4 {
+            a: cos,  b: -sin, c: zero, d: angle,
+            e: sin,  f: cos,  g: zero, h: zero,
+            i: zero, j: zero, k: one,  l: zero,
+            m: zero, n: zero, o: zero, p: one
+        };
+        let point = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 }.to_dual();
+        let transformed = mat4 * point;
+        let length = Vec3 { x: transformed.x, y: transformed.y, z: transformed.z }.length();
+        assert_near(length.value, f(0.4) as f32, 1e-4);
+        assert_near(length.derivative, finite_difference(f, 0.4) as f32, 1e-4);
+    }
+
+    #[test]
+    fn derivative_of_determinant_and_cross_product() {
+        // d/dt det(A + tB) at t = 0 is tr(adj(A) B).
+        let a = Mat3::new_from_array(&[2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+        let b = Mat3::new_from_array(&[0.5, 0.0, 1.0, 0.0, -1.0, 0.0, 2.0, 0.0, 1.0]);
+        let determinant = |t: f64| {
+            let m: Vec<f64> = a.to_array().iter().zip(b.to_array().iter()).map(|(a, b)| *a as f64 + t * *b as f64).collect();
+            m[0] * (m[4] * m[8] - m[5] * m[7]) - m[1] * (m[3] * m[8] - m[5] * m[6]) + m[2] * (m[3] * m[7] - m[4] * m[6])
+        };
+        let t = Dual::variable(0.0);
+        let perturbed = a.to_dual() + t * b.to_dual();
+        assert_near(perturbed.determinant().derivative, finite_difference(determinant, 0.0) as f32, 1e-4);
+
+        let u = Vec3 { x: t, y: Dual::constant(1.0), z: t * t };
+        let v = Vec3 { x: 2.0, y: 0.0, z: 1.0 }.to_dual();
+        let cross = u.cross_product(&v);
+        // u × v = (1, 2t² - t, -2) so its derivative at t is (0, 4t - 1, 0).
+        assert_eq!(cross.derivative(), Vec3 { x: 0.0, y: -1.0, z: 0.0 });
+        assert_eq!(cross.value(), Vec3 { x: 1.0, y: 0.0, z: -2.0 });
+    }
+
+    #[test]
+    fn derivative_of_inverse_and_normalize() {
+        // d/dt (A + tB)⁻¹ at t = 0 is -A⁻¹ B A⁻¹.
+        let a = Mat3::new_from_array(&[2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+        let b = Mat3::new_from_array(&[0.5, 0.0, 1.0, 0.0, -1.0, 0.0, 2.0, 0.0, 1.0]);
+        let t = Dual::variable(0.0);
+        let inverse = (a.to_dual() + t * b.to_dual()).inverse().unwrap();
+        let a_inverse = a.inverse().unwrap();
+        let expected = -1.0 * (a_inverse * b * a_inverse);
+        assert_mat3_near(&inverse.derivative(), &expected);
+        assert_eq!(inverse.value(), a_inverse);
+
+        let f = |t: f64| {
+            let (x, y, z) = (t, 1.0, t * t);
+            (x / (x * x + y * y + z * z).sqrt(), z / (x * x + y * y + z * z).sqrt())
+        };
+        let t = Dual::variable(0.6);
+        let normalized = Vec3 { x: t, y: Dual::constant(1.0), z: t * t }.normalize();
+        assert_near(normalized.x.derivative, finite_difference(|t| f(t).0, 0.6) as f32, 1e-4);
+        assert_near(normalized.z.derivative, finite_difference(|t| f(t).1, 0.6) as f32, 1e-4);
+        assert_near(normalized.length().value, 1.0, 1e-4);
+        assert_near(normalized.length().derivative, 0.0, 1e-4);
+    }
+}
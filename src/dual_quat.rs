@@ -0,0 +1,268 @@
+//! Implementation of a Dual Quaternion for representing and blending rigid
+//! transforms.
+//!
+//! Blending Mat4s component-wise, as linear blend skinning does, shrinks the
+//! mesh where bones twist ("candy-wrapper" artifacts). Blending DualQuats
+//! instead keeps every result a rigid rotation and translation.
+
+use std::ops;
+
+use super::{Mat4, Quat, Vec3};
+
+/// A Dual Quaternion `real + ε dual`, where `ε² = 0`.
+///
+/// A unit DualQuat represents a rotation by `real` followed by a translation
+/// `t`, stored as `dual = 0.5 * t * real` with `t` as a pure Quat.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DualQuat {
+    pub real: Quat,
+    pub dual: Quat,
+}
+
+impl DualQuat {
+    /// Returns the DualQuat representing no rotation or translation.
+    pub fn identity() -> DualQuat {
+        DualQuat { real: Quat::identity(), dual: Quat::new() }
+    }
+
+    /// Returns a DualQuat that rotates by the given unit Quat and then
+    /// translates.
+    pub fn new_from_rotation_translation(rotation: &Quat, translation: &Vec3) -> DualQuat {
+        let translation = Quat { x: translation.x, y: translation.y, z: translation.z, w: 0.0 };
+        DualQuat { real: *rotation, dual: 0.5 * (translation * *rotation) }
+    }
+
+    /// Returns a DualQuat with the same rotation and translation as the
+    /// given Mat4.
+    ///
+    /// The Mat4 must be a rigid transform, with an orthonormal rotation and
+    /// no scale, shear or perspective.
+    pub fn new_from_mat4(mat4: &Mat4) -> DualQuat {
+        let translation = Vec3 { x: mat4.d, y: mat4.h, z: mat4.l };
+        DualQuat::new_from_rotation_translation(&Quat::new_from_mat3(&mat4.to_mat3()), &translation)
+    }
+
+    /// Returns the rotation of a unit DualQuat.
+    pub fn rotation(&self) -> Quat {
+        self.real
+    }
+
+    /// Returns the translation of a unit DualQuat.
+    pub fn translation(&self) -> Vec3 {
+        (2.0 * (self.dual * self.real.conjugate())).vector_part()
+    }
+
+    /// Returns the rigid transform Mat4 of a unit DualQuat.
+    pub fn to_mat4(&self) -> Mat4 {
+        let scale = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+        Mat4::new_from_trs(&self.translation(), &self.real.to_mat3(), &scale)
+    }
+
+    /// Returns the quaternion conjugate of both parts, which for a unit
+    /// DualQuat is the inverse transform.
+    pub fn conjugate(&self) -> DualQuat {
+        DualQuat { real: self.real.conjugate(), dual: self.dual.conjugate() }
+    }
+
+    /// Returns the DualQuat scaled to unit length, with any part of the dual
+    /// that isn't orthogonal to the real part removed, so that the result is
+    /// a valid rigid transform.
+    pub fn normalize(&self) -> DualQuat {
+        let inverse_length = 1.0 / self.real.length();
+        let real = inverse_length * self.real;
+        let dual = inverse_length * self.dual;
+        DualQuat { real, dual: dual - real.dot(&dual) * real }
+    }
+
+    /// Transforms a position by a unit DualQuat, rotating and then
+    /// translating it.
+    pub fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.real * *point + self.translation()
+    }
+
+    /// Blends the transforms with dual quaternion linear blending (DLB),
+    /// weighting each by the weight at the same index.
+    ///
+    /// Transforms whose rotation is in the opposite hemisphere to the first
+    /// are negated before blending so every rotation takes the shortest
+    /// path. Returns `None` if there are no transforms or the weighted sum
+    /// has no rotation part.
+    ///
+    /// Panics if the slices have different lengths.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{DualQuat, Quat, Vec3};
+    /// let a = DualQuat::identity();
+    /// let b = DualQuat::new_from_rotation_translation(&Quat::identity(), &Vec3 { x: 2.0, y: 0.0, z: 0.0 });
+    /// let blended = DualQuat::blend(&[a, b], &[0.75, 0.25]).unwrap();
+    /// assert_eq!(blended.translation(), Vec3 { x: 0.5, y: 0.0, z: 0.0 });
+    /// ```
+    pub fn blend(transforms: &[DualQuat], weights: &[f32]) -> Option<DualQuat> {
+        assert_eq!(transforms.len(), weights.len(), "transforms and weights must be the same length");
+        let pivot = transforms.first()?.real;
+        let mut sum = DualQuat { real: Quat::new(), dual: Quat::new() };
+        for (transform, weight) in transforms.iter().zip(weights.iter()) {
+            let weight = if pivot.dot(&transform.real) < 0.0 { -weight } else { *weight };
+            sum = sum + weight * *transform;
+        }
+        if sum.real.length() <= f32::EPSILON {
+            return None;
+        }
+        Some(sum.normalize())
+    }
+
+    /// Interpolates between two unit DualQuats with screw linear
+    /// interpolation (ScLERP), returning `self` at `t = 0` and `other` at
+    /// `t = 1`.
+    ///
+    /// The result moves along the single screw motion, a rotation about and
+    /// translation along one axis, that takes `self` to `other`, so both the
+    /// rotation and translation change at a constant rate.
+    pub fn sclerp(&self, other: &DualQuat, t: f32) -> DualQuat {
+        let mut difference = self.conjugate() * *other;
+        if difference.real.w < 0.0 {
+            difference = -1.0 * difference;
+        }
+        (*self * difference.powf(t)).normalize()
+    }
+
+    /// Raises a unit DualQuat to the power `t` by scaling the angle and
+    /// distance of its screw motion.
+    fn powf(&self, t: f32) -> DualQuat {
+        let real_vector = self.real.vector_part();
+        let sin = real_vector.length();
+        if sin <= 1e-6 {
+            // A pure translation, which scales linearly.
+            return DualQuat { real: Quat::identity(), dual: t * self.dual };
+        }
+        let half_angle = sin.atan2(self.real.w);
+        let axis = (1.0 / sin) * real_vector;
+        let pitch = -2.0 * self.dual.w / sin;
+        let moment = (1.0 / sin) * (self.dual.vector_part() - (0.5 * pitch * self.real.w) * axis);
+
+        let half_angle = t * half_angle;
+        let pitch = t * pitch;
+        let (sin, cos) = half_angle.sin_cos();
+        let real = sin * axis;
+        let dual = sin * moment + (0.5 * pitch * cos) * axis;
+        DualQuat {
+            real: Quat { x: real.x, y: real.y, z: real.z, w: cos },
+            dual: Quat { x: dual.x, y: dual.y, z: dual.z, w: -0.5 * pitch * sin },
+        }
+    }
+}
+
+impl ops::Add<DualQuat> for DualQuat {
+    type Output = DualQuat;
+
+    fn add(self, other: DualQuat) -> DualQuat {
+        DualQuat { real: self.real + other.real, dual: self.dual + other.dual }
+    }
+}
+
+impl ops::Mul<DualQuat> for f32 {
+    type Output = DualQuat;
+
+    /// Multiplies a scalar value by both parts of the DualQuat.
+    fn mul(self, dual_quat: DualQuat) -> DualQuat {
+        DualQuat { real: self * dual_quat.real, dual: self * dual_quat.dual }
+    }
+}
+
+impl ops::Mul<DualQuat> for DualQuat {
+    type Output = DualQuat;
+
+    /// Composes two DualQuats in the same order as multiplying their Mat4s,
+    /// so `a * b` applies `b` and then `a`.
+    fn mul(self, other: DualQuat) -> DualQuat {
+        DualQuat {
+            real: self.real * other.real,
+            dual: self.real * other.dual + self.dual * other.real,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{DualQuat, Mat4, Quat, Vec3};
+    use crate::test_values::assert_vec3_near;
+
+    fn rigid(axis: &Vec3, angle: f32, translation: &Vec3) -> DualQuat {
+        DualQuat::new_from_rotation_translation(&Quat::new_from_axis_angle(axis, angle), translation)
+    }
+
+    #[test]
+    fn mat4_round_trip() {
+        let dual_quat = rigid(&Vec3 { x: 0.0, y: 0.6, z: 0.8 }, 1.3, &Vec3 { x: 1.0, y: -2.0, z: 3.0 });
+        let mat4 = dual_quat.to_mat4();
+        let point = Vec3 { x: 0.5, y: 1.5, z: -2.0 };
+        assert_vec3_near(&dual_quat.transform_point(&point), &mat4.transform_point(&point));
+
+        let round_trip = DualQuat::new_from_mat4(&mat4);
+        assert_vec3_near(&round_trip.translation(), &Vec3 { x: 1.0, y: -2.0, z: 3.0 });
+        assert_vec3_near(&round_trip.transform_point(&point), &mat4.transform_point(&point));
+    }
+
+    #[test]
+    fn multiplication_matches_mat4() {
+        let a = rigid(&Vec3 { x: 1.0, y: 0.0, z: 0.0 }, 0.7, &Vec3 { x: 0.0, y: 1.0, z: 0.0 });
+        let b = rigid(&Vec3 { x: 0.0, y: 0.0, z: 1.0 }, -1.9, &Vec3 { x: 4.0, y: 0.0, z: 2.0 });
+        let expected: Mat4 = a.to_mat4() * b.to_mat4();
+        let point = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_vec3_near(&(a * b).transform_point(&point), &expected.transform_point(&point));
+        assert_vec3_near(&(a * a.conjugate()).transform_point(&point), &point);
+    }
+
+    #[test]
+    fn normalize_restores_rigid_transform() {
+        let dual_quat = rigid(&Vec3 { x: 0.0, y: 1.0, z: 0.0 }, 0.4, &Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+        let mut scaled = 3.0 * dual_quat;
+        scaled.dual = scaled.dual + 0.1 * scaled.real;
+        let normalized = scaled.normalize();
+        assert!((normalized.real.length() - 1.0).abs() < 1e-6);
+        assert!(normalized.real.dot(&normalized.dual).abs() < 1e-6);
+        assert_vec3_near(&normalized.translation(), &dual_quat.translation());
+    }
+
+    #[test]
+    fn blend_keeps_rotations_rigid() {
+        let axis = Vec3 { x: 0.0, y: 0.0, z: 1.0 };
+        let a = rigid(&axis, 0.0, &Vec3::new());
+        // Negating the second transform describes the same rotation from the
+        // other hemisphere and must not change the blend.
+        let b = -1.0 * rigid(&axis, 0.5 * PI, &Vec3::new());
+        let blended = DualQuat::blend(&[a, b], &[0.5, 0.5]).unwrap();
+        let expected = rigid(&axis, 0.25 * PI, &Vec3::new());
+        let point = Vec3 { x: 2.0, y: 0.0, z: 0.0 };
+        assert_vec3_near(&blended.transform_point(&point), &expected.transform_point(&point));
+
+        assert_eq!(DualQuat::blend(&[], &[]), None);
+        assert_eq!(DualQuat::blend(&[a], &[0.0]), None);
+    }
+
+    #[test]
+    fn sclerp_follows_screw_motion() {
+        let axis = Vec3 { x: 0.0, y: 0.0, z: 1.0 };
+        let start = DualQuat::identity();
+        // Turning about z while moving 4 along it is a screw motion, so
+        // halfway is half the turn having moved 2.
+        let end = rigid(&axis, 0.8 * PI, &Vec3 { x: 0.0, y: 0.0, z: 4.0 });
+        let halfway = start.sclerp(&end, 0.5);
+        let expected = rigid(&axis, 0.4 * PI, &Vec3 { x: 0.0, y: 0.0, z: 2.0 });
+        let point = Vec3 { x: 1.0, y: 0.0, z: 0.0 };
+        assert_vec3_near(&halfway.transform_point(&point), &expected.transform_point(&point));
+
+        let a = rigid(&Vec3 { x: 0.6, y: 0.0, z: 0.8 }, 0.9, &Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+        let b = rigid(&Vec3 { x: 0.0, y: 1.0, z: 0.0 }, -0.4, &Vec3 { x: -3.0, y: 0.0, z: 1.0 });
+        assert_vec3_near(&a.sclerp(&b, 0.0).transform_point(&point), &a.transform_point(&point));
+        assert_vec3_near(&a.sclerp(&b, 1.0).transform_point(&point), &b.transform_point(&point));
+
+        // Pure translations interpolate linearly.
+        let c = rigid(&axis, 0.0, &Vec3 { x: 2.0, y: 0.0, z: 0.0 });
+        assert_vec3_near(&start.sclerp(&c, 0.25).translation(), &Vec3 { x: 0.5, y: 0.0, z: 0.0 });
+    }
+}
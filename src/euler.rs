@@ -0,0 +1,276 @@
+//! Conversions between Euler angles and rotation matrices.
+
+use std::f32::consts::PI;
+
+use super::{Mat3, Mat4};
+
+/// The order and frame in which a set of 3 Euler angles are applied.
+///
+/// The letters name the axis of the first, second and third angle. Variants
+/// without a suffix are intrinsic: each rotation is about the axes of the
+/// already rotated frame, so `XYZ` builds `Rx(first) * Ry(second) *
+/// Rz(third)`. Variants ending in `Ex` are extrinsic: each rotation is about
+/// the fixed world axes, so `XYZEx` builds `Rz(third) * Ry(second) *
+/// Rx(first)`. An extrinsic order is therefore the same rotation as the
+/// reversed intrinsic order with the angles reversed.
+///
+/// The first 6 orders of each frame are Tait–Bryan angles, using every axis
+/// once. The last 6 are proper Euler angles, repeating the first axis.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+    XYZEx,
+    XZYEx,
+    YXZEx,
+    YZXEx,
+    ZXYEx,
+    ZYXEx,
+    XYXEx,
+    XZXEx,
+    YXYEx,
+    YZYEx,
+    ZXZEx,
+    ZYZEx,
+}
+
+// Axis indices used to address rows and columns below.
+const X: usize = 0;
+const Y: usize = 1;
+const Z: usize = 2;
+
+// Below this the second angle is treated as being in gimbal lock.
+const GIMBAL_LOCK_EPSILON: f32 = 16.0 * f32::EPSILON;
+
+impl EulerRot {
+    /// Returns the axes of the first, second and third angles, and whether
+    /// the rotations are intrinsic.
+    fn axes(&self) -> ([usize; 3], bool) {
+        match *self {
+            EulerRot::XYZ => ([X, Y, Z], true),
+            EulerRot::XZY => ([X, Z, Y], true),
+            EulerRot::YXZ => ([Y, X, Z], true),
+            EulerRot::YZX => ([Y, Z, X], true),
+            EulerRot::ZXY => ([Z, X, Y], true),
+            EulerRot::ZYX => ([Z, Y, X], true),
+            EulerRot::XYX => ([X, Y, X], true),
+            EulerRot::XZX => ([X, Z, X], true),
+            EulerRot::YXY => ([Y, X, Y], true),
+            EulerRot::YZY => ([Y, Z, Y], true),
+            EulerRot::ZXZ => ([Z, X, Z], true),
+            EulerRot::ZYZ => ([Z, Y, Z], true),
+            EulerRot::XYZEx => ([X, Y, Z], false),
+            EulerRot::XZYEx => ([X, Z, Y], false),
+            EulerRot::YXZEx => ([Y, X, Z], false),
+            EulerRot::YZXEx => ([Y, Z, X], false),
+            EulerRot::ZXYEx => ([Z, X, Y], false),
+            EulerRot::ZYXEx => ([Z, Y, X], false),
+            EulerRot::XYXEx => ([X, Y, X], false),
+            EulerRot::XZXEx => ([X, Z, X], false),
+            EulerRot::YXYEx => ([Y, X, Y], false),
+            EulerRot::YZYEx => ([Y, Z, Y], false),
+            EulerRot::ZXZEx => ([Z, X, Z], false),
+            EulerRot::ZYZEx => ([Z, Y, Z], false),
+        }
+    }
+}
+
+fn rotation_about(axis: usize, angle: f32) -> Mat3 {
+    match axis {
+        X => Mat3::new_from_rotation_x(angle),
+        Y => Mat3::new_from_rotation_y(angle),
+        _ => Mat3::new_from_rotation_z(angle),
+    }
+}
+
+/// Extracts the angles `(first, second, third)` of a rotation built as
+/// `R_last(third) * R_middle(second) * R_first(first)`, following Ken
+/// Shoemake's "Euler Angle Conversion" from Graphics Gems IV.
+///
+/// In gimbal lock the first and third axes coincide, so only their combined
+/// angle is recoverable. The third angle is set to 0 and the first absorbs
+/// the whole rotation.
+fn extrinsic_angles(m: &[[f32; 3]; 3], first: usize, middle: usize, repeated: bool) -> (f32, f32, f32) {
+    let (i, j) = (first, middle);
+    let k = 3 - i - j;
+    let odd_parity = j != (i + 1) % 3;
+
+    let (x, y, z) = if repeated {
+        let sy = m[i][j].hypot(m[i][k]);
+        if sy > GIMBAL_LOCK_EPSILON {
+            (m[i][j].atan2(m[i][k]), sy.atan2(m[i][i]), m[j][i].atan2(-m[k][i]))
+        } else {
+            ((-m[j][k]).atan2(m[j][j]), sy.atan2(m[i][i]), 0.0)
+        }
+    } else {
+        let cy = m[i][i].hypot(m[j][i]);
+        if cy > GIMBAL_LOCK_EPSILON {
+            (m[k][j].atan2(m[k][k]), (-m[k][i]).atan2(cy), m[j][i].atan2(m[i][i]))
+        } else {
+            ((-m[j][k]).atan2(m[j][j]), (-m[k][i]).atan2(cy), 0.0)
+        }
+    };
+
+    let (x, y, z) = if odd_parity { (-x, -y, -z) } else { (x, y, z) };
+
+    if repeated && y < 0.0 {
+        // Odd parity pushes the second angle of proper Euler orders into
+        // [-π, 0]. Rotating by π about the repeated axis on both sides flips
+        // the middle axis, giving the equivalent angles with y in [0, π].
+        (wrap_angle(x + PI), -y, wrap_angle(z + PI))
+    } else {
+        (x, y, z)
+    }
+}
+
+/// Wraps an angle in [-2π, 2π] into (-π, π].
+fn wrap_angle(angle: f32) -> f32 {
+    if angle > PI {
+        angle - (2.0 * PI)
+    } else if angle <= -PI {
+        angle + (2.0 * PI)
+    } else {
+        angle
+    }
+}
+
+impl Mat3 {
+    /// Returns the rotation Mat3 described by 3 Euler angles, in radians,
+    /// applied in the given order.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{EulerRot, Mat3};
+    /// let mat3 = Mat3::new_from_euler(EulerRot::ZYX, 0.5, 0.25, -1.0);
+    /// let (yaw, pitch, roll) = mat3.to_euler(EulerRot::ZYX);
+    /// ```
+    pub fn new_from_euler(order: EulerRot, first: f32, second: f32, third: f32) -> Mat3 {
+        let (axes, intrinsic) = order.axes();
+        let r1 = rotation_about(axes[0], first);
+        let r2 = rotation_about(axes[1], second);
+        let r3 = rotation_about(axes[2], third);
+        if intrinsic {
+            r1 * r2 * r3
+        } else {
+            r3 * r2 * r1
+        }
+    }
+
+    /// Returns the Euler angles `(first, second, third)`, in radians, that
+    /// rebuild this rotation Mat3 when applied in the given order.
+    ///
+    /// The second angle is in [-π/2, π/2] for Tait–Bryan orders and [0, π]
+    /// for proper Euler orders. In gimbal lock the first and third axes line
+    /// up and only their combined angle can be recovered, so the angle of the
+    /// left-most rotation in the product is returned as 0: the first angle
+    /// for intrinsic orders and the third for extrinsic ones.
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        let (axes, intrinsic) = order.axes();
+        let m = [
+            [self.a, self.b, self.c],
+            [self.d, self.e, self.f],
+            [self.g, self.h, self.i],
+        ];
+        let repeated = axes[0] == axes[2];
+        if intrinsic {
+            // An intrinsic rotation is the extrinsic rotation about the same
+            // axes taken in reverse order.
+            let (third, second, first) = extrinsic_angles(&m, axes[2], axes[1], repeated);
+            (first, second, third)
+        } else {
+            extrinsic_angles(&m, axes[0], axes[1], repeated)
+        }
+    }
+}
+
+impl Mat4 {
+    /// Returns the rotation Mat4 described by 3 Euler angles, in radians,
+    /// applied in the given order.
+    pub fn new_from_euler(order: EulerRot, first: f32, second: f32, third: f32) -> Mat4 {
+        Mat4::new_from_mat3(&Mat3::new_from_euler(order, first, second, third))
+    }
+
+    /// Returns the Euler angles of the rotation in the upper-left 3x3 corner
+    /// of the Mat4. See `Mat3::to_euler`.
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        self.to_mat3().to_euler(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EulerRot, Mat3, Mat4};
+    use crate::test_values::assert_mat3_near;
+
+    const ALL_ORDERS: [EulerRot; 24] = [
+        EulerRot::XYZ, EulerRot::XZY, EulerRot::YXZ, EulerRot::YZX,
+        EulerRot::ZXY, EulerRot::ZYX, EulerRot::XYX, EulerRot::XZX,
+        EulerRot::YXY, EulerRot::YZY, EulerRot::ZXZ, EulerRot::ZYZ,
+        EulerRot::XYZEx, EulerRot::XZYEx, EulerRot::YXZEx, EulerRot::YZXEx,
+        EulerRot::ZXYEx, EulerRot::ZYXEx, EulerRot::XYXEx, EulerRot::XZXEx,
+        EulerRot::YXYEx, EulerRot::YZYEx, EulerRot::ZXZEx, EulerRot::ZYZEx,
+    ];
+
+    #[test]
+    fn single_axis_euler_matches_rotation() {
+        assert_mat3_near(&Mat3::new_from_euler(EulerRot::XYZ, 0.5, 0.0, 0.0), &Mat3::new_from_rotation_x(0.5));
+        assert_mat3_near(&Mat3::new_from_euler(EulerRot::ZYXEx, 0.0, 0.5, 0.0), &Mat3::new_from_rotation_y(0.5));
+        assert_mat3_near(&Mat3::new_from_euler(EulerRot::ZXZ, 0.0, 0.0, 0.5), &Mat3::new_from_rotation_z(0.5));
+    }
+
+    #[test]
+    fn extrinsic_is_reversed_intrinsic() {
+        let extrinsic = Mat3::new_from_euler(EulerRot::XYZEx, 0.1, 0.2, 0.3);
+        let intrinsic = Mat3::new_from_euler(EulerRot::ZYX, 0.3, 0.2, 0.1);
+        assert_mat3_near(&extrinsic, &intrinsic);
+        assert_mat3_near(
+            &intrinsic,
+            &(Mat3::new_from_rotation_z(0.3) * Mat3::new_from_rotation_y(0.2) * Mat3::new_from_rotation_x(0.1))
+        );
+    }
+
+    #[test]
+    fn round_trip_all_orders() {
+        for order in ALL_ORDERS.iter() {
+            let mat3 = Mat3::new_from_euler(*order, 0.3, 0.7, -1.1);
+            let (first, second, third) = mat3.to_euler(*order);
+            assert!((first - 0.3).abs() < 1e-4, "{:?}: {}", order, first);
+            assert!((second - 0.7).abs() < 1e-4, "{:?}: {}", order, second);
+            assert!((third + 1.1).abs() < 1e-4, "{:?}: {}", order, third);
+        }
+    }
+
+    #[test]
+    fn round_trip_in_gimbal_lock() {
+        let half_pi = std::f32::consts::FRAC_PI_2;
+        for order in ALL_ORDERS.iter() {
+            let (axes, intrinsic) = order.axes();
+            let second = if axes[0] == axes[2] { 0.0 } else { half_pi };
+            let mat3 = Mat3::new_from_euler(*order, 0.4, second, 0.2);
+            let (first, second, third) = mat3.to_euler(*order);
+            assert_eq!(if intrinsic { first } else { third }, 0.0);
+            assert_mat3_near(&Mat3::new_from_euler(*order, first, second, third), &mat3);
+        }
+    }
+
+    #[test]
+    fn mat4_round_trip() {
+        let mat4 = Mat4::new_from_euler(EulerRot::YXZ, -0.5, 0.25, 1.5);
+        assert_eq!(mat4.p, 1.0);
+        let (first, second, third) = mat4.to_euler(EulerRot::YXZ);
+        assert!((first + 0.5).abs() < 1e-5);
+        assert!((second - 0.25).abs() < 1e-5);
+        assert!((third - 1.5).abs() < 1e-5);
+    }
+}
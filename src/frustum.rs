@@ -0,0 +1,221 @@
+//! Implementation of a view Frustum and its associated functions and methods.
+
+use super::{Aabb, Mat4, Plane, Vec3, Vec4};
+
+/// The result of testing a volume against a Frustum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Intersection {
+    /// The volume lies entirely inside the Frustum.
+    Inside,
+    /// The volume lies entirely outside the Frustum.
+    Outside,
+    /// The volume straddles at least one of the Frustum's planes.
+    Intersecting,
+}
+
+/// A view frustum bounded by 6 planes whose normals all point inwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Frustum {
+    pub left: Plane,
+    pub right: Plane,
+    pub bottom: Plane,
+    pub top: Plane,
+    pub near: Plane,
+    pub far: Plane,
+}
+
+impl Frustum {
+    /// Extracts the Frustum from a view-projection Mat4 using the
+    /// Gribb–Hartmann method.
+    ///
+    /// The matrix is expected to map points to OpenGL style clip space, where
+    /// a point is visible if `-w <= x, y, z <= w`. Each plane is normalized so
+    /// that signed distances are in world units.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Frustum, Mat4, Vec3};
+    /// let frustum = Frustum::new_from_mat4(&Mat4::identity());
+    /// assert!(frustum.contains_point(&Vec3::new()));
+    /// ```
+    pub fn new_from_mat4(view_projection: &Mat4) -> Frustum {
+        let m = view_projection;
+        let row1 = Vec4 { x: m.a, y: m.b, z: m.c, w: m.d };
+        let row2 = Vec4 { x: m.e, y: m.f, z: m.g, w: m.h };
+        let row3 = Vec4 { x: m.i, y: m.j, z: m.k, w: m.l };
+        let row4 = Vec4 { x: m.m, y: m.n, z: m.o, w: m.p };
+
+        Frustum {
+            left: Plane::new_from_vec4(&(row4 + row1)).normalize(),
+            right: Plane::new_from_vec4(&(row4 - row1)).normalize(),
+            bottom: Plane::new_from_vec4(&(row4 + row2)).normalize(),
+            top: Plane::new_from_vec4(&(row4 - row2)).normalize(),
+            near: Plane::new_from_vec4(&(row4 + row3)).normalize(),
+            far: Plane::new_from_vec4(&(row4 - row3)).normalize(),
+        }
+    }
+
+    /// Returns the planes of the Frustum in the order left, right, bottom,
+    /// top, near, far.
+    pub fn planes(&self) -> [Plane; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    /// Returns true if the point lies inside or on the surface of the
+    /// Frustum.
+    pub fn contains_point(&self, point: &Vec3) -> bool {
+        self.planes()
+            .iter()
+            .all(|plane| plane.distance_to_point(point) >= 0.0)
+    }
+
+    /// Tests a sphere against the Frustum.
+    pub fn intersects_sphere(&self, center: &Vec3, radius: f32) -> Intersection {
+        let mut result = Intersection::Inside;
+        for plane in self.planes().iter() {
+            let distance = plane.distance_to_point(center);
+            if distance < -radius {
+                return Intersection::Outside;
+            }
+            if distance < radius {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Tests an Aabb against the Frustum.
+    ///
+    /// For each plane only the corners of the Aabb furthest along and
+    /// furthest against the plane normal are tested. Like most frustum
+    /// culling tests this is conservative: a box near a corner of the
+    /// Frustum may be reported as `Intersecting` while lying outside of it.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> Intersection {
+        let mut result = Intersection::Inside;
+        for plane in self.planes().iter() {
+            let normal = plane.normal;
+            let positive = Vec3 {
+                x: if normal.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                y: if normal.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                z: if normal.z >= 0.0 { aabb.max.z } else { aabb.min.z },
+            };
+            let negative = Vec3 {
+                x: if normal.x >= 0.0 { aabb.min.x } else { aabb.max.x },
+                y: if normal.y >= 0.0 { aabb.min.y } else { aabb.max.y },
+                z: if normal.z >= 0.0 { aabb.min.z } else { aabb.max.z },
+            };
+            if plane.distance_to_point(&positive) < 0.0 {
+                return Intersection::Outside;
+            }
+            if plane.distance_to_point(&negative) < 0.0 {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns the 8 corners of the Frustum, which is useful for fitting
+    /// shadow map projections to the visible volume.
+    ///
+    /// Corners are ordered like `Aabb::corners`: bit 0 of the index selects
+    /// left/right, bit 1 bottom/top and bit 2 near/far.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let mut corners = [Vec3::new(); 8];
+        for (index, corner) in corners.iter_mut().enumerate() {
+            let x_plane = if index & 1 == 0 { &self.left } else { &self.right };
+            let y_plane = if index & 2 == 0 { &self.bottom } else { &self.top };
+            let z_plane = if index & 4 == 0 { &self.near } else { &self.far };
+            *corner = intersect_planes(x_plane, y_plane, z_plane);
+        }
+        corners
+    }
+}
+
+/// Returns the point shared by 3 planes.
+///
+/// The planes of a Frustum built from an invertible matrix never share a line,
+/// so the denominator can't be zero here.
+fn intersect_planes(p1: &Plane, p2: &Plane, p3: &Plane) -> Vec3 {
+    let n2_cross_n3 = p2.normal.cross_product(&p3.normal);
+    let n3_cross_n1 = p3.normal.cross_product(&p1.normal);
+    let n1_cross_n2 = p1.normal.cross_product(&p2.normal);
+    let denominator = p1.normal * n2_cross_n3;
+
+    (-1.0 / denominator) * ((p1.d * n2_cross_n3) + (p2.d * n3_cross_n1) + (p3.d * n1_cross_n2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aabb, Frustum, Intersection, Mat4, Vec3};
+    use crate::test_values::assert_vec3_near;
+
+    // A 90 degree perspective projection with an aspect ratio of 1, a near
+    // plane at 1 and a far plane at 10, looking down -z.
+    fn perspective() -> Mat4 {
+        Mat4 {
+            a: 1.0, b: 0.0, c:  0.0,           d:  0.0,
+            e: 0.0, f: 1.0, g:  0.0,           h:  0.0,
+            i: 0.0, j: 0.0, k: -11.0 / 9.0,    l: -20.0 / 9.0,
+            m: 0.0, n: 0.0, o: -1.0,           p:  0.0
+        }
+    }
+
+    #[test]
+    fn identity_frustum_is_unit_cube() {
+        let frustum = Frustum::new_from_mat4(&Mat4::identity());
+        assert_eq!(frustum.left.normal, Vec3 { x: 1.0, y: 0.0, z: 0.0 });
+        assert_eq!(frustum.left.d, 1.0);
+        assert_eq!(frustum.far.normal, Vec3 { x: 0.0, y: 0.0, z: -1.0 });
+        assert_eq!(frustum.far.d, 1.0);
+    }
+
+    #[test]
+    fn frustum_contains_point() {
+        let frustum = Frustum::new_from_mat4(&perspective());
+        assert!(frustum.contains_point(&Vec3 { x: 0.0, y: 0.0, z: -5.0 }));
+        assert!(frustum.contains_point(&Vec3 { x: 4.0, y: -4.0, z: -5.0 }));
+        assert!(!frustum.contains_point(&Vec3 { x: 6.0, y: 0.0, z: -5.0 }));
+        assert!(!frustum.contains_point(&Vec3 { x: 0.0, y: 0.0, z: 5.0 }));
+        assert!(!frustum.contains_point(&Vec3 { x: 0.0, y: 0.0, z: -11.0 }));
+    }
+
+    #[test]
+    fn frustum_intersects_sphere() {
+        let frustum = Frustum::new_from_mat4(&perspective());
+        let center = Vec3 { x: 0.0, y: 0.0, z: -5.0 };
+        assert_eq!(frustum.intersects_sphere(&center, 1.0), Intersection::Inside);
+        let center = Vec3 { x: 0.0, y: 0.0, z: -0.5 };
+        assert_eq!(frustum.intersects_sphere(&center, 1.0), Intersection::Intersecting);
+        let center = Vec3 { x: 0.0, y: 0.0, z: 3.0 };
+        assert_eq!(frustum.intersects_sphere(&center, 1.0), Intersection::Outside);
+    }
+
+    #[test]
+    fn frustum_intersects_aabb() {
+        let frustum = Frustum::new_from_mat4(&perspective());
+        let inside = Aabb {
+            min: Vec3 { x: -1.0, y: -1.0, z: -6.0 },
+            max: Vec3 { x: 1.0, y: 1.0, z: -4.0 },
+        };
+        assert_eq!(frustum.intersects_aabb(&inside), Intersection::Inside);
+        let straddling = Aabb {
+            min: Vec3 { x: -1.0, y: -1.0, z: -12.0 },
+            max: Vec3 { x: 1.0, y: 1.0, z: -8.0 },
+        };
+        assert_eq!(frustum.intersects_aabb(&straddling), Intersection::Intersecting);
+        let outside = Aabb {
+            min: Vec3 { x: 20.0, y: -1.0, z: -6.0 },
+            max: Vec3 { x: 22.0, y: 1.0, z: -4.0 },
+        };
+        assert_eq!(frustum.intersects_aabb(&outside), Intersection::Outside);
+    }
+
+    #[test]
+    fn get_frustum_corners() {
+        let corners = Frustum::new_from_mat4(&perspective()).corners();
+        assert_vec3_near(&corners[0], &Vec3 { x: -1.0, y: -1.0, z: -1.0 });
+        assert_vec3_near(&corners[3], &Vec3 { x: 1.0, y: 1.0, z: -1.0 });
+        assert_vec3_near(&corners[4], &Vec3 { x: -10.0, y: -10.0, z: -10.0 });
+        assert_vec3_near(&corners[7], &Vec3 { x: 10.0, y: 10.0, z: -10.0 });
+    }
+}
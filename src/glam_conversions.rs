@@ -0,0 +1,127 @@
+//! Conversions between Matriarch and `glam` types, enabled with the `glam`
+//! feature.
+//!
+//! glam stores its matrices column-major while Matriarch is row-major, so
+//! matrices are converted through their column-major arrays to keep the same
+//! mathematical value.
+
+use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+impl From<glam::Vec2> for Vec2 {
+    fn from(vector: glam::Vec2) -> Vec2 {
+        Vec2 { x: vector.x, y: vector.y }
+    }
+}
+
+impl From<Vec2> for glam::Vec2 {
+    fn from(vec2: Vec2) -> glam::Vec2 {
+        glam::Vec2::new(vec2.x, vec2.y)
+    }
+}
+
+impl From<glam::Vec3> for Vec3 {
+    fn from(vector: glam::Vec3) -> Vec3 {
+        Vec3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+impl From<Vec3> for glam::Vec3 {
+    fn from(vec3: Vec3) -> glam::Vec3 {
+        glam::Vec3::new(vec3.x, vec3.y, vec3.z)
+    }
+}
+
+impl From<glam::Vec3A> for Vec3 {
+    fn from(vector: glam::Vec3A) -> Vec3 {
+        Vec3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+impl From<Vec3> for glam::Vec3A {
+    fn from(vec3: Vec3) -> glam::Vec3A {
+        glam::Vec3A::new(vec3.x, vec3.y, vec3.z)
+    }
+}
+
+impl From<glam::Vec4> for Vec4 {
+    fn from(vector: glam::Vec4) -> Vec4 {
+        Vec4 { x: vector.x, y: vector.y, z: vector.z, w: vector.w }
+    }
+}
+
+impl From<Vec4> for glam::Vec4 {
+    fn from(vec4: Vec4) -> glam::Vec4 {
+        glam::Vec4::new(vec4.x, vec4.y, vec4.z, vec4.w)
+    }
+}
+
+impl From<glam::Mat2> for Mat2 {
+    fn from(matrix: glam::Mat2) -> Mat2 {
+        Mat2::new_from_col_array(&matrix.to_cols_array())
+    }
+}
+
+impl From<Mat2> for glam::Mat2 {
+    fn from(mat2: Mat2) -> glam::Mat2 {
+        glam::Mat2::from_cols_array(&mat2.to_col_array())
+    }
+}
+
+impl From<glam::Mat3> for Mat3 {
+    fn from(matrix: glam::Mat3) -> Mat3 {
+        Mat3::new_from_col_array(&matrix.to_cols_array())
+    }
+}
+
+impl From<Mat3> for glam::Mat3 {
+    fn from(mat3: Mat3) -> glam::Mat3 {
+        glam::Mat3::from_cols_array(&mat3.to_col_array())
+    }
+}
+
+impl From<glam::Mat4> for Mat4 {
+    fn from(matrix: glam::Mat4) -> Mat4 {
+        Mat4::new_from_col_array(&matrix.to_cols_array())
+    }
+}
+
+impl From<Mat4> for glam::Mat4 {
+    fn from(mat4: Mat4) -> glam::Mat4 {
+        glam::Mat4::from_cols_array(&mat4.to_col_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat2, Mat3, Mat4, Vec3, Vec4};
+
+    #[test]
+    fn convert_vectors() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_eq!(glam::Vec3::from(vec3), glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(glam::Vec3A::from(vec3)), vec3);
+        let vec4 = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(Vec4::from(glam::Vec4::from(vec4)), vec4);
+    }
+
+    #[test]
+    fn convert_matrices_preserving_value() {
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        let glam_mat2 = glam::Mat2::from(mat2);
+        assert_eq!(glam_mat2.row(0), glam::Vec2::new(1.0, 2.0));
+        assert_eq!(Mat2::from(glam_mat2), mat2);
+
+        let mat3 = Mat3::new_from_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(glam::Mat3::from(mat3).row(1), glam::Vec3::new(4.0, 5.0, 6.0));
+        assert_eq!(Mat3::from(glam::Mat3::from(mat3)), mat3);
+    }
+
+    #[test]
+    fn mat4_vec4_product_agrees() {
+        let mat4 = Mat4::new_from_array(&[ 1.5, 8.0, 2.0, 2.5, 10.0, 4.0, 4.0, 10.0, 3.5, 6.0, 7.0, 0.0, 7.0, 4.0, 2.0, 1.0 ]);
+        let vec4 = Vec4 { x: 8.0, y: 5.0, z: 0.0, w: 5.0 };
+        let glam_result = glam::Mat4::from(mat4) * glam::Vec4::from(vec4);
+        assert_eq!(Vec4::from(glam_result), mat4 * vec4);
+        assert_eq!(Mat4::from(glam::Mat4::from(mat4)), mat4);
+    }
+}
@@ -0,0 +1,489 @@
+//! Convex hulls of 2D and 3D point sets.
+//!
+//! Both hulls make every orientation decision with the exact predicates, so
+//! duplicate, collinear and coplanar points never produce an inconsistent
+//! result. Points lying on an edge or face of the hull are left out of it,
+//! so the hull only ever contains its corners.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::{orient2d, orient3d, Sign, Vec2, Vec3};
+
+/// Returns the indices of the corners of the convex hull of the points, in
+/// counter-clockwise order starting from the point with the smallest x (and
+/// then y) coordinate, using Andrew's monotone chain.
+///
+/// Duplicate points appear once, using the lowest index. If every point is
+/// collinear the result is the two ends of the line, and if every point is
+/// the same the result is that point. Points with a NaN coordinate don't
+/// panic, but make the result meaningless.
+///
+/// Example:
+/// ```
+/// # use matriarch::{convex_hull_2d, Vec2};
+/// let points = [
+///     Vec2 { x: 0.0, y: 0.0 },
+///     Vec2 { x: 1.0, y: 1.0 },
+///     Vec2 { x: 2.0, y: 0.0 },
+///     Vec2 { x: 1.0, y: 0.5 },
+///     Vec2 { x: 1.0, y: 0.0 },
+/// ];
+/// assert_eq!(convex_hull_2d(&points), vec![0, 2, 1]);
+/// ```
+pub fn convex_hull_2d(points: &[Vec2]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|a, b| compare_2d(&points[*a], &points[*b]).then(a.cmp(b)));
+    order.dedup_by(|a, b| points[*a] == points[*b]);
+    if order.len() < 3 {
+        return order;
+    }
+
+    let mut hull: Vec<usize> = Vec::with_capacity(order.len() + 1);
+    let turns_left = |hull: &[usize], index: usize| {
+        let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+        orient2d(&points[a], &points[b], &points[index]) == Sign::Positive
+    };
+    // The lower chain, from left to right.
+    for index in &order {
+        while hull.len() >= 2 && !turns_left(&hull, *index) {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    // The upper chain, from right to left, which mustn't remove any of the
+    // lower chain.
+    let lower_len = hull.len();
+    for index in order.iter().rev().skip(1) {
+        while hull.len() > lower_len && !turns_left(&hull, *index) {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    // The last point is the first one again.
+    hull.pop();
+    hull
+}
+
+/// Orders points by x and then y.
+fn compare_2d(a: &Vec2, b: &Vec2) -> Ordering {
+    a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+}
+
+/// A triangle of the 3D hull under construction, with the points that are
+/// still outside it.
+struct Face {
+    vertices: [usize; 3],
+    /// The face across each edge, where edge `i` runs from `vertices[i]` to
+    /// `vertices[(i + 1) % 3]`.
+    neighbors: [usize; 3],
+    outside: Vec<usize>,
+    removed: bool,
+    /// The last apex the face was tested against, and whether the face was
+    /// visible from it.
+    tested: Option<(usize, bool)>,
+}
+
+impl Face {
+    fn new(vertices: [usize; 3]) -> Face {
+        Face { vertices, neighbors: [0; 3], outside: Vec::new(), removed: false, tested: None }
+    }
+
+    /// Returns the index of the edge that starts at the given vertex.
+    fn edge_from(&self, vertex: usize) -> usize {
+        self.vertices.iter().position(|v| *v == vertex).unwrap()
+    }
+}
+
+/// Returns the triangles of the convex hull of the points as index triples,
+/// using quickhull.
+///
+/// Each triangle's corners are in counter-clockwise order seen from outside
+/// the hull. Faces of the hull with more than three corners are split into
+/// several coplanar triangles.
+///
+/// If every point lies in one plane the result covers the flat hull twice,
+/// once facing each way, so that it still has an outside in every
+/// direction. If every point lies on one line, or there are fewer than
+/// three distinct points, the result is empty. Points with a NaN coordinate
+/// don't panic, but make the result meaningless.
+///
+/// Example:
+/// ```
+/// # use matriarch::{convex_hull_3d, Vec3};
+/// let points = [
+///     Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+///     Vec3 { x: 1.0, y: 0.0, z: 0.0 },
+///     Vec3 { x: 0.0, y: 1.0, z: 0.0 },
+///     Vec3 { x: 0.0, y: 0.0, z: 1.0 },
+///     Vec3 { x: 0.1, y: 0.1, z: 0.1 },
+/// ];
+/// assert_eq!(convex_hull_3d(&points).len(), 4);
+/// ```
+pub fn convex_hull_3d(points: &[Vec3]) -> Vec<[usize; 3]> {
+    let (a, b, c) = match initial_triangle(points) {
+        Some(triangle) => triangle,
+        None => return Vec::new(),
+    };
+    let d = (0..points.len())
+        .filter(|index| orient3d(&points[a], &points[b], &points[c], &points[*index]) != Sign::Zero)
+        .max_by(|p, q| {
+            let normal = (points[b] - points[a]).cross_product(&(points[c] - points[a]));
+            let distance = |index: usize| (normal * (points[index] - points[a])).abs();
+            distance(*p).total_cmp(&distance(*q))
+        });
+    let d = match d {
+        Some(d) => d,
+        None => return flat_hull(points, a, b, c),
+    };
+
+    // Start from the tetrahedron, with each face wound so that the opposite
+    // corner is on its inside.
+    let mut faces: Vec<Face> = [[a, b, c, d], [a, b, d, c], [a, c, d, b], [b, c, d, a]]
+        .iter()
+        .map(|[p, q, r, opposite]| match orient3d(&points[*p], &points[*q], &points[*r], &points[*opposite]) {
+            Sign::Positive => Face::new([*p, *q, *r]),
+            _ => Face::new([*p, *r, *q]),
+        })
+        .collect();
+    // Every edge of the tetrahedron is shared by exactly two of its faces.
+    for face in 0..faces.len() {
+        let [p, q, r] = faces[face].vertices;
+        for (edge, (from, to)) in [(p, q), (q, r), (r, p)].iter().enumerate() {
+            let shares_edge = |other: &usize| *other != face && faces[*other].vertices.contains(from) && faces[*other].vertices.contains(to);
+            faces[face].neighbors[edge] = (0..faces.len()).find(shares_edge).unwrap();
+        }
+    }
+    let remaining: Vec<usize> = (0..points.len()).filter(|index| ![a, b, c, d].contains(index)).collect();
+    let mut pending: Vec<usize> = (0..faces.len()).collect();
+    assign_outside(points, &mut faces, &pending, remaining);
+
+    // Faces removed from the hull leave their slots to be reused, so the
+    // queue can hold stale indices, which are skipped.
+    let mut free = Vec::new();
+    let mut visible = Vec::new();
+    let mut horizon = Vec::new();
+    let mut new_faces = Vec::new();
+    let mut starting_at = HashMap::new();
+    while let Some(face) = pending.pop() {
+        if faces[face].removed || faces[face].outside.is_empty() {
+            continue;
+        }
+        let apex = farthest_outside(points, &faces[face]);
+        find_visible(points, &mut faces, face, apex, &mut visible, &mut horizon);
+
+        let mut orphans = Vec::new();
+        for index in &visible {
+            faces[*index].removed = true;
+            orphans.append(&mut faces[*index].outside);
+        }
+        orphans.retain(|index| *index != apex);
+        free.extend_from_slice(&visible);
+
+        // Each horizon edge makes a new face with the apex, keeping its
+        // winding, and facing the hidden face across it.
+        new_faces.clear();
+        starting_at.clear();
+        for (p, q, hidden) in &horizon {
+            let mut new_face = Face::new([*p, *q, apex]);
+            new_face.neighbors[0] = *hidden;
+            let index = match free.pop() {
+                Some(index) => {
+                    faces[index] = new_face;
+                    index
+                }
+                None => {
+                    faces.push(new_face);
+                    faces.len() - 1
+                }
+            };
+            let edge = faces[*hidden].edge_from(*q);
+            faces[*hidden].neighbors[edge] = index;
+            starting_at.insert(*p, index);
+            new_faces.push(index);
+        }
+        // The new faces form a fan around the apex, where the edge from q to
+        // the apex is shared with the face whose horizon edge starts at q.
+        for index in &new_faces {
+            let next = starting_at[&faces[*index].vertices[1]];
+            faces[*index].neighbors[1] = next;
+            faces[next].neighbors[2] = *index;
+        }
+        assign_outside(points, &mut faces, &new_faces, orphans);
+        pending.extend(new_faces.iter().filter(|index| !faces[**index].outside.is_empty()));
+    }
+
+    faces.iter().filter(|face| !face.removed).map(|face| face.vertices).collect()
+}
+
+/// Collects the faces visible from the apex by flood filling across edges
+/// from a face that is known to be visible, along with the horizon: each
+/// edge from a visible face to a hidden one, as `(start, end, hidden face)`.
+fn find_visible(
+    points: &[Vec3],
+    faces: &mut [Face],
+    start: usize,
+    apex: usize,
+    visible: &mut Vec<usize>,
+    horizon: &mut Vec<(usize, usize, usize)>,
+) {
+    visible.clear();
+    horizon.clear();
+    faces[start].tested = Some((apex, true));
+    let mut stack = vec![start];
+    while let Some(face) = stack.pop() {
+        visible.push(face);
+        let [p, q, r] = faces[face].vertices;
+        for (edge, (from, to)) in [(p, q), (q, r), (r, p)].iter().enumerate() {
+            let neighbor = faces[face].neighbors[edge];
+            let is_visible = match faces[neighbor].tested {
+                Some((tested_apex, is_visible)) if tested_apex == apex => is_visible,
+                _ => {
+                    let is_visible = is_outside(points, &faces[neighbor], apex);
+                    faces[neighbor].tested = Some((apex, is_visible));
+                    if is_visible {
+                        stack.push(neighbor);
+                    }
+                    is_visible
+                }
+            };
+            if !is_visible {
+                horizon.push((*from, *to, neighbor));
+            }
+        }
+    }
+}
+
+/// Returns three points that aren't collinear, starting from the leftmost
+/// point and the point farthest from it, or `None` if there aren't any.
+fn initial_triangle(points: &[Vec3]) -> Option<(usize, usize, usize)> {
+    let compare = |p: &Vec3, q: &Vec3| {
+        p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)).then(p.z.total_cmp(&q.z))
+    };
+    let a = (0..points.len()).min_by(|p, q| compare(&points[*p], &points[*q]))?;
+    let b = (0..points.len())
+        .filter(|index| points[*index] != points[a])
+        .max_by(|p, q| {
+            let distance = |index: usize| (points[index] - points[a]).length();
+            distance(*p).total_cmp(&distance(*q))
+        })?;
+    let c = (0..points.len())
+        .filter(|index| !collinear(&points[a], &points[b], &points[*index]))
+        .max_by(|p, q| {
+            let distance = |index: usize| (points[b] - points[a]).cross_product(&(points[index] - points[a])).length();
+            distance(*p).total_cmp(&distance(*q))
+        })?;
+    Some((a, b, c))
+}
+
+/// Returns true if the three points are exactly collinear, which is when
+/// their projections onto all three axis planes are.
+fn collinear(a: &Vec3, b: &Vec3, c: &Vec3) -> bool {
+    let xy = |v: &Vec3| Vec2 { x: v.x, y: v.y };
+    let yz = |v: &Vec3| Vec2 { x: v.y, y: v.z };
+    let zx = |v: &Vec3| Vec2 { x: v.z, y: v.x };
+    orient2d(&xy(a), &xy(b), &xy(c)) == Sign::Zero
+        && orient2d(&yz(a), &yz(b), &yz(c)) == Sign::Zero
+        && orient2d(&zx(a), &zx(b), &zx(c)) == Sign::Zero
+}
+
+/// Returns the double-sided triangulation of the hull of points that all
+/// lie in the plane through `a`, `b` and `c`.
+fn flat_hull(points: &[Vec3], a: usize, b: usize, c: usize) -> Vec<[usize; 3]> {
+    // Dropping the coordinate the plane faces most along keeps the points
+    // distinct and the hull the same shape.
+    let normal = (points[b] - points[a]).cross_product(&(points[c] - points[a]));
+    let (x, y, z) = (normal.x.abs(), normal.y.abs(), normal.z.abs());
+    let projected: Vec<Vec2> = points
+        .iter()
+        .map(|point| {
+            if x >= y && x >= z {
+                Vec2 { x: point.y, y: point.z }
+            } else if y >= z {
+                Vec2 { x: point.z, y: point.x }
+            } else {
+                Vec2 { x: point.x, y: point.y }
+            }
+        })
+        .collect();
+    let outline = convex_hull_2d(&projected);
+    let mut triangles = Vec::with_capacity(2 * (outline.len() - 2));
+    for pair in outline[1..].windows(2) {
+        triangles.push([outline[0], pair[0], pair[1]]);
+    }
+    // The back is fanned from the next corner, so that no edge is used twice
+    // in the same direction.
+    let mut back = outline.clone();
+    back.rotate_left(1);
+    for pair in back[1..].windows(2) {
+        triangles.push([back[0], pair[1], pair[0]]);
+    }
+    triangles
+}
+
+/// Returns true if the point is strictly on the outside of the face.
+fn is_outside(points: &[Vec3], face: &Face, index: usize) -> bool {
+    let [p, q, r] = face.vertices;
+    orient3d(&points[p], &points[q], &points[r], &points[index]) == Sign::Negative
+}
+
+/// Gives each point to the first of the given faces that it's outside of.
+/// Points outside none of them are inside the hull.
+fn assign_outside(points: &[Vec3], faces: &mut [Face], candidate_faces: &[usize], candidates: Vec<usize>) {
+    for index in candidates {
+        if let Some(face) = candidate_faces.iter().find(|face| is_outside(points, &faces[**face], index)) {
+            faces[*face].outside.push(index);
+        }
+    }
+}
+
+/// Returns the point outside the face that is farthest from its plane.
+fn farthest_outside(points: &[Vec3], face: &Face) -> usize {
+    let [p, q, r] = face.vertices;
+    let normal = (points[q] - points[p]).cross_product(&(points[r] - points[p]));
+    let distance = |index: usize| normal * (points[index] - points[p]);
+    *face
+        .outside
+        .iter()
+        .max_by(|a, b| distance(**a).total_cmp(&distance(**b)))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convex_hull_2d, convex_hull_3d, orient2d, orient3d, Sign, Vec2, Vec3};
+    use crate::test_values::grid_values;
+    use std::collections::HashSet;
+
+    #[test]
+    fn hull_2d_degenerate_inputs() {
+        assert!(convex_hull_2d(&[]).is_empty());
+        let point = Vec2 { x: 1.0, y: 2.0 };
+        assert_eq!(convex_hull_2d(&[point, point, point]), vec![0]);
+        let line: Vec<Vec2> = (0..5).map(|i| Vec2 { x: 3.0 - i as f32, y: 2.0 * i as f32 }).collect();
+        assert_eq!(convex_hull_2d(&line), vec![4, 0]);
+        let mut with_nan = line.clone();
+        with_nan.push(Vec2 { x: f32::NAN, y: 1.0 });
+        convex_hull_2d(&with_nan);
+    }
+
+    #[test]
+    fn hull_2d_square_with_edge_and_duplicate_points() {
+        let points = [
+            Vec2 { x: 0.0, y: 0.0 },
+            Vec2 { x: 0.5, y: 0.0 },
+            Vec2 { x: 1.0, y: 0.0 },
+            Vec2 { x: 1.0, y: 1.0 },
+            Vec2 { x: 0.0, y: 1.0 },
+            Vec2 { x: 0.5, y: 0.5 },
+            Vec2 { x: 1.0, y: 1.0 },
+            Vec2 { x: 0.0, y: 0.5 },
+        ];
+        assert_eq!(convex_hull_2d(&points), vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn hull_2d_contains_every_point() {
+        let coordinates = grid_values(2 * 300, 5, 9);
+        let points: Vec<Vec2> = coordinates.chunks(2).map(|c| Vec2 { x: c[0], y: c[1] }).collect();
+        let hull = convex_hull_2d(&points);
+        assert!(hull.len() >= 3);
+        for i in 0..hull.len() {
+            let (a, b, c) = (hull[i], hull[(i + 1) % hull.len()], hull[(i + 2) % hull.len()]);
+            // Strictly convex and counter-clockwise.
+            assert_eq!(orient2d(&points[a], &points[b], &points[c]), Sign::Positive);
+            for point in &points {
+                assert_ne!(orient2d(&points[a], &points[b], point), Sign::Negative);
+            }
+        }
+    }
+
+    fn assert_valid_hull(points: &[Vec3], triangles: &[[usize; 3]]) {
+        let mut edges = HashSet::new();
+        for [a, b, c] in triangles {
+            for edge in &[(*a, *b), (*b, *c), (*c, *a)] {
+                assert!(edges.insert(*edge), "edge {:?} used twice", edge);
+            }
+            for point in points {
+                assert_ne!(orient3d(&points[*a], &points[*b], &points[*c], point), Sign::Negative);
+            }
+        }
+        // Every edge has a twin going the other way, so the surface is closed.
+        for (a, b) in &edges {
+            assert!(edges.contains(&(*b, *a)));
+        }
+    }
+
+    #[test]
+    fn hull_3d_cube_with_surface_points() {
+        let mut points = Vec::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    points.push(Vec3 { x: x as f32, y: y as f32, z: z as f32 });
+                }
+            }
+        }
+        points.push(Vec3 { x: 2.0, y: 2.0, z: 2.0 });
+        let triangles = convex_hull_3d(&points);
+        assert_valid_hull(&points, &triangles);
+        assert_eq!(triangles.len(), 12);
+        let corners: HashSet<usize> = triangles.iter().flat_map(|t| t.iter().cloned()).collect();
+        assert_eq!(corners.len(), 8);
+        for corner in corners {
+            let point = points[corner];
+            assert!((point.x == 0.0 || point.x == 2.0) && (point.y == 0.0 || point.y == 2.0) && (point.z == 0.0 || point.z == 2.0));
+        }
+    }
+
+    #[test]
+    fn hull_3d_random_points() {
+        let coordinates = grid_values(3 * 400, 9, 9);
+        let points: Vec<Vec3> = coordinates.chunks(3).map(|c| Vec3 { x: c[0], y: c[1], z: c[2] }).collect();
+        let triangles = convex_hull_3d(&points);
+        assert_valid_hull(&points, &triangles);
+        let corners: HashSet<usize> = triangles.iter().flat_map(|t| t.iter().cloned()).collect();
+        // Euler's formula for a closed triangulated surface.
+        assert_eq!(corners.len() + triangles.len() - 3 * triangles.len() / 2, 2);
+    }
+
+    #[test]
+    fn hull_3d_points_on_a_sphere() {
+        // Points on a Fibonacci spiral, so nearly every one is a corner and
+        // each new point sees only a few faces.
+        let count = 2000;
+        let points: Vec<Vec3> = (0..count)
+            .map(|i| {
+                let z = 1.0 - (2 * i + 1) as f32 / count as f32;
+                let radius = (1.0 - z * z).sqrt();
+                let angle = i as f32 * 2.399_963;
+                Vec3 { x: radius * angle.cos(), y: radius * angle.sin(), z }
+            })
+            .collect();
+        let triangles = convex_hull_3d(&points);
+        assert_valid_hull(&points, &triangles);
+        let corners: HashSet<usize> = triangles.iter().flat_map(|t| t.iter().cloned()).collect();
+        assert!(corners.len() > count * 9 / 10);
+        assert_eq!(corners.len() + triangles.len() - 3 * triangles.len() / 2, 2);
+    }
+
+    #[test]
+    fn hull_3d_degenerate_inputs() {
+        assert!(convex_hull_3d(&[]).is_empty());
+        let line: Vec<Vec3> = (0..4).map(|i| Vec3 { x: i as f32, y: 2.0 * i as f32, z: -(i as f32) }).collect();
+        assert!(convex_hull_3d(&line).is_empty());
+
+        // A square in a tilted plane, with a point in the middle.
+        let origin = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let u = Vec3 { x: 1.0, y: 1.0, z: 0.0 };
+        let v = Vec3 { x: 0.0, y: 1.0, z: 2.0 };
+        let points = [origin, origin + u, origin + u + v, origin + v, origin + 0.5 * u + 0.5 * v];
+        let triangles = convex_hull_3d(&points);
+        assert_eq!(triangles.len(), 4);
+        assert_valid_hull(&points, &triangles);
+        assert!(triangles.iter().all(|t| !t.contains(&4)));
+        let mut with_nan = points.to_vec();
+        with_nan.push(Vec3 { x: 0.0, y: f32::NAN, z: 0.0 });
+        convex_hull_3d(&with_nan);
+    }
+}
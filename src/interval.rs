@@ -0,0 +1,327 @@
+//! Implementation of an Interval scalar for computing guaranteed bounds.
+//!
+//! Rust always rounds floating point results to the nearest representable
+//! value, which is at most half a unit in the last place away from the exact
+//! result. Every operation here moves its lower bound down and its upper
+//! bound up by one unit in the last place afterwards, so the exact result of
+//! the operation on any numbers within the input Intervals is always inside
+//! the output Interval.
+
+use std::ops;
+
+use super::{Mat2, Mat3, Mat4, Scalar, Vec2, Vec3, Vec4};
+
+/// A closed Interval `[lo, hi]` of real numbers.
+///
+/// Example:
+/// ```
+/// # use matriarch::{Interval, Mat3};
+/// let mat3 = Mat3::new_from_array(&[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 1.0]).to_interval();
+/// // The determinant is about -0.003, and the bounds prove that the exact
+/// // determinant of the stored f32 values is negative.
+/// let determinant = mat3.determinant();
+/// assert!(determinant.is_negative());
+/// assert!(determinant.width() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Interval {
+    pub lo: f32,
+    pub hi: f32,
+}
+
+impl Interval {
+    /// Returns a new Interval using the given bounds.
+    ///
+    /// Panics if `lo` is greater than `hi`.
+    pub fn new_from_values(lo: &f32, hi: &f32) -> Interval {
+        assert!(lo <= hi, "lower bound {} is greater than upper bound {}", lo, hi);
+        Interval { lo: *lo, hi: *hi }
+    }
+
+    /// Returns the Interval containing only the given value.
+    pub fn point(value: f32) -> Interval {
+        Interval { lo: value, hi: value }
+    }
+
+    /// Returns the Interval containing every real number.
+    pub fn entire() -> Interval {
+        Interval { lo: f32::NEG_INFINITY, hi: f32::INFINITY }
+    }
+
+    /// Returns an Interval with the bounds of a rounded-to-nearest result
+    /// moved outwards so it contains the exact result.
+    fn outward(lo: f32, hi: f32) -> Interval {
+        Interval { lo: lo.next_down(), hi: hi.next_up() }
+    }
+
+    /// Returns the distance between the bounds.
+    pub fn width(&self) -> f32 {
+        self.hi - self.lo
+    }
+
+    /// Returns the value halfway between the bounds.
+    ///
+    /// An Interval with one infinite bound has no middle, so this returns
+    /// its finite bound instead, and 0 for the entire real line.
+    pub fn midpoint(&self) -> f32 {
+        match (self.lo.is_infinite(), self.hi.is_infinite()) {
+            (true, true) if self.lo < self.hi => 0.0,
+            (true, false) => self.hi,
+            (false, true) => self.lo,
+            _ => 0.5 * self.lo + 0.5 * self.hi,
+        }
+    }
+
+    /// Returns true if the value is within the bounds.
+    pub fn contains(&self, value: f32) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Returns true if the Interval contains only positive numbers.
+    pub fn is_positive(&self) -> bool {
+        self.lo > 0.0
+    }
+
+    /// Returns true if the Interval contains only negative numbers.
+    pub fn is_negative(&self) -> bool {
+        self.hi < 0.0
+    }
+
+    /// Returns the square root, ignoring any part of the Interval below 0.
+    pub fn sqrt(&self) -> Interval {
+        Interval::outward(self.lo.max(0.0).sqrt(), self.hi.max(0.0).sqrt()).clamp_below(0.0)
+    }
+
+    /// Returns the Interval with any part below `min` removed.
+    fn clamp_below(&self, min: f32) -> Interval {
+        Interval { lo: self.lo.max(min), hi: self.hi.max(min) }
+    }
+}
+
+impl Scalar for Interval {
+    fn zero() -> Interval {
+        Interval::point(0.0)
+    }
+
+    fn one() -> Interval {
+        Interval::point(1.0)
+    }
+
+    fn from_f32(value: f32) -> Interval {
+        Interval::point(value)
+    }
+
+    fn sqrt(self) -> Interval {
+        Interval::sqrt(&self)
+    }
+
+    /// Any Interval containing 0 counts, since dividing by it can't be
+    /// bounded.
+    fn is_zero(self) -> bool {
+        self.contains(0.0)
+    }
+}
+
+impl ops::Add<Interval> for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        Interval::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl ops::Sub<Interval> for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        Interval::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl ops::Mul<Interval> for Interval {
+    type Output = Interval;
+
+    /// Multiplies two Intervals, taking the bounds from the smallest and
+    /// largest products of their bounds.
+    ///
+    /// A product of 0 and an infinite bound counts as 0, since every number
+    /// within the bounds is finite.
+    fn mul(self, other: Interval) -> Interval {
+        let products = [self.lo * other.lo, self.lo * other.hi, self.hi * other.lo, self.hi * other.hi];
+        let products = products.iter().map(|product| if product.is_nan() { 0.0 } else { *product });
+        let lo = products.clone().fold(f32::INFINITY, f32::min);
+        let hi = products.fold(f32::NEG_INFINITY, f32::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl ops::Div<Interval> for Interval {
+    type Output = Interval;
+
+    /// Divides two Intervals, returning the entire real line if the divisor
+    /// contains 0.
+    fn div(self, other: Interval) -> Interval {
+        if other.contains(0.0) {
+            return Interval::entire();
+        }
+        let quotients = [self.lo / other.lo, self.lo / other.hi, self.hi / other.lo, self.hi / other.hi];
+        let lo = quotients.iter().cloned().fold(f32::INFINITY, f32::min);
+        let hi = quotients.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl ops::Neg for Interval {
+    type Output = Interval;
+
+    /// Negates the Interval, which is exact.
+    fn neg(self) -> Interval {
+        Interval { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl ops::Mul<Interval> for f32 {
+    type Output = Interval;
+
+    /// Multiplies a scalar value by the Interval.
+    fn mul(self, interval: Interval) -> Interval {
+        Interval::point(self) * interval
+    }
+}
+
+macro_rules! impl_interval_components {
+    ($name:ident { $($field:ident),+ }) => {
+        impl $name {
+            /// Returns the same value with Interval components, each
+            /// containing only the original component.
+            pub fn to_interval(&self) -> $name<Interval> {
+                $name { $($field: Interval::point(self.$field),)+ }
+            }
+        }
+
+        impl $name<Interval> {
+            /// Returns the lower bound of each component.
+            pub fn lo(&self) -> $name {
+                $name { $($field: self.$field.lo,)+ }
+            }
+
+            /// Returns the upper bound of each component.
+            pub fn hi(&self) -> $name {
+                $name { $($field: self.$field.hi,)+ }
+            }
+        }
+
+        impl ops::Mul<$name<Interval>> for Interval {
+            type Output = $name<Interval>;
+
+            /// Multiplies an Interval by each component.
+            fn mul(self, other: $name<Interval>) -> $name<Interval> {
+                $name { $($field: self * other.$field,)+ }
+            }
+        }
+    };
+}
+
+impl_interval_components!(Vec2 { x, y });
+impl_interval_components!(Vec3 { x, y, z });
+impl_interval_components!(Vec4 { x, y, z, w });
+impl_interval_components!(Mat2 { a, b, c, d });
+impl_interval_components!(Mat3 { a, b, c, d, e, f, g, h, i });
+impl_interval_components!(Mat4 { a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p });
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, Mat4, Vec3, Vec4};
+    use crate::test_values::values;
+
+    fn contains(interval: &Interval, value: f64) -> bool {
+        interval.lo as f64 <= value && value <= interval.hi as f64
+    }
+
+    #[test]
+    fn arithmetic_contains_exact_result() {
+        let a = Interval::new_from_values(&-1.5, &2.0);
+        let b = Interval::new_from_values(&0.1, &3.0);
+        let sum = a + b;
+        assert!(sum.lo <= -1.4 && sum.hi >= 5.0);
+        let product = a * b;
+        assert!(product.lo <= -4.5 && product.hi >= 6.0);
+        let quotient = a / b;
+        assert!(quotient.lo <= -15.0 && quotient.hi >= 20.0);
+        assert_eq!(-a, Interval::new_from_values(&-2.0, &1.5));
+        assert_eq!(b / a, Interval::entire());
+        let zero_times_entire = Interval::entire() * Interval::new_from_values(&0.0, &1.0);
+        assert_eq!(zero_times_entire, Interval::entire());
+
+        // 0.1 + 0.2 isn't exact in f32, so the result must have width.
+        let inexact = Interval::point(0.1) + Interval::point(0.2);
+        assert!(inexact.width() > 0.0);
+        assert!(contains(&inexact, 0.1f32 as f64 + 0.2f32 as f64));
+    }
+
+    #[test]
+    fn sqrt_bounds() {
+        let root = Interval::new_from_values(&-1.0, &2.0).sqrt();
+        assert_eq!(root.lo, 0.0);
+        assert!(root.contains(std::f32::consts::SQRT_2));
+        assert!(Interval::point(9.0).sqrt().contains(3.0));
+    }
+
+    #[test]
+    fn midpoint_of_unbounded_intervals() {
+        assert_eq!(Interval::new_from_values(&-1.0, &3.0).midpoint(), 1.0);
+        assert_eq!(Interval::new_from_values(&f32::MAX, &f32::MAX).midpoint(), f32::MAX);
+        assert_eq!(Interval::entire().midpoint(), 0.0);
+        assert_eq!(Interval::new_from_values(&2.0, &f32::INFINITY).midpoint(), 2.0);
+        assert_eq!(Interval::new_from_values(&f32::NEG_INFINITY, &-2.0).midpoint(), -2.0);
+    }
+
+    #[test]
+    fn mat4_times_vec4_contains_exact_result() {
+        let components = values(16 * 50, 7);
+        let points = values(4 * 50, 11);
+        for (matrix, point) in components.chunks(16).zip(points.chunks(4)) {
+            let mut array = [0.0; 16];
+            array.copy_from_slice(matrix);
+            let mat4 = Mat4::new_from_array(&array);
+            let vec4 = Vec4::new_from_array(&[point[0], point[1], point[2], point[3]]);
+            let bounds = mat4.to_interval() * vec4.to_interval();
+            // f64 products of f32 values are exact, and the sums here are
+            // far more accurate than the f32 rounding being bounded.
+            for (row, bound) in [bounds.x, bounds.y, bounds.z, bounds.w].iter().enumerate() {
+                let exact: f64 = (0..4).map(|col| array[row * 4 + col] as f64 * point[col] as f64).sum();
+                assert!(contains(bound, exact), "{:?} doesn't contain {}", bound, exact);
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_contains_exact_result() {
+        let components = values(16 * 50, 3);
+        for matrix in components.chunks(16) {
+            let mut array = [0.0; 16];
+            array.copy_from_slice(matrix);
+            let bounds = Mat4::new_from_array(&array).to_interval().determinant();
+            let m: Vec<f64> = array.iter().map(|x| *x as f64).collect();
+            let minor = |a: usize, b: usize, c: usize, d: usize| m[a] * m[d] - m[b] * m[c];
+            // Laplace expansion along the top two rows in f64.
+            let exact = minor(0, 1, 4, 5) * minor(10, 11, 14, 15) - minor(0, 2, 4, 6) * minor(9, 11, 13, 15)
+                + minor(0, 3, 4, 7) * minor(9, 10, 13, 14) + minor(1, 2, 5, 6) * minor(8, 11, 12, 15)
+                - minor(1, 3, 5, 7) * minor(8, 10, 12, 14) + minor(2, 3, 6, 7) * minor(8, 9, 12, 13);
+            assert!(contains(&bounds, exact), "{:?} doesn't contain {}", bounds, exact);
+            assert!(bounds.width() < 1e-3 * (1.0 + exact.abs() as f32));
+        }
+    }
+
+    #[test]
+    fn vec3_length_and_cross_product() {
+        let a = Vec3 { x: 0.1, y: 0.2, z: 0.3 };
+        let b = Vec3 { x: -0.7, y: 0.5, z: 0.11 };
+        let cross = a.to_interval().cross_product(&b.to_interval());
+        let exact = a.cross_product(&b);
+        assert!(cross.x.contains(exact.x) && cross.y.contains(exact.y) && cross.z.contains(exact.z));
+        assert!(a.to_interval().length().contains(a.length()));
+        assert!(cross.lo().x <= cross.hi().x);
+    }
+}
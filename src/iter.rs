@@ -0,0 +1,251 @@
+//! Iterator, slice and row/column access to the components of vectors and
+//! matrices, along with `Sum` and `Product` over iterators of them.
+//!
+//! Every vector and matrix is `#[repr(C)]` with only `f32` fields, so its
+//! components are laid out contiguously in declaration order. For matrices
+//! that is row-major order, the same as `to_array`.
+
+use std::convert::TryInto;
+use std::iter::{Product, Sum};
+use std::{array, slice};
+
+use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+macro_rules! impl_component_access {
+    ($name:ident, $len:expr, $zero:expr) => {
+        impl $name {
+            /// Returns the components as a slice, in the same order as
+            /// `to_array`.
+            pub fn as_slice(&self) -> &[f32] {
+                // Safe because the type is `#[repr(C)]` and made only of
+                // contiguous f32s.
+                unsafe { slice::from_raw_parts(self as *const $name as *const f32, $len) }
+            }
+
+            /// Returns the components as a mutable slice, in the same order as
+            /// `to_array`.
+            pub fn as_mut_slice(&mut self) -> &mut [f32] {
+                unsafe { slice::from_raw_parts_mut(self as *mut $name as *mut f32, $len) }
+            }
+
+            /// Returns an iterator over the components.
+            pub fn iter(&self) -> slice::Iter<'_, f32> {
+                self.as_slice().iter()
+            }
+
+            /// Returns an iterator that allows modifying each component.
+            pub fn iter_mut(&mut self) -> slice::IterMut<'_, f32> {
+                self.as_mut_slice().iter_mut()
+            }
+
+            /// Returns a new value with `f` applied to each component.
+            pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> $name {
+                let mut result = *self;
+                for component in result.iter_mut() {
+                    *component = f(*component);
+                }
+                result
+            }
+
+            /// Returns a new value with `f` applied to each pair of
+            /// corresponding components.
+            pub fn zip_map<F: Fn(f32, f32) -> f32>(&self, other: &$name, f: F) -> $name {
+                let mut result = *self;
+                for (component, other) in result.iter_mut().zip(other.iter()) {
+                    *component = f(*component, *other);
+                }
+                result
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a f32;
+            type IntoIter = slice::Iter<'a, f32>;
+
+            fn into_iter(self) -> slice::Iter<'a, f32> {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $name {
+            type Item = &'a mut f32;
+            type IntoIter = slice::IterMut<'a, f32>;
+
+            fn into_iter(self) -> slice::IterMut<'a, f32> {
+                self.iter_mut()
+            }
+        }
+
+        impl Sum for $name {
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+                iter.fold($zero, |total, value| total + value)
+            }
+        }
+
+        impl<'a> Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+                iter.fold($zero, |total, value| total + *value)
+            }
+        }
+    };
+}
+
+impl_component_access!(Vec2, 2, Vec2::new());
+impl_component_access!(Vec3, 3, Vec3::new());
+impl_component_access!(Vec4, 4, Vec4::new());
+impl_component_access!(Mat2, 4, Mat2::new());
+impl_component_access!(Mat3, 9, Mat3::new());
+impl_component_access!(Mat4, 16, Mat4::new());
+
+macro_rules! impl_matrix_access {
+    ($name:ident, $vec:ident, $size:expr) => {
+        impl $name {
+            /// Returns row `index` as a vector.
+            ///
+            /// Panics if `index` is out of range.
+            pub fn row(&self, index: usize) -> $vec {
+                let start = index * $size;
+                $vec::new_from_array(self.as_slice()[start..start + $size].try_into().unwrap())
+            }
+
+            /// Returns column `index` as a vector.
+            ///
+            /// Panics if `index` is out of range.
+            pub fn col(&self, index: usize) -> $vec {
+                assert!(index < $size, "column index {} out of range", index);
+                let mut column = $vec::new();
+                for (row, component) in column.iter_mut().enumerate() {
+                    *component = self.as_slice()[(row * $size) + index];
+                }
+                column
+            }
+
+            /// Replaces row `index` with the given vector.
+            ///
+            /// Panics if `index` is out of range.
+            pub fn set_row(&mut self, index: usize, row: &$vec) {
+                let start = index * $size;
+                self.as_mut_slice()[start..start + $size].copy_from_slice(row.as_slice());
+            }
+
+            /// Replaces column `index` with the given vector.
+            ///
+            /// Panics if `index` is out of range.
+            pub fn set_col(&mut self, index: usize, column: &$vec) {
+                assert!(index < $size, "column index {} out of range", index);
+                for (row, component) in column.iter().enumerate() {
+                    self.as_mut_slice()[(row * $size) + index] = *component;
+                }
+            }
+
+            /// Returns an iterator over the rows of the matrix.
+            pub fn rows(&self) -> array::IntoIter<$vec, $size> {
+                let mut rows = [$vec::new(); $size];
+                for (index, row) in rows.iter_mut().enumerate() {
+                    *row = self.row(index);
+                }
+                IntoIterator::into_iter(rows)
+            }
+
+            /// Returns an iterator over the columns of the matrix.
+            pub fn columns(&self) -> array::IntoIter<$vec, $size> {
+                let mut columns = [$vec::new(); $size];
+                for (index, column) in columns.iter_mut().enumerate() {
+                    *column = self.col(index);
+                }
+                IntoIterator::into_iter(columns)
+            }
+        }
+
+        impl Product for $name {
+            fn product<I: Iterator<Item = $name>>(iter: I) -> $name {
+                iter.fold($name::identity(), |total, value| total * value)
+            }
+        }
+
+        impl<'a> Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a $name>>(iter: I) -> $name {
+                iter.fold($name::identity(), |total, value| total * *value)
+            }
+        }
+    };
+}
+
+impl_matrix_access!(Mat2, Vec2, 2);
+impl_matrix_access!(Mat3, Vec3, 3);
+impl_matrix_access!(Mat4, Vec4, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+    #[test]
+    fn vec3_as_slice() {
+        let mut vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_eq!(vec3.as_slice(), &[1.0, 2.0, 3.0]);
+        vec3.as_mut_slice()[1] = 5.0;
+        assert_eq!(vec3, Vec3 { x: 1.0, y: 5.0, z: 3.0 });
+    }
+
+    #[test]
+    fn iterate_over_components() {
+        let mut vec4 = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(vec4.iter().sum::<f32>(), 10.0);
+        for component in &mut vec4 {
+            *component *= 2.0;
+        }
+        assert_eq!(vec4, Vec4 { x: 2.0, y: 4.0, z: 6.0, w: 8.0 });
+
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        assert_eq!(mat2.iter().copied().collect::<Vec<f32>>(), mat2.to_array().to_vec());
+    }
+
+    #[test]
+    fn map_and_zip_map() {
+        let vec2 = Vec2 { x: -1.5, y: 2.5 };
+        assert_eq!(vec2.map(f32::abs), Vec2 { x: 1.5, y: 2.5 });
+        let other = Vec2 { x: 3.0, y: 1.0 };
+        assert_eq!(vec2.zip_map(&other, f32::max), Vec2 { x: 3.0, y: 2.5 });
+        assert_eq!(Mat3::identity().map(|x| x * 2.0), 2.0 * Mat3::identity());
+    }
+
+    #[test]
+    fn get_rows_and_columns() {
+        let mat3 = Mat3::new_from_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(mat3.row(1), Vec3 { x: 4.0, y: 5.0, z: 6.0 });
+        assert_eq!(mat3.col(2), Vec3 { x: 3.0, y: 6.0, z: 9.0 });
+        let columns: Vec<Vec3> = mat3.columns().collect();
+        assert_eq!(columns, mat3.to_vec3_array().to_vec());
+        let rows: Vec<Vec3> = mat3.rows().collect();
+        assert_eq!(rows, mat3.transpose().to_vec3_array().to_vec());
+    }
+
+    #[test]
+    fn set_rows_and_columns() {
+        let mut mat4 = Mat4::new();
+        mat4.set_row(0, &Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+        mat4.set_col(3, &Vec4 { x: 5.0, y: 6.0, z: 7.0, w: 8.0 });
+        assert_eq!(mat4.row(0), Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 5.0 });
+        assert_eq!(mat4.col(3), Vec4 { x: 5.0, y: 6.0, z: 7.0, w: 8.0 });
+        assert_eq!(mat4.h, 6.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_column_out_of_range() {
+        Mat2::identity().col(2);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let vectors = [Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 0.0, y: 2.0, z: 1.0 }];
+        assert_eq!(vectors.iter().sum::<Vec3>(), Vec3 { x: 1.0, y: 2.0, z: 1.0 });
+        assert_eq!(vectors.iter().copied().sum::<Vec3>(), Vec3 { x: 1.0, y: 2.0, z: 1.0 });
+
+        let a = Mat2::new_from_values(&1.0, &2.0, &1.0, &3.0);
+        let b = Mat2::new_from_values(&1.5, &2.25, &1.25, &2.0);
+        assert_eq!([a, b].iter().product::<Mat2>(), a * b);
+        assert_eq!(Vec::<Mat2>::new().into_iter().product::<Mat2>(), Mat2::identity());
+        assert_eq!([a, b].iter().sum::<Mat2>(), a + b);
+    }
+}
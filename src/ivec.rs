@@ -0,0 +1,227 @@
+//! Implementation of signed and unsigned integer vectors, such as those used
+//! to index voxel grids and tile maps.
+
+use std::ops;
+
+use super::{Vec2, Vec3, Vec4};
+
+macro_rules! int_vec {
+    (
+        $(#[$doc:meta])*
+        $name:ident($scalar:ty; $len:expr) { $($field:ident),+ },
+        $vec:ident {
+            from_floor: $floor:ident,
+            from_round: $round:ident,
+            from_truncate: $truncate:ident,
+            to: $to_vec:ident
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name {
+            $(pub $field: $scalar,)+
+        }
+
+        impl $name {
+            /// Returns a new vector with every component set to 0.
+            pub fn new() -> $name {
+                $name { $($field: 0,)+ }
+            }
+
+            /// Returns a new vector using the given component values.
+            pub fn new_from_values($($field: &$scalar),+) -> $name {
+                $name { $($field: *$field,)+ }
+            }
+
+            /// Returns a new vector with every component set to `value`.
+            pub fn splat(value: $scalar) -> $name {
+                $name { $($field: value,)+ }
+            }
+
+            /// Returns a new vector using the components of the given array in
+            /// order.
+            pub fn new_from_array(input: &[$scalar; $len]) -> $name {
+                let mut iter = input.iter();
+                $name { $($field: *iter.next().unwrap(),)+ }
+            }
+
+            /// Returns an array of the vector's components in order.
+            pub fn to_array(&self) -> [$scalar; $len] {
+                [$(self.$field),+]
+            }
+
+            /// Returns a new vector rounding each component of the float
+            /// vector towards negative infinity.
+            ///
+            /// Components outside the range of the integer type saturate to
+            /// its minimum or maximum.
+            pub fn $floor(input: &$vec) -> $name {
+                $name { $($field: input.$field.floor() as $scalar,)+ }
+            }
+
+            /// Returns a new vector rounding each component of the float
+            /// vector to the nearest integer, with halves rounded away from 0.
+            pub fn $round(input: &$vec) -> $name {
+                $name { $($field: input.$field.round() as $scalar,)+ }
+            }
+
+            /// Returns a new vector dropping the fractional part of each
+            /// component of the float vector.
+            pub fn $truncate(input: &$vec) -> $name {
+                $name { $($field: input.$field as $scalar,)+ }
+            }
+
+            /// Returns the vector converted to floating point.
+            pub fn $to_vec(&self) -> $vec {
+                $vec { $($field: self.$field as f32,)+ }
+            }
+        }
+
+        impl ops::Add<$name> for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name { $($field: self.$field + other.$field,)+ }
+            }
+        }
+
+        impl ops::AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                *self = *self + other;
+            }
+        }
+
+        impl ops::Sub<$name> for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name { $($field: self.$field - other.$field,)+ }
+            }
+        }
+
+        impl ops::SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                *self = *self - other;
+            }
+        }
+
+        impl ops::Mul<$name> for $scalar {
+            type Output = $name;
+
+            /// Multiplies a scalar value by each component of the vector.
+            fn mul(self, other: $name) -> $name {
+                $name { $($field: self * other.$field,)+ }
+            }
+        }
+
+        impl ops::Mul<$name> for $name {
+            type Output = $scalar;
+
+            /// Returns the dot product of 2 vectors.
+            fn mul(self, other: $name) -> $scalar {
+                0 $(+ (self.$field * other.$field))+
+            }
+        }
+    };
+}
+
+int_vec!(
+    /// A 2D signed integer vector with elements x and y
+    IVec2(i32; 2) { x, y },
+    Vec2 { from_floor: new_from_vec2_floor, from_round: new_from_vec2_round, from_truncate: new_from_vec2_truncate, to: to_vec2 }
+);
+int_vec!(
+    /// A 3D signed integer vector with elements x, y, and z
+    IVec3(i32; 3) { x, y, z },
+    Vec3 { from_floor: new_from_vec3_floor, from_round: new_from_vec3_round, from_truncate: new_from_vec3_truncate, to: to_vec3 }
+);
+int_vec!(
+    /// A 4D signed integer vector with elements x, y, z, and w
+    IVec4(i32; 4) { x, y, z, w },
+    Vec4 { from_floor: new_from_vec4_floor, from_round: new_from_vec4_round, from_truncate: new_from_vec4_truncate, to: to_vec4 }
+);
+int_vec!(
+    /// A 2D unsigned integer vector with elements x and y
+    UVec2(u32; 2) { x, y },
+    Vec2 { from_floor: new_from_vec2_floor, from_round: new_from_vec2_round, from_truncate: new_from_vec2_truncate, to: to_vec2 }
+);
+int_vec!(
+    /// A 3D unsigned integer vector with elements x, y, and z
+    UVec3(u32; 3) { x, y, z },
+    Vec3 { from_floor: new_from_vec3_floor, from_round: new_from_vec3_round, from_truncate: new_from_vec3_truncate, to: to_vec3 }
+);
+int_vec!(
+    /// A 4D unsigned integer vector with elements x, y, z, and w
+    UVec4(u32; 4) { x, y, z, w },
+    Vec4 { from_floor: new_from_vec4_floor, from_round: new_from_vec4_round, from_truncate: new_from_vec4_truncate, to: to_vec4 }
+);
+
+macro_rules! impl_neg {
+    ($name:ident { $($field:ident),+ }) => {
+        impl ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name { $($field: -self.$field,)+ }
+            }
+        }
+    };
+}
+
+impl_neg!(IVec2 { x, y });
+impl_neg!(IVec3 { x, y, z });
+impl_neg!(IVec4 { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::{IVec2, IVec3, IVec4, UVec2, UVec3, Vec2, Vec3, Vec4};
+
+    #[test]
+    fn create_new_ivec3() {
+        assert_eq!(IVec3::new(), IVec3 { x: 0, y: 0, z: 0 });
+        assert_eq!(IVec3::new_from_values(&1, &-2, &3), IVec3 { x: 1, y: -2, z: 3 });
+        assert_eq!(IVec3::new_from_array(&[4, 5, 6]), IVec3 { x: 4, y: 5, z: 6 });
+        assert_eq!(IVec3::splat(7).to_array(), [7, 7, 7]);
+    }
+
+    #[test]
+    fn ivec_arithmetic() {
+        let mut a = IVec2 { x: 1, y: -2 };
+        let b = IVec2 { x: 3, y: 4 };
+        assert_eq!(a + b, IVec2 { x: 4, y: 2 });
+        assert_eq!(a - b, IVec2 { x: -2, y: -6 });
+        assert_eq!(3 * a, IVec2 { x: 3, y: -6 });
+        assert_eq!(a * b, -5);
+        assert_eq!(-a, IVec2 { x: -1, y: 2 });
+        a += b;
+        a -= IVec2 { x: 1, y: 1 };
+        assert_eq!(a, IVec2 { x: 3, y: 1 });
+    }
+
+    #[test]
+    fn uvec_arithmetic() {
+        let a = UVec3 { x: 1, y: 2, z: 3 };
+        let b = UVec3 { x: 4, y: 5, z: 6 };
+        assert_eq!(a + b, UVec3 { x: 5, y: 7, z: 9 });
+        assert_eq!(b - a, UVec3 { x: 3, y: 3, z: 3 });
+        assert_eq!(a * b, 32);
+    }
+
+    #[test]
+    fn convert_from_float_vectors() {
+        let vec3 = Vec3 { x: -1.5, y: 2.5, z: 0.7 };
+        assert_eq!(IVec3::new_from_vec3_floor(&vec3), IVec3 { x: -2, y: 2, z: 0 });
+        assert_eq!(IVec3::new_from_vec3_round(&vec3), IVec3 { x: -2, y: 3, z: 1 });
+        assert_eq!(IVec3::new_from_vec3_truncate(&vec3), IVec3 { x: -1, y: 2, z: 0 });
+
+        // Negative values saturate to 0 for unsigned vectors.
+        let vec2 = Vec2 { x: -3.5, y: 3.5 };
+        assert_eq!(UVec2::new_from_vec2_floor(&vec2), UVec2 { x: 0, y: 3 });
+    }
+
+    #[test]
+    fn convert_to_float_vectors() {
+        let ivec4 = IVec4 { x: 1, y: -2, z: 3, w: -4 };
+        assert_eq!(ivec4.to_vec4(), Vec4 { x: 1.0, y: -2.0, z: 3.0, w: -4.0 });
+    }
+}
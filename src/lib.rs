@@ -2,16 +2,80 @@
 //!
 //! Matriarch is a Linear Algebra and Matrix library written in pure Rust.
 
+mod aabb;
+mod animation;
+mod bvec;
+mod bvh;
+mod complex;
+mod curve;
+mod decompose;
+mod delaunay;
+mod dual;
+mod dual_quat;
+mod euler;
+mod frustum;
+#[cfg(feature = "glam")]
+mod glam_conversions;
+mod hull;
+mod interval;
+mod iter;
+mod ivec;
 mod mat2;
 mod mat3;
 mod mat4;
+mod matrix_functions;
+#[cfg(feature = "mint")]
+mod mint_conversions;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions;
+mod obb;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod plane;
+mod predicates;
+mod quat;
+mod ray;
+mod scalar;
+mod sphere;
+mod surface;
+#[cfg(feature = "swizzle")]
+mod swizzle;
+#[cfg(test)]
+mod test_values;
+mod transform;
 mod vec2;
 mod vec3;
+mod vec3_wide;
 mod vec4;
 
+pub use self::aabb::Aabb;
+pub use self::animation::{Animatable, Interpolation, Keyframe, Track, Trs, TrsChannels};
+pub use self::bvec::{BVec2, BVec3, BVec4};
+pub use self::bvh::{closest_point_on_triangle, Bvh, RayHit};
+pub use self::complex::{Complex, Rotation2};
+pub use self::curve::{ArcLengthTable, BSpline, CatmullRom, CubicBezier, Curve, CurvePoint, Hermite};
+pub use self::decompose::Decomposition;
+pub use self::delaunay::{HalfEdge, Triangulation, Voronoi, VoronoiCell};
+pub use self::dual::Dual;
+pub use self::dual_quat::DualQuat;
+pub use self::euler::EulerRot;
+pub use self::frustum::{Frustum, Intersection};
+pub use self::hull::{convex_hull_2d, convex_hull_3d};
+pub use self::interval::Interval;
+pub use self::ivec::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4};
 pub use self::mat2::Mat2;
 pub use self::mat3::Mat3;
 pub use self::mat4::Mat4;
+pub use self::obb::Obb;
+pub use self::plane::Plane;
+pub use self::predicates::{incircle, insphere, orient2d, orient3d, Sign};
+pub use self::quat::Quat;
+pub use self::ray::{screen_point_to_ray, Ray};
+pub use self::scalar::Scalar;
+pub use self::sphere::Sphere;
+pub use self::surface::{BezierPatch, NurbsSurface, Surface, SurfaceMesh};
+pub use self::transform::{Affine2, Affine3, Isometry2, Isometry3, Similarity2, Similarity3};
 pub use self::vec2::Vec2;
 pub use self::vec3::Vec3;
+pub use self::vec3_wide::{Vec3x4, Vec3x8};
 pub use self::vec4::Vec4;
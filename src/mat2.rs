@@ -2,7 +2,7 @@
 
 use std::ops;
 
-use super::Vec2;
+use super::{Scalar, Vec2};
 
 /// A 2x2 Matrix with elements arranged in row-major order.
 /// 
@@ -14,11 +14,12 @@ use super::Vec2;
 /// ```
 /// 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Mat2 {
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-    pub d: f32,
+#[repr(C)]
+pub struct Mat2<T = f32> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
 }
 
 impl Mat2 {
@@ -37,9 +38,11 @@ impl Mat2 {
             c: 0.0, d: 1.0
         }
     }
+}
 
+impl<T: Scalar> Mat2<T> {
     /// Creates a new Mat2 using the borrowed values.
-    pub fn new_from_values(a: &f32, b: &f32, c: &f32, d: &f32) -> Mat2 {
+    pub fn new_from_values(a: &T, b: &T, c: &T, d: &T) -> Mat2<T> {
         Mat2 {
             a: *a, b: *b,
             c: *c, d: *d
@@ -47,7 +50,7 @@ impl Mat2 {
     }
 
     /// Creates a new Mat2 from a row-major ordered array.
-    pub fn new_from_array(input: &[f32; 4]) -> Mat2 {
+    pub fn new_from_array(input: &[T; 4]) -> Mat2<T> {
         Mat2 {
             a: input[0], b: input[1],
             c: input[2], d: input[3]
@@ -55,7 +58,7 @@ impl Mat2 {
     }
 
     /// Creates a Mat2 from a column-major ordered array.
-    pub fn new_from_col_array(input: &[f32; 4]) -> Mat2 {
+    pub fn new_from_col_array(input: &[T; 4]) -> Mat2<T> {
         Mat2 {
             a: input[0], b: input[2],
             c: input[1], d: input[3]
@@ -63,30 +66,43 @@ impl Mat2 {
     }
 
     /// Returns an array of the Mat2 elements in row-major order.
-    pub fn to_array(&self) -> [f32; 4] {
+    pub fn to_array(&self) -> [T; 4] {
         [self.a, self.b, self.c, self.d]
     }
 
     /// Returns an array of the Mat2 elements in column-major order.
-    pub fn to_col_array(&self) -> [f32; 4] {
+    pub fn to_col_array(&self) -> [T; 4] {
         [self.a, self.c, self.b, self.d]
     }
 
     /// Returns the matrix as an array of Vec2 columns
-    pub fn to_vec2_array(&self) -> [Vec2; 2] {
+    pub fn to_vec2_array(&self) -> [Vec2<T>; 2] {
         [
             Vec2 { x: self.a, y: self.c },
             Vec2 { x: self.b, y: self.d }
         ]
     }
 
+    /// Returns the inverse of the Mat2, or `None` if the Mat2 is singular.
+    pub fn inverse(&self) -> Option<Mat2<T>> {
+        let determinant = self.determinant();
+        if determinant.is_zero() {
+            return None;
+        }
+        let inv = determinant.recip();
+        Some(Mat2 {
+            a:  self.d * inv, b: -self.b * inv,
+            c: -self.c * inv, d:  self.a * inv
+        })
+    }
+
     /// Returns the determinant of a Mat2.
-    pub fn determinant(&self) -> f32 {
+    pub fn determinant(&self) -> T {
         (self.a * self.d) - (self.b * self.c)
     }
 
     /// Returns a new Mat2 that is the tranpose of the original Mat2.
-    pub fn transpose(&self) -> Mat2 {
+    pub fn transpose(&self) -> Mat2<T> {
         Mat2 {
             a: self.a, b: self.c,
             c: self.b, d: self.d
@@ -94,8 +110,36 @@ impl Mat2 {
     }
 }
 
-impl ops::Mul<Mat2> for Mat2 {
-    type Output = Mat2;
+impl<T: Scalar> ops::Add<Mat2<T>> for Mat2<T> {
+    type Output = Mat2<T>;
+
+    /// Adds two Mat2s together element-wise, returning a new Mat2.
+    fn add(self, other_mat2: Mat2<T>) -> Mat2<T> {
+        Mat2 {
+            a: self.a + other_mat2.a,
+            b: self.b + other_mat2.b,
+            c: self.c + other_mat2.c,
+            d: self.d + other_mat2.d,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Sub<Mat2<T>> for Mat2<T> {
+    type Output = Mat2<T>;
+
+    /// Subtracts one Mat2 from another element-wise, returning a new Mat2.
+    fn sub(self, other_mat2: Mat2<T>) -> Mat2<T> {
+        Mat2 {
+            a: self.a - other_mat2.a,
+            b: self.b - other_mat2.b,
+            c: self.c - other_mat2.c,
+            d: self.d - other_mat2.d,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Mul<Mat2<T>> for Mat2<T> {
+    type Output = Mat2<T>;
 
     /// Multiplies two Mat2s together, returning a new Mat2.
     /// 
@@ -110,7 +154,7 @@ impl ops::Mul<Mat2> for Mat2 {
     /// # let some_other_mat2 = Mat2::new();
     /// let some_mat2 = mat2 * some_other_mat2;
     /// ```
-    fn mul(self, other_mat2: Mat2) -> Mat2 {
+    fn mul(self, other_mat2: Mat2<T>) -> Mat2<T> {
         Mat2 {
             a: (self.a * other_mat2.a) + (self.b * other_mat2.c),
             b: (self.a * other_mat2.b) + (self.b * other_mat2.d),
@@ -120,11 +164,11 @@ impl ops::Mul<Mat2> for Mat2 {
     }
 }
 
-impl ops::Mul<Vec2> for Mat2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Mul<Vec2<T>> for Mat2<T> {
+    type Output = Vec2<T>;
 
     /// Multiplies a Mat2 by a Vec2, returning a Vec2.
-    fn mul(self, vec2: Vec2) -> Vec2 {
+    fn mul(self, vec2: Vec2<T>) -> Vec2<T> {
         Vec2 {
             x: (self.a * vec2.x) + (self.b * vec2.y),
             y: (self.c * vec2.x) + (self.d * vec2.y),
@@ -132,8 +176,8 @@ impl ops::Mul<Vec2> for Mat2 {
     }
 }
 
-impl ops::Mul<Mat2> for f32 {
-    type Output = Mat2;
+impl<T: Scalar> ops::Mul<Mat2<T>> for f32 {
+    type Output = Mat2<T>;
 
     /// Multiplies a Mat2 and a scalar together and returns the Mat2.
     /// 
@@ -144,12 +188,13 @@ impl ops::Mul<Mat2> for f32 {
     /// let some_mat2 = Mat2::identity();
     /// let result = scalar * some_mat2;
     /// ```
-    fn mul(self, mat2: Mat2) -> Mat2 {
+    fn mul(self, mat2: Mat2<T>) -> Mat2<T> {
+        let scalar = T::from_f32(self);
         Mat2 {
-            a: self * mat2.a,
-            b: self * mat2.b,
-            c: self * mat2.c,
-            d: self * mat2.d,
+            a: scalar * mat2.a,
+            b: scalar * mat2.b,
+            c: scalar * mat2.c,
+            d: scalar * mat2.d,
         }
     }
 }
@@ -221,6 +266,28 @@ mod tests {
         assert_eq!(mat2.determinant(), 0.0);
     }
 
+    #[test]
+    fn get_inverse_of_mat2() {
+        let mat2 = Mat2 { a: 4.0, b: 7.0, c: 2.0, d: 6.0 };
+        let inverse = mat2.inverse().unwrap();
+        assert_eq!(inverse, Mat2 { a: 0.6, b: -0.7, c: -0.2, d: 0.4 });
+        assert_eq!(Mat2 { a: 2.0, b: 3.0, c: 4.0, d: 6.0 }.inverse(), None);
+    }
+
+    #[test]
+    fn add_2_mat2s_together() {
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        let other_mat2 = Mat2::new_from_values(&0.5, &-1.0, &2.0, &0.0);
+        assert_eq!(mat2 + other_mat2, Mat2::new_from_values(&1.5, &1.0, &5.0, &4.0));
+    }
+
+    #[test]
+    fn subtract_2_mat2s() {
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        let other_mat2 = Mat2::new_from_values(&0.5, &-1.0, &2.0, &0.0);
+        assert_eq!(mat2 - other_mat2, Mat2::new_from_values(&0.5, &3.0, &1.0, &4.0));
+    }
+
     #[test]
     fn multiply_by_identity() {
         let mat2 = Mat2::new_from_values(&2.0, &3.0, &4.0, &5.0);
@@ -2,7 +2,7 @@
 
 use std::ops;
 
-use super::Vec3;
+use super::{Scalar, Vec3};
 
 /// A 3x3 Matrix with elements arranged in row-major order.
 ///
@@ -14,16 +14,17 @@ use super::Vec3;
 ///     [ g  h  i ]
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Mat3 {
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-    pub d: f32,
-    pub e: f32,
-    pub f: f32,
-    pub g: f32,
-    pub h: f32,
-    pub i: f32,
+#[repr(C)]
+pub struct Mat3<T = f32> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub e: T,
+    pub f: T,
+    pub g: T,
+    pub h: T,
+    pub i: T,
 }
 
 impl Mat3 {
@@ -43,7 +44,42 @@ impl Mat3 {
         }
     }
 
-    pub fn new_from_array(input: &[f32; 9]) -> Mat3 {
+    /// Returns a Mat3 rotating counter-clockwise by `angle` radians about the
+    /// x axis.
+    pub fn new_from_rotation_x(angle: f32) -> Mat3 {
+        let (sin, cos) = angle.sin_cos();
+        Mat3 {
+            a: 1.0, b: 0.0, c:  0.0,
+            d: 0.0, e: cos, f: -sin,
+            g: 0.0, h: sin, i:  cos
+        }
+    }
+
+    /// Returns a Mat3 rotating counter-clockwise by `angle` radians about the
+    /// y axis.
+    pub fn new_from_rotation_y(angle: f32) -> Mat3 {
+        let (sin, cos) = angle.sin_cos();
+        Mat3 {
+            a:  cos, b: 0.0, c: sin,
+            d:  0.0, e: 1.0, f: 0.0,
+            g: -sin, h: 0.0, i: cos
+        }
+    }
+
+    /// Returns a Mat3 rotating counter-clockwise by `angle` radians about the
+    /// z axis.
+    pub fn new_from_rotation_z(angle: f32) -> Mat3 {
+        let (sin, cos) = angle.sin_cos();
+        Mat3 {
+            a: cos, b: -sin, c: 0.0,
+            d: sin, e:  cos, f: 0.0,
+            g: 0.0, h:  0.0, i: 1.0
+        }
+    }
+}
+
+impl<T: Scalar> Mat3<T> {
+    pub fn new_from_array(input: &[T; 9]) -> Mat3<T> {
         Mat3 {
             a: input[0], b: input[1], c: input[2],
             d: input[3], e: input[4], f: input[5],
@@ -51,7 +87,7 @@ impl Mat3 {
         }
     }
 
-    pub fn new_from_col_array(input: &[f32; 9]) -> Mat3 {
+    pub fn new_from_col_array(input: &[T; 9]) -> Mat3<T> {
         Mat3 {
             a: input[0], b: input[3], c: input[6],
             d: input[1], e: input[4], f: input[7],
@@ -59,15 +95,15 @@ impl Mat3 {
         }
     }
 
-    pub fn to_array(&self) -> [f32; 9] {
+    pub fn to_array(&self) -> [T; 9] {
         [ self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h, self.i]
     }
 
-    pub fn to_col_array(&self) -> [f32; 9] {
+    pub fn to_col_array(&self) -> [T; 9] {
         [ self.a, self.d, self.g, self.b, self.e, self.h, self.c, self.f, self.i]
     }
 
-    pub fn to_vec3_array(&self) -> [Vec3; 3] {
+    pub fn to_vec3_array(&self) -> [Vec3<T>; 3] {
         [
             Vec3 { x: self.a, y: self.d, z: self.g },
             Vec3 { x: self.b, y: self.e, z: self.h },
@@ -75,7 +111,35 @@ impl Mat3 {
         ]
     }
 
-    pub fn determinant(&self) -> f32 {
+    /// Returns the inverse of the Mat3, or `None` if the Mat3 is singular.
+    ///
+    /// The cofactors of the first row are shared between the determinant and
+    /// the first column of the adjugate.
+    pub fn inverse(&self) -> Option<Mat3<T>> {
+        let c0 = (self.e * self.i) - (self.f * self.h);
+        let c1 = (self.f * self.g) - (self.d * self.i);
+        let c2 = (self.d * self.h) - (self.e * self.g);
+
+        let determinant = (self.a * c0) + (self.b * c1) + (self.c * c2);
+        if determinant.is_zero() {
+            return None;
+        }
+        let inv = determinant.recip();
+
+        Some(Mat3 {
+            a: c0 * inv,
+            b: ((self.c * self.h) - (self.b * self.i)) * inv,
+            c: ((self.b * self.f) - (self.c * self.e)) * inv,
+            d: c1 * inv,
+            e: ((self.a * self.i) - (self.c * self.g)) * inv,
+            f: ((self.c * self.d) - (self.a * self.f)) * inv,
+            g: c2 * inv,
+            h: ((self.b * self.g) - (self.a * self.h)) * inv,
+            i: ((self.a * self.e) - (self.b * self.d)) * inv,
+        })
+    }
+
+    pub fn determinant(&self) -> T {
         // We're applying the same optimization here as in the Mat4 determinant
         (self.a * (
             (self.e * self.i) - (self.f * self.h)
@@ -90,7 +154,7 @@ impl Mat3 {
         ))
     }
 
-    pub fn transpose(&self) -> Mat3 {
+    pub fn transpose(&self) -> Mat3<T> {
         Mat3{
             a: self.a, b: self.d, c: self.g,
             d: self.b, e: self.e, f: self.h,
@@ -99,8 +163,46 @@ impl Mat3 {
     }
 }
 
-impl ops::Mul<Mat3> for Mat3 {
-    type Output = Mat3;
+impl<T: Scalar> ops::Add<Mat3<T>> for Mat3<T> {
+    type Output = Mat3<T>;
+
+    /// Adds two Mat3s together element-wise, returning a new Mat3.
+    fn add(self, other_mat3: Mat3<T>) -> Mat3<T> {
+        Mat3 {
+            a: self.a + other_mat3.a,
+            b: self.b + other_mat3.b,
+            c: self.c + other_mat3.c,
+            d: self.d + other_mat3.d,
+            e: self.e + other_mat3.e,
+            f: self.f + other_mat3.f,
+            g: self.g + other_mat3.g,
+            h: self.h + other_mat3.h,
+            i: self.i + other_mat3.i,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Sub<Mat3<T>> for Mat3<T> {
+    type Output = Mat3<T>;
+
+    /// Subtracts one Mat3 from another element-wise, returning a new Mat3.
+    fn sub(self, other_mat3: Mat3<T>) -> Mat3<T> {
+        Mat3 {
+            a: self.a - other_mat3.a,
+            b: self.b - other_mat3.b,
+            c: self.c - other_mat3.c,
+            d: self.d - other_mat3.d,
+            e: self.e - other_mat3.e,
+            f: self.f - other_mat3.f,
+            g: self.g - other_mat3.g,
+            h: self.h - other_mat3.h,
+            i: self.i - other_mat3.i,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Mul<Mat3<T>> for Mat3<T> {
+    type Output = Mat3<T>;
 
     /// Multiplies two Mat3s together, returning a new Mat3.
     ///
@@ -115,7 +217,7 @@ impl ops::Mul<Mat3> for Mat3 {
     /// # let some_other_mat3 = Mat3::new();
     /// let some_mat3 = mat3 * some_other_mat3;
     /// ```
-    fn mul(self, other_mat3: Mat3) -> Mat3 {
+    fn mul(self, other_mat3: Mat3<T>) -> Mat3<T> {
         Mat3 {
             a: (self.a * other_mat3.a) + (self.b * other_mat3.d) + (self.c * other_mat3.g),
             b: (self.a * other_mat3.b) + (self.b * other_mat3.e) + (self.c * other_mat3.h),
@@ -130,10 +232,10 @@ impl ops::Mul<Mat3> for Mat3 {
     }
 }
 
-impl ops::Mul<Vec3> for Mat3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
 
-    fn mul(self, vec3: Vec3) -> Vec3 {
+    fn mul(self, vec3: Vec3<T>) -> Vec3<T> {
         Vec3 {
             x: (self.a * vec3.x) + (self.b * vec3.y) + (self.c * vec3.z),
             y: (self.d * vec3.x) + (self.e * vec3.y) + (self.f * vec3.z),
@@ -142,20 +244,21 @@ impl ops::Mul<Vec3> for Mat3 {
     }
 }
 
-impl ops::Mul<Mat3> for f32 {
-    type Output = Mat3;
+impl<T: Scalar> ops::Mul<Mat3<T>> for f32 {
+    type Output = Mat3<T>;
 
-    fn mul(self, mat3: Mat3) -> Mat3 {
+    fn mul(self, mat3: Mat3<T>) -> Mat3<T> {
+        let scalar = T::from_f32(self);
         Mat3 {
-            a: self * mat3.a,
-            b: self * mat3.b,
-            c: self * mat3.c,
-            d: self * mat3.d,
-            e: self * mat3.e,
-            f: self * mat3.f,
-            g: self * mat3.g,
-            h: self * mat3.h,
-            i: self * mat3.i,
+            a: scalar * mat3.a,
+            b: scalar * mat3.b,
+            c: scalar * mat3.c,
+            d: scalar * mat3.d,
+            e: scalar * mat3.e,
+            f: scalar * mat3.f,
+            g: scalar * mat3.g,
+            h: scalar * mat3.h,
+            i: scalar * mat3.i,
         }
     }
 }
@@ -252,6 +355,17 @@ mod tests {
         assert_eq!(array, other_array);
     }
 
+    #[test]
+    fn rotate_vec3_about_axes() {
+        let angle = std::f32::consts::FRAC_PI_2;
+        let x = Vec3 { x: 1.0, y: 0.0, z: 0.0 };
+        let y = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+        let z = Vec3 { x: 0.0, y: 0.0, z: 1.0 };
+        assert!((Mat3::new_from_rotation_x(angle) * y - z).length() < 1e-6);
+        assert!((Mat3::new_from_rotation_y(angle) * z - x).length() < 1e-6);
+        assert!((Mat3::new_from_rotation_z(angle) * x - y).length() < 1e-6);
+    }
+
     #[test]
     fn get_determinant_of_mat3() {
         let array = [2.0, 3.0, 5.0, 7.0, 1.0, 2.0, 5.0, 1.0, 0.0];
@@ -266,6 +380,38 @@ mod tests {
         assert_eq!(mat3.determinant(), 0.0);
     }
 
+    #[test]
+    fn get_inverse_of_mat3() {
+        let array = [2.0, 3.0, 5.0, 7.0, 1.0, 2.0, 5.0, 1.0, 0.0];
+        let mat3 = Mat3::new_from_array(&array);
+        let inverse = mat3.inverse().unwrap();
+        for (x, y) in (mat3 * inverse).to_array().iter().zip(Mat3::identity().to_array().iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+        let singular = [2.0, 3.0, 4.0, 4.0, 6.0, 8.0, 1.0, 2.0, 5.0];
+        assert_eq!(Mat3::new_from_array(&singular).inverse(), None);
+    }
+
+    #[test]
+    fn add_2_mat3s_together() {
+        let mat3 = Mat3::new_from_array(&[-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0]);
+        let other_mat3 = Mat3::new_from_array(&[1.0, 2.5, 2.0, 9.5, 8.0, 0.0, 1.0, 1.0, 6.5]);
+        assert_eq!(
+            mat3 + other_mat3,
+            Mat3::new_from_array(&[-3.0, -0.5, 0.0, 8.5, 8.0, 1.0, 3.0, 4.0, 10.5])
+        );
+    }
+
+    #[test]
+    fn subtract_2_mat3s() {
+        let mat3 = Mat3::new_from_array(&[-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0]);
+        let other_mat3 = Mat3::new_from_array(&[1.0, 2.5, 2.0, 9.5, 8.0, 0.0, 1.0, 1.0, 6.5]);
+        assert_eq!(
+            mat3 - other_mat3,
+            Mat3::new_from_array(&[-5.0, -5.5, -4.0, -10.5, -8.0, 1.0, 1.0, 2.0, -2.5])
+        );
+    }
+
     #[test]
     fn multiply_by_identity() {
         let array = [1.0, 2.5, 2.0, 9.5, 8.0, 0.0, 1.0, 1.0, 6.5];
@@ -2,7 +2,7 @@
 
 use std::ops;
 
-use super::Vec4;
+use super::{Mat3, Scalar, Vec3, Vec4};
 
 /// A 4x4 Matrix with elements arraged in row-major order.
 ///
@@ -15,23 +15,24 @@ use super::Vec4;
 ///     [ m  n  o  p ]
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Mat4 {
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-    pub d: f32,
-    pub e: f32,
-    pub f: f32,
-    pub g: f32,
-    pub h: f32,
-    pub i: f32,
-    pub j: f32,
-    pub k: f32,
-    pub l: f32,
-    pub m: f32,
-    pub n: f32,
-    pub o: f32,
-    pub p: f32,
+#[repr(C)]
+pub struct Mat4<T = f32> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub e: T,
+    pub f: T,
+    pub g: T,
+    pub h: T,
+    pub i: T,
+    pub j: T,
+    pub k: T,
+    pub l: T,
+    pub m: T,
+    pub n: T,
+    pub o: T,
+    pub p: T,
 }
 
 impl Mat4 {
@@ -52,8 +53,10 @@ impl Mat4 {
             m: 0.0, n: 0.0, o: 0.0, p: 1.0
         }
     }
+}
 
-    pub fn new_from_array(input: &[f32; 16]) -> Mat4 {
+impl<T: Scalar> Mat4<T> {
+    pub fn new_from_array(input: &[T; 16]) -> Mat4<T> {
         Mat4 {
             a: input[0],  b: input[1],  c: input[2],  d: input[3],
             e: input[4],  f: input[5],  g: input[6],  h: input[7],
@@ -62,7 +65,7 @@ impl Mat4 {
         }
     }
 
-    pub fn new_from_col_array(input: &[f32; 16]) -> Mat4 {
+    pub fn new_from_col_array(input: &[T; 16]) -> Mat4<T> {
         Mat4 {
             a: input[0], b: input[4], c: input[8],  d: input[12],
             e: input[1], f: input[5], g: input[9],  h: input[13],
@@ -71,7 +74,34 @@ impl Mat4 {
         }
     }
 
-    pub fn to_array(&self) -> [f32; 16] {
+    /// Returns a Mat4 with the given Mat3 in its upper-left corner and the
+    /// remaining elements taken from the identity matrix.
+    pub fn new_from_mat3(mat3: &Mat3<T>) -> Mat4<T> {
+        let (zero, one) = (T::zero(), T::one());
+        Mat4 {
+            a: mat3.a, b: mat3.b, c: mat3.c, d: zero,
+            e: mat3.d, f: mat3.e, g: mat3.f, h: zero,
+            i: mat3.g, j: mat3.h, k: mat3.i, l: zero,
+            m: zero,   n: zero,   o: zero,   p: one
+        }
+    }
+
+    /// Returns a Mat4 that scales, then rotates, then translates, which is
+    /// the usual order for placing an object in a scene.
+    ///
+    /// This is the inverse of `Mat4::decompose` for matrices without shear or
+    /// perspective.
+    pub fn new_from_trs(translation: &Vec3<T>, rotation: &Mat3<T>, scale: &Vec3<T>) -> Mat4<T> {
+        let (zero, one) = (T::zero(), T::one());
+        Mat4 {
+            a: rotation.a * scale.x, b: rotation.b * scale.y, c: rotation.c * scale.z, d: translation.x,
+            e: rotation.d * scale.x, f: rotation.e * scale.y, g: rotation.f * scale.z, h: translation.y,
+            i: rotation.g * scale.x, j: rotation.h * scale.y, k: rotation.i * scale.z, l: translation.z,
+            m: zero,                 n: zero,                 o: zero,                 p: one
+        }
+    }
+
+    pub fn to_array(&self) -> [T; 16] {
         [
             self.a, self.b, self.c, self.d,
             self.e, self.f, self.g, self.h,
@@ -80,7 +110,7 @@ impl Mat4 {
         ]
     }
 
-    pub fn to_col_array(&self) -> [f32; 16] {
+    pub fn to_col_array(&self) -> [T; 16] {
         [
             self.a, self.e, self.i, self.m,
             self.b, self.f, self.j, self.n,
@@ -89,7 +119,16 @@ impl Mat4 {
         ]
     }
 
-    pub fn to_vec4_array(&self) -> [Vec4; 4] {
+    /// Returns the upper-left 3x3 corner of the Mat4.
+    pub fn to_mat3(&self) -> Mat3<T> {
+        Mat3 {
+            a: self.a, b: self.b, c: self.c,
+            d: self.e, e: self.f, f: self.g,
+            g: self.i, h: self.j, i: self.k
+        }
+    }
+
+    pub fn to_vec4_array(&self) -> [Vec4<T>; 4] {
         [
             Vec4 { x: self.a, y: self.e, z: self.i, w: self.m },
             Vec4 { x: self.b, y: self.f, z: self.j, w: self.n },
@@ -98,7 +137,194 @@ impl Mat4 {
         ]
     }
 
-    pub fn determinant(&self) -> f32 {
+    /// Returns the inverse of the Mat4, or `None` if the Mat4 is singular.
+    ///
+    /// The determinant is expanded along pairs of rows: the 2x2 minors of the
+    /// top two rows (`s0`-`s5`) and bottom two rows (`c0`-`c5`) are computed
+    /// once and then shared between the determinant and every cofactor.
+    pub fn inverse(&self) -> Option<Mat4<T>> {
+        let s0 = (self.a * self.f) - (self.b * self.e);
+        let s1 = (self.a * self.g) - (self.c * self.e);
+        let s2 = (self.a * self.h) - (self.d * self.e);
+        let s3 = (self.b * self.g) - (self.c * self.f);
+        let s4 = (self.b * self.h) - (self.d * self.f);
+        let s5 = (self.c * self.h) - (self.d * self.g);
+
+        let c0 = (self.i * self.n) - (self.j * self.m);
+        let c1 = (self.i * self.o) - (self.k * self.m);
+        let c2 = (self.i * self.p) - (self.l * self.m);
+        let c3 = (self.j * self.o) - (self.k * self.n);
+        let c4 = (self.j * self.p) - (self.l * self.n);
+        let c5 = (self.k * self.p) - (self.l * self.o);
+
+        let determinant = (s0 * c5) - (s1 * c4) + (s2 * c3) + (s3 * c2) - (s4 * c1) + (s5 * c0);
+        if determinant.is_zero() {
+            return None;
+        }
+        let inv = determinant.recip();
+
+        Some(Mat4 {
+            a: ( (self.f * c5) - (self.g * c4) + (self.h * c3)) * inv,
+            b: (-(self.b * c5) + (self.c * c4) - (self.d * c3)) * inv,
+            c: ( (self.n * s5) - (self.o * s4) + (self.p * s3)) * inv,
+            d: (-(self.j * s5) + (self.k * s4) - (self.l * s3)) * inv,
+            e: (-(self.e * c5) + (self.g * c2) - (self.h * c1)) * inv,
+            f: ( (self.a * c5) - (self.c * c2) + (self.d * c1)) * inv,
+            g: (-(self.m * s5) + (self.o * s2) - (self.p * s1)) * inv,
+            h: ( (self.i * s5) - (self.k * s2) + (self.l * s1)) * inv,
+            i: ( (self.e * c4) - (self.f * c2) + (self.h * c0)) * inv,
+            j: (-(self.a * c4) + (self.b * c2) - (self.d * c0)) * inv,
+            k: ( (self.m * s4) - (self.n * s2) + (self.p * s0)) * inv,
+            l: (-(self.i * s4) + (self.j * s2) - (self.l * s0)) * inv,
+            m: (-(self.e * c3) + (self.f * c1) - (self.g * c0)) * inv,
+            n: ( (self.a * c3) - (self.b * c1) + (self.c * c0)) * inv,
+            o: (-(self.m * s3) + (self.n * s1) - (self.o * s0)) * inv,
+            p: ( (self.i * s3) - (self.j * s1) + (self.k * s0)) * inv,
+        })
+    }
+
+    /// Transforms a point by the Mat4, treating it as having a w of 1.
+    ///
+    /// The bottom row of the Mat4 is ignored, so this is only correct for
+    /// affine transforms. Use `project` for projections.
+    pub fn transform_point(&self, point: &Vec3<T>) -> Vec3<T> {
+        Vec3 {
+            x: (self.a * point.x) + (self.b * point.y) + (self.c * point.z) + self.d,
+            y: (self.e * point.x) + (self.f * point.y) + (self.g * point.z) + self.h,
+            z: (self.i * point.x) + (self.j * point.y) + (self.k * point.z) + self.l,
+        }
+    }
+
+    /// Transforms a direction by the Mat4, treating it as having a w of 0 so
+    /// that translation is ignored.
+    pub fn transform_vector(&self, vector: &Vec3<T>) -> Vec3<T> {
+        Vec3 {
+            x: (self.a * vector.x) + (self.b * vector.y) + (self.c * vector.z),
+            y: (self.e * vector.x) + (self.f * vector.y) + (self.g * vector.z),
+            z: (self.i * vector.x) + (self.j * vector.y) + (self.k * vector.z),
+        }
+    }
+
+    /// Transforms every point in `input` with `transform_point`, writing the
+    /// results to the same index of `output`.
+    ///
+    /// This skips widening each point to a Vec4 and computing the unused w
+    /// row, and keeps the matrix in registers for the whole slice. The
+    /// `batch_transform` benchmark compares it against multiplying one Vec4
+    /// at a time.
+    ///
+    /// Panics if the slices have different lengths.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Mat4, Vec3};
+    /// let vertices = vec![Vec3::new(); 1024];
+    /// let mut transformed = vec![Vec3::new(); 1024];
+    /// Mat4::identity().transform_points(&vertices, &mut transformed);
+    /// ```
+    pub fn transform_points(&self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "input and output must be the same length");
+        self.transform_batch(input, output, &Vec3 { x: self.d, y: self.h, z: self.l });
+    }
+
+    /// Transforms every direction in `input` with `transform_vector`, writing
+    /// the results to the same index of `output`.
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn transform_vectors(&self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "input and output must be the same length");
+        self.transform_batch(input, output, &Vec3 { x: T::zero(), y: T::zero(), z: T::zero() });
+    }
+
+    /// Transforms every point in the slice with `transform_point`, in place.
+    pub fn transform_points_in_place(&self, points: &mut [Vec3<T>]) {
+        self.transform_batch_in_place(points, &Vec3 { x: self.d, y: self.h, z: self.l });
+    }
+
+    /// Transforms every direction in the slice with `transform_vector`, in
+    /// place.
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vec3<T>]) {
+        self.transform_batch_in_place(vectors, &Vec3 { x: T::zero(), y: T::zero(), z: T::zero() });
+    }
+
+    /// Transforms each of `input` into `output` by the top left 3x3 of the
+    /// Mat4 followed by `translation`.
+    ///
+    /// The elements are copied into locals first so the compiler knows they
+    /// can't change as `output` is written, and is free to vectorize the
+    /// loop.
+    fn transform_batch(&self, input: &[Vec3<T>], output: &mut [Vec3<T>], translation: &Vec3<T>) {
+        let (a, b, c, d) = (self.a, self.b, self.c, translation.x);
+        let (e, f, g, h) = (self.e, self.f, self.g, translation.y);
+        let (i, j, k, l) = (self.i, self.j, self.k, translation.z);
+        for (point, result) in input.iter().zip(output.iter_mut()) {
+            let (x, y, z) = (point.x, point.y, point.z);
+            *result = Vec3 {
+                x: a * x + b * y + c * z + d,
+                y: e * x + f * y + g * z + h,
+                z: i * x + j * y + k * z + l,
+            };
+        }
+    }
+
+    fn transform_batch_in_place(&self, points: &mut [Vec3<T>], translation: &Vec3<T>) {
+        let (a, b, c, d) = (self.a, self.b, self.c, translation.x);
+        let (e, f, g, h) = (self.e, self.f, self.g, translation.y);
+        let (i, j, k, l) = (self.i, self.j, self.k, translation.z);
+        for point in points.iter_mut() {
+            let (x, y, z) = (point.x, point.y, point.z);
+            *point = Vec3 {
+                x: a * x + b * y + c * z + d,
+                y: e * x + f * y + g * z + h,
+                z: i * x + j * y + k * z + l,
+            };
+        }
+    }
+
+    /// Maps a point from object space to window coordinates, the equivalent
+    /// of `gluProject`.
+    ///
+    /// `self` is the combined projection * model-view Mat4 and `viewport` is
+    /// `(x, y, width, height)`. The returned z is the window depth in the
+    /// range [0, 1]. Returns `None` if the point projects to infinity.
+    pub fn project(&self, point: &Vec3<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+        let (one, half) = (T::one(), T::from_f32(0.5));
+        let clip = *self * point.extend(one);
+        if clip.w.is_zero() {
+            return None;
+        }
+        let ndc = Vec3 { x: clip.x / clip.w, y: clip.y / clip.w, z: clip.z / clip.w };
+
+        Some(Vec3 {
+            x: viewport.x + (viewport.z * (ndc.x + one) * half),
+            y: viewport.y + (viewport.w * (ndc.y + one) * half),
+            z: (ndc.z + one) * half,
+        })
+    }
+
+    /// Maps window coordinates back to object space, the equivalent of
+    /// `gluUnProject`.
+    ///
+    /// `self` is the same combined projection * model-view Mat4 that was
+    /// passed to `project`. Returns `None` if the Mat4 can't be inverted.
+    pub fn unproject(&self, window_coord: &Vec3<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+        let inverse = self.inverse()?;
+        let (one, two) = (T::one(), T::from_f32(2.0));
+        let ndc = Vec4 {
+            x: (two * (window_coord.x - viewport.x) / viewport.z) - one,
+            y: (two * (window_coord.y - viewport.y) / viewport.w) - one,
+            z: (two * window_coord.z) - one,
+            w: one,
+        };
+        let object = inverse * ndc;
+        if object.w.is_zero() {
+            return None;
+        }
+
+        Some(Vec3 { x: object.x / object.w, y: object.y / object.w, z: object.z / object.w })
+    }
+
+    pub fn determinant(&self) -> T {
     // This optimization, as shown in the `determinant_optimizations` 
     // benchmark, speeds up the calculation of the determinant by factoring out
     // a, b, c, and d, and then factoring out m, n, o, and p, reducing the total
@@ -133,7 +359,7 @@ impl Mat4 {
         ))
     }
 
-    pub fn transpose(&self) -> Mat4 {
+    pub fn transpose(&self) -> Mat4<T> {
         Mat4 {
             a: self.a, b: self.e, c: self.i, d: self.m,
             e: self.b, f: self.f, g: self.j, h: self.n,
@@ -143,8 +369,60 @@ impl Mat4 {
     }
 }
 
-impl ops::Mul<Mat4> for Mat4 {
-    type Output = Mat4;
+impl<T: Scalar> ops::Add<Mat4<T>> for Mat4<T> {
+    type Output = Mat4<T>;
+
+    /// Adds two Mat4s together element-wise, returning a new Mat4.
+    fn add(self, other_mat4: Mat4<T>) -> Mat4<T> {
+        Mat4 {
+            a: self.a + other_mat4.a,
+            b: self.b + other_mat4.b,
+            c: self.c + other_mat4.c,
+            d: self.d + other_mat4.d,
+            e: self.e + other_mat4.e,
+            f: self.f + other_mat4.f,
+            g: self.g + other_mat4.g,
+            h: self.h + other_mat4.h,
+            i: self.i + other_mat4.i,
+            j: self.j + other_mat4.j,
+            k: self.k + other_mat4.k,
+            l: self.l + other_mat4.l,
+            m: self.m + other_mat4.m,
+            n: self.n + other_mat4.n,
+            o: self.o + other_mat4.o,
+            p: self.p + other_mat4.p,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Sub<Mat4<T>> for Mat4<T> {
+    type Output = Mat4<T>;
+
+    /// Subtracts one Mat4 from another element-wise, returning a new Mat4.
+    fn sub(self, other_mat4: Mat4<T>) -> Mat4<T> {
+        Mat4 {
+            a: self.a - other_mat4.a,
+            b: self.b - other_mat4.b,
+            c: self.c - other_mat4.c,
+            d: self.d - other_mat4.d,
+            e: self.e - other_mat4.e,
+            f: self.f - other_mat4.f,
+            g: self.g - other_mat4.g,
+            h: self.h - other_mat4.h,
+            i: self.i - other_mat4.i,
+            j: self.j - other_mat4.j,
+            k: self.k - other_mat4.k,
+            l: self.l - other_mat4.l,
+            m: self.m - other_mat4.m,
+            n: self.n - other_mat4.n,
+            o: self.o - other_mat4.o,
+            p: self.p - other_mat4.p,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Mul<Mat4<T>> for Mat4<T> {
+    type Output = Mat4<T>;
 
     /// Multiplies two Mat4s together, returning a new Mat4.
     ///
@@ -159,7 +437,7 @@ impl ops::Mul<Mat4> for Mat4 {
     /// # let some_other_mat4 = Mat4::new();
     /// let some_mat4 = mat4 * some_other_mat4;
     /// ```
-    fn mul(self, mat4: Mat4) -> Mat4 {
+    fn mul(self, mat4: Mat4<T>) -> Mat4<T> {
         Mat4 {
             a: (self.a * mat4.a) + (self.b * mat4.e) + (self.c * mat4.i) + (self.d * mat4.m),
             b: (self.a * mat4.b) + (self.b * mat4.f) + (self.c * mat4.j) + (self.d * mat4.n),
@@ -181,10 +459,10 @@ impl ops::Mul<Mat4> for Mat4 {
     }
 }
 
-impl ops::Mul<Vec4> for Mat4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Mul<Vec4<T>> for Mat4<T> {
+    type Output = Vec4<T>;
 
-    fn mul(self, vec4: Vec4) -> Vec4 {
+    fn mul(self, vec4: Vec4<T>) -> Vec4<T> {
         Vec4 {
             x: (self.a * vec4.x) + (self.b * vec4.y) + (self.c * vec4.z) + (self.d * vec4.w),
             y: (self.e * vec4.x) + (self.f * vec4.y) + (self.g * vec4.z) + (self.h * vec4.w),
@@ -194,34 +472,36 @@ impl ops::Mul<Vec4> for Mat4 {
     }
 }
 
-impl ops::Mul<Mat4> for f32 {
-    type Output = Mat4;
+impl<T: Scalar> ops::Mul<Mat4<T>> for f32 {
+    type Output = Mat4<T>;
 
-    fn mul(self, mat4: Mat4) -> Mat4 {
+    fn mul(self, mat4: Mat4<T>) -> Mat4<T> {
+        let scalar = T::from_f32(self);
         Mat4 {
-            a: self * mat4.a,
-            b: self * mat4.b,
-            c: self * mat4.c,
-            d: self * mat4.d,
-            e: self * mat4.e,
-            f: self * mat4.f,
-            g: self * mat4.g,
-            h: self * mat4.h,
-            i: self * mat4.i,
-            j: self * mat4.j,
-            k: self * mat4.k,
-            l: self * mat4.l,
-            m: self * mat4.m,
-            n: self * mat4.n,
-            o: self * mat4.o,
-            p: self * mat4.p,
+            a: scalar * mat4.a,
+            b: scalar * mat4.b,
+            c: scalar * mat4.c,
+            d: scalar * mat4.d,
+            e: scalar * mat4.e,
+            f: scalar * mat4.f,
+            g: scalar * mat4.g,
+            h: scalar * mat4.h,
+            i: scalar * mat4.i,
+            j: scalar * mat4.j,
+            k: scalar * mat4.k,
+            l: scalar * mat4.l,
+            m: scalar * mat4.m,
+            n: scalar * mat4.n,
+            o: scalar * mat4.o,
+            p: scalar * mat4.p,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Mat4, Vec4};
+    use super::{Mat3, Mat4, Vec3, Vec4};
+    use crate::test_values::assert_mat4_near;
 
     #[test]
     fn create_new_mat4() {
@@ -310,6 +590,31 @@ mod tests {
         assert_eq!(mat4.to_vec4_array(), array);
     }
 
+    #[test]
+    fn mat4_to_and_from_mat3() {
+        let mat3 = Mat3::new_from_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let mat4 = Mat4::new_from_mat3(&mat3);
+        assert_eq!(
+            mat4,
+            Mat4 {
+                a: 1.0, b: 2.0, c: 3.0, d: 0.0,
+                e: 4.0, f: 5.0, g: 6.0, h: 0.0,
+                i: 7.0, j: 8.0, k: 9.0, l: 0.0,
+                m: 0.0, n: 0.0, o: 0.0, p: 1.0
+            });
+        assert_eq!(mat4.to_mat3(), mat3);
+    }
+
+    #[test]
+    fn create_new_mat4_from_trs() {
+        let translation = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let rotation = Mat3::new_from_array(&[0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        let scale = Vec3 { x: 2.0, y: 3.0, z: 4.0 };
+        let mat4 = Mat4::new_from_trs(&translation, &rotation, &scale);
+        let point = Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
+        assert_eq!(mat4 * point, Vec4 { x: -2.0, y: 4.0, z: 7.0, w: 1.0 });
+    }
+
     #[test]
     fn get_determinant_of_mat4() {
         let array = [ 2.0, 3.0, 5.0, -1.0, 7.0, 1.0, 2.0, 0.0, 5.0, 1.0, 0.0, 2.5, 8.0, 1.0, 1.0, 3.25 ];
@@ -324,6 +629,20 @@ mod tests {
         assert_eq!(mat4.determinant(), 0.0);
     }
 
+    #[test]
+    fn add_2_mat4s_together() {
+        let mat4 = Mat4::new_from_array(&[ -8.0, -7.0, -6.0, -5.0, -4.0, -3.0, -2.0, -1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ]);
+        assert_eq!(mat4 + mat4, 2.0 * mat4);
+        assert_eq!(mat4 + Mat4::new(), mat4);
+    }
+
+    #[test]
+    fn subtract_2_mat4s() {
+        let mat4 = Mat4::new_from_array(&[ -8.0, -7.0, -6.0, -5.0, -4.0, -3.0, -2.0, -1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ]);
+        assert_eq!(mat4 - mat4, Mat4::new());
+        assert_eq!(mat4 - Mat4::identity(), mat4 + (-1.0 * Mat4::identity()));
+    }
+
     #[test]
     fn multiply_by_identity() {
         let array = [ 1.5, 8.0, 2.0, 2.5, 10.0, 4.0, 4.0, 10.0, 3.5, 6.0, 7.0, 0.0, 7.0, 4.0, 2.0, 1.0 ];
@@ -373,4 +692,92 @@ mod tests {
             Vec4::new_from_values(&64.5, &150.0, &58.0, &81.0)
         );
     }
+
+    #[test]
+    fn get_inverse_of_mat4() {
+        let array = [ 2.0, 3.0, 5.0, -1.0, 7.0, 1.0, 2.0, 0.0, 5.0, 1.0, 0.0, 2.5, 8.0, 1.0, 1.0, 3.25 ];
+        let mat4 = Mat4::new_from_array(&array);
+        let inverse = mat4.inverse().unwrap();
+        assert_mat4_near(&(mat4 * inverse), &Mat4::identity());
+        assert_mat4_near(&(inverse * mat4), &Mat4::identity());
+    }
+
+    #[test]
+    fn get_inverse_of_singular_mat4() {
+        let array = [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 1.0, 2.25, 4.0, 7.0, 12.0, 2.0, 4.0, -3.0 ];
+        assert_eq!(Mat4::new_from_array(&array).inverse(), None);
+    }
+
+    #[test]
+    fn transform_point_and_vector() {
+        let mat4 = Mat4::new_from_trs(
+            &Vec3 { x: 1.0, y: 2.0, z: 3.0 },
+            &Mat3::identity(),
+            &Vec3 { x: 2.0, y: 2.0, z: 2.0 }
+        );
+        let vec3 = Vec3 { x: 1.0, y: -1.0, z: 0.5 };
+        assert_eq!(mat4.transform_point(&vec3), Vec3 { x: 3.0, y: 0.0, z: 4.0 });
+        assert_eq!(mat4.transform_vector(&vec3), Vec3 { x: 2.0, y: -2.0, z: 1.0 });
+    }
+
+    #[test]
+    fn transform_slices() {
+        let array = [ 1.5, 8.0, 2.0, 2.5, 10.0, 4.0, 4.0, 10.0, 3.5, 6.0, 7.0, 0.0, 0.0, 0.0, 0.0, 1.0 ];
+        let mat4 = Mat4::new_from_array(&array);
+        let input: Vec<Vec3> = (0..37)
+            .map(|index| Vec3 { x: index as f32, y: 1.0 - index as f32, z: 0.5 * index as f32 })
+            .collect();
+
+        let mut points = vec![Vec3::new(); input.len()];
+        mat4.transform_points(&input, &mut points);
+        let mut vectors = vec![Vec3::new(); input.len()];
+        mat4.transform_vectors(&input, &mut vectors);
+        for (index, vec3) in input.iter().enumerate() {
+            assert_eq!(points[index], (mat4 * vec3.extend(1.0)).truncate());
+            assert_eq!(vectors[index], (mat4 * vec3.extend(0.0)).truncate());
+        }
+
+        let mut in_place = input.clone();
+        mat4.transform_points_in_place(&mut in_place);
+        assert_eq!(in_place, points);
+        let mut in_place = input;
+        mat4.transform_vectors_in_place(&mut in_place);
+        assert_eq!(in_place, vectors);
+    }
+
+    #[test]
+    #[should_panic]
+    fn transform_slices_of_different_lengths() {
+        let mut output = [Vec3::new(); 2];
+        Mat4::identity().transform_points(&[Vec3::new(); 3], &mut output);
+    }
+
+    #[test]
+    fn project_and_unproject_point() {
+        // A 90 degree perspective projection with a near plane at 1 and a far
+        // plane at 10, combined with a translation of -2 along x.
+        let projection = Mat4 {
+            a: 1.0, b: 0.0, c:  0.0,        d:  0.0,
+            e: 0.0, f: 1.0, g:  0.0,        h:  0.0,
+            i: 0.0, j: 0.0, k: -11.0 / 9.0, l: -20.0 / 9.0,
+            m: 0.0, n: 0.0, o: -1.0,        p:  0.0
+        };
+        let view = Mat4 {
+            a: 1.0, b: 0.0, c: 0.0, d: -2.0,
+            e: 0.0, f: 1.0, g: 0.0, h:  0.0,
+            i: 0.0, j: 0.0, k: 1.0, l:  0.0,
+            m: 0.0, n: 0.0, o: 0.0, p:  1.0
+        };
+        let viewport = Vec4 { x: 0.0, y: 0.0, z: 800.0, w: 600.0 };
+        let mvp = projection * view;
+
+        let window = mvp.project(&Vec3 { x: 2.0, y: 0.0, z: -5.0 }, &viewport).unwrap();
+        assert!((window.x - 400.0).abs() < 1e-3);
+        assert!((window.y - 300.0).abs() < 1e-3);
+
+        let point = Vec3 { x: 3.0, y: -1.5, z: -4.0 };
+        let window = mvp.project(&point, &viewport).unwrap();
+        let unprojected = mvp.unproject(&window, &viewport).unwrap();
+        assert!((unprojected - point).length() < 1e-3);
+    }
 }
@@ -0,0 +1,431 @@
+//! Matrix exponential, logarithm and square root for Mat2, Mat3 and Mat4.
+//!
+//! The square matrices are converted to `f64` arrays so that the same
+//! algorithms can be shared between every size, and so that the many
+//! multiplications and inversions involved don't accumulate `f32` rounding
+//! errors.
+
+use super::{Mat2, Mat3, Mat4, Vec3};
+
+type Square<const N: usize> = [[f64; N]; N];
+
+// Degree of the Padé approximant used by `exp`. Degree 6 with the norm scaled
+// below 0.5 is accurate to well beyond f32 precision.
+const PADE_DEGREE: usize = 6;
+
+// Iteration limits for the Denman–Beavers square root and the atanh series
+// used by the logarithm.
+const MAX_SQRT_ITERATIONS: usize = 64;
+const MAX_LOG_SQUARE_ROOTS: usize = 32;
+const LOG_SERIES_TERMS: usize = 32;
+const CONVERGENCE_EPSILON: f64 = 1e-12;
+
+fn identity<const N: usize>() -> Square<N> {
+    let mut result = [[0.0; N]; N];
+    for (index, row) in result.iter_mut().enumerate() {
+        row[index] = 1.0;
+    }
+    result
+}
+
+fn add<const N: usize>(a: &Square<N>, b: &Square<N>) -> Square<N> {
+    let mut result = *a;
+    for (row, other_row) in result.iter_mut().zip(b.iter()) {
+        for (element, other) in row.iter_mut().zip(other_row.iter()) {
+            *element += other;
+        }
+    }
+    result
+}
+
+fn scale<const N: usize>(scalar: f64, a: &Square<N>) -> Square<N> {
+    let mut result = *a;
+    for row in result.iter_mut() {
+        for element in row.iter_mut() {
+            *element *= scalar;
+        }
+    }
+    result
+}
+
+fn multiply<const N: usize>(a: &Square<N>, b: &Square<N>) -> Square<N> {
+    let mut result = [[0.0; N]; N];
+    for (row, a_row) in result.iter_mut().zip(a.iter()) {
+        for (col, element) in row.iter_mut().enumerate() {
+            *element = a_row.iter().zip(b.iter()).map(|(x, b_row)| x * b_row[col]).sum();
+        }
+    }
+    result
+}
+
+/// Returns the infinity norm, the largest absolute row sum.
+fn norm<const N: usize>(a: &Square<N>) -> f64 {
+    a.iter()
+        .map(|row| row.iter().map(|element| element.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// Inverts the matrix using Gauss-Jordan elimination with partial pivoting.
+fn inverse<const N: usize>(a: &Square<N>) -> Option<Square<N>> {
+    let mut left = *a;
+    let mut right = identity::<N>();
+    for col in 0..N {
+        let pivot = (col..N).max_by(|&x, &y| left[x][col].abs().total_cmp(&left[y][col].abs()))?;
+        if left[pivot][col] == 0.0 {
+            return None;
+        }
+        left.swap(col, pivot);
+        right.swap(col, pivot);
+
+        let inv_pivot = 1.0 / left[col][col];
+        for index in 0..N {
+            left[col][index] *= inv_pivot;
+            right[col][index] *= inv_pivot;
+        }
+        for row in 0..N {
+            if row != col {
+                let factor = left[row][col];
+                for index in 0..N {
+                    left[row][index] -= factor * left[col][index];
+                    right[row][index] -= factor * right[col][index];
+                }
+            }
+        }
+    }
+    Some(right)
+}
+
+/// Computes the exponential using a diagonal Padé approximant with scaling
+/// and squaring: `exp(A) = exp(A / 2^s)^(2^s)`.
+///
+/// Returns a matrix of NaN if `a` has an infinite or NaN element, which
+/// would otherwise need endless squarings, or if the Padé denominator
+/// turns out singular.
+fn exp<const N: usize>(a: &Square<N>) -> Square<N> {
+    let norm = norm(a);
+    if !norm.is_finite() {
+        return [[f64::NAN; N]; N];
+    }
+    let squarings = if norm > 0.5 { (norm / 0.5).log2().ceil() as i32 } else { 0 };
+    let scaled = scale(0.5f64.powi(squarings), a);
+
+    let mut numerator = identity::<N>();
+    let mut denominator = identity::<N>();
+    let mut power = identity::<N>();
+    let mut coefficient = 1.0;
+    for k in 1..=PADE_DEGREE {
+        coefficient *= (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+        power = multiply(&power, &scaled);
+        let term = scale(coefficient, &power);
+        numerator = add(&numerator, &term);
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        denominator = add(&denominator, &scale(sign, &term));
+    }
+
+    // The denominator is close to the identity after scaling, so it is
+    // invertible for finite input.
+    let inverse_denominator = match inverse(&denominator) {
+        Some(inverse_denominator) => inverse_denominator,
+        None => return [[f64::NAN; N]; N],
+    };
+    let mut result = multiply(&inverse_denominator, &numerator);
+    for _ in 0..squarings {
+        result = multiply(&result, &result);
+    }
+    result
+}
+
+/// Computes the principal square root using the Denman–Beavers iteration.
+fn sqrt<const N: usize>(a: &Square<N>) -> Option<Square<N>> {
+    let mut y = *a;
+    let mut z = identity::<N>();
+    for _ in 0..MAX_SQRT_ITERATIONS {
+        let next_y = scale(0.5, &add(&y, &inverse(&z)?));
+        let next_z = scale(0.5, &add(&z, &inverse(&y)?));
+        let change = norm(&add(&next_y, &scale(-1.0, &y)));
+        y = next_y;
+        z = next_z;
+        if change <= CONVERGENCE_EPSILON * norm(&y) {
+            return Some(y);
+        }
+    }
+    None
+}
+
+/// Computes the principal logarithm using inverse scaling and squaring:
+/// square roots are taken until the matrix is close to the identity, then
+/// `log(A) = 2 * atanh((A - I)(A + I)⁻¹)` is summed as a series.
+fn log<const N: usize>(a: &Square<N>) -> Option<Square<N>> {
+    let identity = identity::<N>();
+    let negative_identity = scale(-1.0, &identity);
+    let mut root = *a;
+    let mut square_roots = 0;
+    while norm(&add(&root, &negative_identity)) > 0.25 {
+        if square_roots == MAX_LOG_SQUARE_ROOTS {
+            return None;
+        }
+        root = sqrt(&root)?;
+        square_roots += 1;
+    }
+
+    let z = multiply(&add(&root, &negative_identity), &inverse(&add(&root, &identity))?);
+    let z_squared = multiply(&z, &z);
+    let mut power = z;
+    let mut result = z;
+    for term in 1..LOG_SERIES_TERMS {
+        power = multiply(&power, &z_squared);
+        result = add(&result, &scale(1.0 / (2 * term + 1) as f64, &power));
+    }
+    Some(scale(2.0 * 2.0f64.powi(square_roots as i32), &result))
+}
+
+fn from_mat2(mat2: &Mat2) -> Square<2> {
+    [
+        [mat2.a as f64, mat2.b as f64],
+        [mat2.c as f64, mat2.d as f64],
+    ]
+}
+
+fn to_mat2(m: &Square<2>) -> Mat2 {
+    Mat2 {
+        a: m[0][0] as f32, b: m[0][1] as f32,
+        c: m[1][0] as f32, d: m[1][1] as f32
+    }
+}
+
+fn from_mat3(mat3: &Mat3) -> Square<3> {
+    [
+        [mat3.a as f64, mat3.b as f64, mat3.c as f64],
+        [mat3.d as f64, mat3.e as f64, mat3.f as f64],
+        [mat3.g as f64, mat3.h as f64, mat3.i as f64],
+    ]
+}
+
+fn to_mat3(m: &Square<3>) -> Mat3 {
+    Mat3 {
+        a: m[0][0] as f32, b: m[0][1] as f32, c: m[0][2] as f32,
+        d: m[1][0] as f32, e: m[1][1] as f32, f: m[1][2] as f32,
+        g: m[2][0] as f32, h: m[2][1] as f32, i: m[2][2] as f32
+    }
+}
+
+fn from_mat4(mat4: &Mat4) -> Square<4> {
+    [
+        [mat4.a as f64, mat4.b as f64, mat4.c as f64, mat4.d as f64],
+        [mat4.e as f64, mat4.f as f64, mat4.g as f64, mat4.h as f64],
+        [mat4.i as f64, mat4.j as f64, mat4.k as f64, mat4.l as f64],
+        [mat4.m as f64, mat4.n as f64, mat4.o as f64, mat4.p as f64],
+    ]
+}
+
+fn to_mat4(m: &Square<4>) -> Mat4 {
+    Mat4 {
+        a: m[0][0] as f32, b: m[0][1] as f32, c: m[0][2] as f32, d: m[0][3] as f32,
+        e: m[1][0] as f32, f: m[1][1] as f32, g: m[1][2] as f32, h: m[1][3] as f32,
+        i: m[2][0] as f32, j: m[2][1] as f32, k: m[2][2] as f32, l: m[2][3] as f32,
+        m: m[3][0] as f32, n: m[3][1] as f32, o: m[3][2] as f32, p: m[3][3] as f32
+    }
+}
+
+impl Mat2 {
+    /// Returns the matrix exponential `e^A`.
+    ///
+    /// Every element is NaN if any element is infinite or NaN.
+    pub fn exp(&self) -> Mat2 {
+        to_mat2(&exp(&from_mat2(self)))
+    }
+
+    /// Returns the principal matrix logarithm, the inverse of `exp`.
+    ///
+    /// Returns `None` if the Mat2 has an eigenvalue on the closed negative
+    /// real axis, where no real principal logarithm exists.
+    pub fn log(&self) -> Option<Mat2> {
+        log(&from_mat2(self)).map(|m| to_mat2(&m))
+    }
+
+    /// Returns the principal matrix square root, whose square is the
+    /// original Mat2.
+    ///
+    /// Returns `None` if the Mat2 has an eigenvalue on the closed negative
+    /// real axis.
+    pub fn sqrt(&self) -> Option<Mat2> {
+        sqrt(&from_mat2(self)).map(|m| to_mat2(&m))
+    }
+}
+
+impl Mat3 {
+    /// Returns the matrix exponential `e^A`.
+    ///
+    /// Every element is NaN if any element is infinite or NaN.
+    ///
+    /// Skew-symmetric matrices, such as those made by `Vec3::to_skew_matrix`,
+    /// take the closed form Rodrigues' rotation formula and return a rotation
+    /// matrix.
+    pub fn exp(&self) -> Mat3 {
+        if *self == -1.0 * self.transpose() {
+            let axis_angle = self.vee();
+            let angle = axis_angle.length();
+            if angle == 0.0 {
+                return Mat3::identity();
+            }
+            let k = (1.0 / angle) * *self;
+            let (sin, cos) = angle.sin_cos();
+            return Mat3::identity() + (sin * k) + ((1.0 - cos) * (k * k));
+        }
+        to_mat3(&exp(&from_mat3(self)))
+    }
+
+    /// Returns the principal matrix logarithm, the inverse of `exp`.
+    ///
+    /// Returns `None` if the Mat3 has an eigenvalue on the closed negative
+    /// real axis, where no real principal logarithm exists. This includes
+    /// rotations by exactly π.
+    pub fn log(&self) -> Option<Mat3> {
+        log(&from_mat3(self)).map(|m| to_mat3(&m))
+    }
+
+    /// Returns the principal matrix square root, whose square is the
+    /// original Mat3.
+    ///
+    /// Returns `None` if the Mat3 has an eigenvalue on the closed negative
+    /// real axis.
+    pub fn sqrt(&self) -> Option<Mat3> {
+        sqrt(&from_mat3(self)).map(|m| to_mat3(&m))
+    }
+
+    /// Returns the Vec3 `v` for which this skew-symmetric Mat3 is
+    /// `v.to_skew_matrix()`, also known as the vee operator.
+    ///
+    /// Only the elements below the diagonal are read, so the result is
+    /// meaningless for matrices that aren't skew-symmetric.
+    pub fn vee(&self) -> Vec3 {
+        Vec3 { x: self.h, y: -self.g, z: self.d }
+    }
+}
+
+impl Mat4 {
+    /// Returns the matrix exponential `e^A`.
+    ///
+    /// Every element is NaN if any element is infinite or NaN.
+    pub fn exp(&self) -> Mat4 {
+        to_mat4(&exp(&from_mat4(self)))
+    }
+
+    /// Returns the principal matrix logarithm, the inverse of `exp`.
+    ///
+    /// Returns `None` if the Mat4 has an eigenvalue on the closed negative
+    /// real axis, where no real principal logarithm exists.
+    pub fn log(&self) -> Option<Mat4> {
+        log(&from_mat4(self)).map(|m| to_mat4(&m))
+    }
+
+    /// Returns the principal matrix square root, whose square is the
+    /// original Mat4.
+    ///
+    /// Returns `None` if the Mat4 has an eigenvalue on the closed negative
+    /// real axis.
+    pub fn sqrt(&self) -> Option<Mat4> {
+        sqrt(&from_mat4(self)).map(|m| to_mat4(&m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat2, Mat3, Mat4, Vec3};
+    use crate::test_values::{assert_mat2_near, assert_mat3_near, assert_mat4_near, assert_vec3_near};
+
+    #[test]
+    fn exp_of_zero_is_identity() {
+        assert_eq!(Mat2::new().exp(), Mat2::identity());
+        assert_eq!(Mat3::new().exp(), Mat3::identity());
+        assert_eq!(Mat4::new().exp(), Mat4::identity());
+    }
+
+    #[test]
+    fn exp_of_non_finite_is_nan() {
+        let mat2 = Mat2::new_from_values(&f32::INFINITY, &0.0, &0.0, &1.0);
+        assert!(mat2.exp().to_array().iter().all(|element| element.is_nan()));
+        let mut mat3 = Mat3::identity();
+        mat3.b = f32::INFINITY;
+        assert!(mat3.exp().to_array().iter().all(|element| element.is_nan()));
+        let mut mat4 = Mat4::identity();
+        mat4.k = f32::NEG_INFINITY;
+        assert!(mat4.exp().to_array().iter().all(|element| element.is_nan()));
+        mat4.k = f32::NAN;
+        assert!(mat4.exp().to_array().iter().all(|element| element.is_nan()));
+    }
+
+    #[test]
+    fn exp_of_diagonal() {
+        let mat2 = Mat2::new_from_values(&1.0, &0.0, &0.0, &-2.0);
+        assert_mat2_near(&mat2.exp(), &Mat2::new_from_values(&1f32.exp(), &0.0, &0.0, &(-2f32).exp()));
+    }
+
+    #[test]
+    fn exp_of_mat2_generator_is_rotation() {
+        let angle: f32 = 2.5;
+        let generator = Mat2::new_from_values(&0.0, &-angle, &angle, &0.0);
+        let (sin, cos) = angle.sin_cos();
+        assert_mat2_near(&generator.exp(), &Mat2::new_from_values(&cos, &-sin, &sin, &cos));
+    }
+
+    #[test]
+    fn exp_of_skew_matrix_is_rotation() {
+        let axis_angle = Vec3 { x: 0.0, y: 0.0, z: 1.2 };
+        let rotation = axis_angle.to_skew_matrix().exp();
+        assert_mat3_near(&rotation, &Mat3::new_from_rotation_z(1.2));
+
+        // The general algorithm agrees with Rodrigues' formula.
+        let mut nearly_skew = Vec3 { x: 0.3, y: -0.4, z: 0.5 }.to_skew_matrix();
+        let rodrigues = nearly_skew.exp();
+        nearly_skew.a = 1e-9;
+        assert_mat3_near(&nearly_skew.exp(), &rodrigues);
+    }
+
+    #[test]
+    fn log_inverts_exp() {
+        let mat3 = Mat3::new_from_array(&[0.1, -0.7, 0.2, 0.7, 0.0, -0.3, -0.2, 0.3, 0.4]);
+        let log = mat3.exp().log().unwrap();
+        assert_mat3_near(&log, &mat3);
+
+        let rotation = Mat3::new_from_rotation_x(0.8);
+        let log = rotation.log().unwrap();
+        assert_vec3_near(&log.vee(), &Vec3 { x: 0.8, y: 0.0, z: 0.0 });
+    }
+
+    #[test]
+    fn log_of_rigid_transform() {
+        // The generator of a screw motion: rotation about z with a
+        // translation along x and z.
+        let twist = Mat4::new_from_array(&[
+            0.0, -0.9, 0.0, 1.0,
+            0.9,  0.0, 0.0, 0.0,
+            0.0,  0.0, 0.0, 2.0,
+            0.0,  0.0, 0.0, 0.0,
+        ]);
+        let transform = twist.exp();
+        assert_mat3_near(&transform.to_mat3(), &Mat3::new_from_rotation_z(0.9));
+        assert_mat4_near(&transform.log().unwrap(), &twist);
+    }
+
+    #[test]
+    fn log_of_negative_eigenvalue() {
+        let mat2 = Mat2::new_from_values(&-1.0, &0.0, &0.0, &1.0);
+        assert_eq!(mat2.log(), None);
+    }
+
+    #[test]
+    fn sqrt_squares_to_original() {
+        let mat4 = Mat4::new_from_array(&[
+            4.0, 1.0, 0.0, 0.5,
+            1.0, 3.0, 0.5, 0.0,
+            0.0, 0.5, 2.0, 0.0,
+            0.5, 0.0, 0.0, 5.0,
+        ]);
+        let root = mat4.sqrt().unwrap();
+        assert_mat4_near(&(root * root), &mat4);
+
+        let mat2 = Mat2::new_from_values(&4.0, &0.0, &0.0, &9.0);
+        assert_mat2_near(&mat2.sqrt().unwrap(), &Mat2::new_from_values(&2.0, &0.0, &0.0, &3.0));
+    }
+}
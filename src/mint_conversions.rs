@@ -0,0 +1,227 @@
+//! Conversions between Matriarch types and the `mint` interoperability types,
+//! enabled with the `mint` feature.
+//!
+//! Vectors map to `mint::Vector*`. Matrices convert to and from both
+//! `mint::RowMatrix*` and `mint::ColumnMatrix*`, always preserving the
+//! mathematical value of the matrix rather than its memory layout.
+
+use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+impl From<mint::Vector2<f32>> for Vec2 {
+    fn from(vector: mint::Vector2<f32>) -> Vec2 {
+        Vec2 { x: vector.x, y: vector.y }
+    }
+}
+
+impl From<Vec2> for mint::Vector2<f32> {
+    fn from(vec2: Vec2) -> mint::Vector2<f32> {
+        mint::Vector2 { x: vec2.x, y: vec2.y }
+    }
+}
+
+impl mint::IntoMint for Vec2 {
+    type MintType = mint::Vector2<f32>;
+}
+
+impl From<mint::Vector3<f32>> for Vec3 {
+    fn from(vector: mint::Vector3<f32>) -> Vec3 {
+        Vec3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+impl From<Vec3> for mint::Vector3<f32> {
+    fn from(vec3: Vec3) -> mint::Vector3<f32> {
+        mint::Vector3 { x: vec3.x, y: vec3.y, z: vec3.z }
+    }
+}
+
+impl mint::IntoMint for Vec3 {
+    type MintType = mint::Vector3<f32>;
+}
+
+impl From<mint::Vector4<f32>> for Vec4 {
+    fn from(vector: mint::Vector4<f32>) -> Vec4 {
+        Vec4 { x: vector.x, y: vector.y, z: vector.z, w: vector.w }
+    }
+}
+
+impl From<Vec4> for mint::Vector4<f32> {
+    fn from(vec4: Vec4) -> mint::Vector4<f32> {
+        mint::Vector4 { x: vec4.x, y: vec4.y, z: vec4.z, w: vec4.w }
+    }
+}
+
+impl mint::IntoMint for Vec4 {
+    type MintType = mint::Vector4<f32>;
+}
+
+impl From<mint::RowMatrix2<f32>> for Mat2 {
+    fn from(matrix: mint::RowMatrix2<f32>) -> Mat2 {
+        Mat2 {
+            a: matrix.x.x, b: matrix.x.y,
+            c: matrix.y.x, d: matrix.y.y
+        }
+    }
+}
+
+impl From<Mat2> for mint::RowMatrix2<f32> {
+    fn from(mat2: Mat2) -> mint::RowMatrix2<f32> {
+        mint::RowMatrix2 {
+            x: mint::Vector2 { x: mat2.a, y: mat2.b },
+            y: mint::Vector2 { x: mat2.c, y: mat2.d },
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix2<f32>> for Mat2 {
+    fn from(matrix: mint::ColumnMatrix2<f32>) -> Mat2 {
+        Mat2 {
+            a: matrix.x.x, b: matrix.y.x,
+            c: matrix.x.y, d: matrix.y.y
+        }
+    }
+}
+
+impl From<Mat2> for mint::ColumnMatrix2<f32> {
+    fn from(mat2: Mat2) -> mint::ColumnMatrix2<f32> {
+        mint::ColumnMatrix2 {
+            x: mint::Vector2 { x: mat2.a, y: mat2.c },
+            y: mint::Vector2 { x: mat2.b, y: mat2.d },
+        }
+    }
+}
+
+impl mint::IntoMint for Mat2 {
+    type MintType = mint::RowMatrix2<f32>;
+}
+
+impl From<mint::RowMatrix3<f32>> for Mat3 {
+    fn from(matrix: mint::RowMatrix3<f32>) -> Mat3 {
+        Mat3 {
+            a: matrix.x.x, b: matrix.x.y, c: matrix.x.z,
+            d: matrix.y.x, e: matrix.y.y, f: matrix.y.z,
+            g: matrix.z.x, h: matrix.z.y, i: matrix.z.z
+        }
+    }
+}
+
+impl From<Mat3> for mint::RowMatrix3<f32> {
+    fn from(mat3: Mat3) -> mint::RowMatrix3<f32> {
+        mint::RowMatrix3 {
+            x: mint::Vector3 { x: mat3.a, y: mat3.b, z: mat3.c },
+            y: mint::Vector3 { x: mat3.d, y: mat3.e, z: mat3.f },
+            z: mint::Vector3 { x: mat3.g, y: mat3.h, z: mat3.i },
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<f32>> for Mat3 {
+    fn from(matrix: mint::ColumnMatrix3<f32>) -> Mat3 {
+        Mat3 {
+            a: matrix.x.x, b: matrix.y.x, c: matrix.z.x,
+            d: matrix.x.y, e: matrix.y.y, f: matrix.z.y,
+            g: matrix.x.z, h: matrix.y.z, i: matrix.z.z
+        }
+    }
+}
+
+impl From<Mat3> for mint::ColumnMatrix3<f32> {
+    fn from(mat3: Mat3) -> mint::ColumnMatrix3<f32> {
+        mint::ColumnMatrix3 {
+            x: mint::Vector3 { x: mat3.a, y: mat3.d, z: mat3.g },
+            y: mint::Vector3 { x: mat3.b, y: mat3.e, z: mat3.h },
+            z: mint::Vector3 { x: mat3.c, y: mat3.f, z: mat3.i },
+        }
+    }
+}
+
+impl mint::IntoMint for Mat3 {
+    type MintType = mint::RowMatrix3<f32>;
+}
+
+impl From<mint::RowMatrix4<f32>> for Mat4 {
+    fn from(matrix: mint::RowMatrix4<f32>) -> Mat4 {
+        Mat4 {
+            a: matrix.x.x, b: matrix.x.y, c: matrix.x.z, d: matrix.x.w,
+            e: matrix.y.x, f: matrix.y.y, g: matrix.y.z, h: matrix.y.w,
+            i: matrix.z.x, j: matrix.z.y, k: matrix.z.z, l: matrix.z.w,
+            m: matrix.w.x, n: matrix.w.y, o: matrix.w.z, p: matrix.w.w
+        }
+    }
+}
+
+impl From<Mat4> for mint::RowMatrix4<f32> {
+    fn from(mat4: Mat4) -> mint::RowMatrix4<f32> {
+        mint::RowMatrix4 {
+            x: mint::Vector4 { x: mat4.a, y: mat4.b, z: mat4.c, w: mat4.d },
+            y: mint::Vector4 { x: mat4.e, y: mat4.f, z: mat4.g, w: mat4.h },
+            z: mint::Vector4 { x: mat4.i, y: mat4.j, z: mat4.k, w: mat4.l },
+            w: mint::Vector4 { x: mat4.m, y: mat4.n, z: mat4.o, w: mat4.p },
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f32>> for Mat4 {
+    fn from(matrix: mint::ColumnMatrix4<f32>) -> Mat4 {
+        Mat4 {
+            a: matrix.x.x, b: matrix.y.x, c: matrix.z.x, d: matrix.w.x,
+            e: matrix.x.y, f: matrix.y.y, g: matrix.z.y, h: matrix.w.y,
+            i: matrix.x.z, j: matrix.y.z, k: matrix.z.z, l: matrix.w.z,
+            m: matrix.x.w, n: matrix.y.w, o: matrix.z.w, p: matrix.w.w
+        }
+    }
+}
+
+impl From<Mat4> for mint::ColumnMatrix4<f32> {
+    fn from(mat4: Mat4) -> mint::ColumnMatrix4<f32> {
+        mint::ColumnMatrix4 {
+            x: mint::Vector4 { x: mat4.a, y: mat4.e, z: mat4.i, w: mat4.m },
+            y: mint::Vector4 { x: mat4.b, y: mat4.f, z: mat4.j, w: mat4.n },
+            z: mint::Vector4 { x: mat4.c, y: mat4.g, z: mat4.k, w: mat4.o },
+            w: mint::Vector4 { x: mat4.d, y: mat4.h, z: mat4.l, w: mat4.p },
+        }
+    }
+}
+
+impl mint::IntoMint for Mat4 {
+    type MintType = mint::RowMatrix4<f32>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat2, Mat3, Mat4, Vec3};
+
+    #[test]
+    fn convert_vec3() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let vector: mint::Vector3<f32> = vec3.into();
+        assert_eq!(vector, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+        assert_eq!(Vec3::from(vector), vec3);
+    }
+
+    #[test]
+    fn convert_mat2_rows_and_columns() {
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        let rows: mint::RowMatrix2<f32> = mat2.into();
+        assert_eq!(rows.x, mint::Vector2 { x: 1.0, y: 2.0 });
+        let columns: mint::ColumnMatrix2<f32> = mat2.into();
+        assert_eq!(columns.x, mint::Vector2 { x: 1.0, y: 3.0 });
+        assert_eq!(Mat2::from(rows), mat2);
+        assert_eq!(Mat2::from(columns), mat2);
+    }
+
+    #[test]
+    fn convert_mat3_and_mat4() {
+        let mat3 = Mat3::new_from_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let columns: mint::ColumnMatrix3<f32> = mat3.into();
+        assert_eq!(columns.y, mint::Vector3 { x: 2.0, y: 5.0, z: 8.0 });
+        assert_eq!(Mat3::from(columns), mat3);
+        assert_eq!(Mat3::from(mint::RowMatrix3::from(mat3)), mat3);
+
+        let mat4 = Mat4::new_from_array(&[ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0 ]);
+        let columns: mint::ColumnMatrix4<f32> = mat4.into();
+        assert_eq!(columns.w, mint::Vector4 { x: 4.0, y: 8.0, z: 12.0, w: 16.0 });
+        assert_eq!(Mat4::from(columns), mat4);
+        assert_eq!(Mat4::from(mint::RowMatrix4::from(mat4)), mat4);
+    }
+}
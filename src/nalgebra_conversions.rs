@@ -0,0 +1,132 @@
+//! Conversions between Matriarch and `nalgebra` types, enabled with the
+//! `nalgebra` feature.
+//!
+//! nalgebra stores its matrices column-major while Matriarch is row-major, so
+//! matrices are converted through their column-major arrays to keep the same
+//! mathematical value.
+
+use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+impl From<nalgebra::Vector2<f32>> for Vec2 {
+    fn from(vector: nalgebra::Vector2<f32>) -> Vec2 {
+        Vec2 { x: vector.x, y: vector.y }
+    }
+}
+
+impl From<Vec2> for nalgebra::Vector2<f32> {
+    fn from(vec2: Vec2) -> nalgebra::Vector2<f32> {
+        nalgebra::Vector2::new(vec2.x, vec2.y)
+    }
+}
+
+impl From<nalgebra::Vector3<f32>> for Vec3 {
+    fn from(vector: nalgebra::Vector3<f32>) -> Vec3 {
+        Vec3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+impl From<Vec3> for nalgebra::Vector3<f32> {
+    fn from(vec3: Vec3) -> nalgebra::Vector3<f32> {
+        nalgebra::Vector3::new(vec3.x, vec3.y, vec3.z)
+    }
+}
+
+impl From<nalgebra::Point3<f32>> for Vec3 {
+    fn from(point: nalgebra::Point3<f32>) -> Vec3 {
+        Vec3 { x: point.x, y: point.y, z: point.z }
+    }
+}
+
+impl From<Vec3> for nalgebra::Point3<f32> {
+    fn from(vec3: Vec3) -> nalgebra::Point3<f32> {
+        nalgebra::Point3::new(vec3.x, vec3.y, vec3.z)
+    }
+}
+
+impl From<nalgebra::Vector4<f32>> for Vec4 {
+    fn from(vector: nalgebra::Vector4<f32>) -> Vec4 {
+        Vec4 { x: vector.x, y: vector.y, z: vector.z, w: vector.w }
+    }
+}
+
+impl From<Vec4> for nalgebra::Vector4<f32> {
+    fn from(vec4: Vec4) -> nalgebra::Vector4<f32> {
+        nalgebra::Vector4::new(vec4.x, vec4.y, vec4.z, vec4.w)
+    }
+}
+
+impl From<nalgebra::Matrix2<f32>> for Mat2 {
+    fn from(matrix: nalgebra::Matrix2<f32>) -> Mat2 {
+        let mut columns = [0.0; 4];
+        columns.copy_from_slice(matrix.as_slice());
+        Mat2::new_from_col_array(&columns)
+    }
+}
+
+impl From<Mat2> for nalgebra::Matrix2<f32> {
+    fn from(mat2: Mat2) -> nalgebra::Matrix2<f32> {
+        nalgebra::Matrix2::from_column_slice(&mat2.to_col_array())
+    }
+}
+
+impl From<nalgebra::Matrix3<f32>> for Mat3 {
+    fn from(matrix: nalgebra::Matrix3<f32>) -> Mat3 {
+        let mut columns = [0.0; 9];
+        columns.copy_from_slice(matrix.as_slice());
+        Mat3::new_from_col_array(&columns)
+    }
+}
+
+impl From<Mat3> for nalgebra::Matrix3<f32> {
+    fn from(mat3: Mat3) -> nalgebra::Matrix3<f32> {
+        nalgebra::Matrix3::from_column_slice(&mat3.to_col_array())
+    }
+}
+
+impl From<nalgebra::Matrix4<f32>> for Mat4 {
+    fn from(matrix: nalgebra::Matrix4<f32>) -> Mat4 {
+        let mut columns = [0.0; 16];
+        columns.copy_from_slice(matrix.as_slice());
+        Mat4::new_from_col_array(&columns)
+    }
+}
+
+impl From<Mat4> for nalgebra::Matrix4<f32> {
+    fn from(mat4: Mat4) -> nalgebra::Matrix4<f32> {
+        nalgebra::Matrix4::from_column_slice(&mat4.to_col_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat2, Mat3, Mat4, Vec3, Vec4};
+
+    #[test]
+    fn convert_vectors() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_eq!(nalgebra::Vector3::from(vec3), nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(nalgebra::Point3::from(vec3)), vec3);
+    }
+
+    #[test]
+    fn convert_matrices_preserving_value() {
+        let mat2 = Mat2::new_from_values(&1.0, &2.0, &3.0, &4.0);
+        let matrix = nalgebra::Matrix2::from(mat2);
+        assert_eq!(matrix[(0, 1)], 2.0);
+        assert_eq!(Mat2::from(matrix), mat2);
+
+        let mat3 = Mat3::new_from_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let matrix = nalgebra::Matrix3::from(mat3);
+        assert_eq!(matrix[(1, 2)], 6.0);
+        assert_eq!(Mat3::from(matrix), mat3);
+    }
+
+    #[test]
+    fn mat4_vec4_product_agrees() {
+        let mat4 = Mat4::new_from_array(&[ 1.5, 8.0, 2.0, 2.5, 10.0, 4.0, 4.0, 10.0, 3.5, 6.0, 7.0, 0.0, 7.0, 4.0, 2.0, 1.0 ]);
+        let vec4 = Vec4 { x: 8.0, y: 5.0, z: 0.0, w: 5.0 };
+        let result = nalgebra::Matrix4::from(mat4) * nalgebra::Vector4::from(vec4);
+        assert_eq!(Vec4::from(result), mat4 * vec4);
+        assert_eq!(Mat4::from(nalgebra::Matrix4::from(mat4)), mat4);
+    }
+}
@@ -0,0 +1,375 @@
+//! Implementation of an Oriented Bounding Box and its associated functions
+//! and methods.
+
+use super::{Aabb, Mat3, Vec3};
+
+/// Added to the absolute values of the rotation between two Obbs in
+/// `intersects_obb`, so that when edges are nearly parallel their cross
+/// product, which is then close to zero and meaningless, can't report a
+/// false separation.
+const PARALLEL_EPSILON: f32 = 1e-6;
+
+/// A box centered on `center` whose edges run along the columns of
+/// `orientation`, extending `half_extents` along each of them.
+///
+/// `orientation` is expected to be a rotation, with orthonormal columns and
+/// a determinant of 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Obb {
+    pub center: Vec3,
+    pub orientation: Mat3,
+    pub half_extents: Vec3,
+}
+
+impl Obb {
+    /// Returns a new Obb using the given center, orientation and half
+    /// extents.
+    pub fn new_from_values(center: &Vec3, orientation: &Mat3, half_extents: &Vec3) -> Obb {
+        Obb { center: *center, orientation: *orientation, half_extents: *half_extents }
+    }
+
+    /// Returns the Obb covering the same space as the Aabb.
+    pub fn new_from_aabb(aabb: &Aabb) -> Obb {
+        Obb { center: aabb.center(), orientation: Mat3::identity(), half_extents: aabb.half_extents() }
+    }
+
+    /// Returns an Obb containing all of the given points, or `None` if the
+    /// slice is empty.
+    ///
+    /// The axes of the Obb are the eigenvectors of the points' covariance
+    /// matrix, which are the directions the points spread out along the
+    /// most and the least. This fits elongated point sets well but isn't
+    /// the smallest possible box; where the spread is the same in several
+    /// directions, such as for the corners of a cube, the axes are
+    /// arbitrary among them.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Obb, Vec3};
+    /// let points = [
+    ///     Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+    ///     Vec3 { x: 1.0, y: 1.0, z: 0.0 },
+    ///     Vec3 { x: 2.0, y: 2.0, z: 0.0 },
+    ///     Vec3 { x: 3.0, y: 3.0, z: 0.0 },
+    /// ];
+    /// let obb = Obb::new_from_points(&points).unwrap();
+    /// // The long axis runs along the diagonal.
+    /// assert!((obb.half_extents.x - 1.5 * 2.0f32.sqrt()).abs() < 1e-5);
+    /// assert!(obb.half_extents.y.abs() < 1e-5 && obb.half_extents.z.abs() < 1e-5);
+    /// ```
+    pub fn new_from_points(points: &[Vec3]) -> Option<Obb> {
+        if points.is_empty() {
+            return None;
+        }
+        let count = points.len() as f64;
+        let mut mean = [0.0f64; 3];
+        for point in points {
+            for (total, value) in mean.iter_mut().zip(&[point.x, point.y, point.z]) {
+                *total += *value as f64 / count;
+            }
+        }
+        let mut covariance = [[0.0f64; 3]; 3];
+        for point in points {
+            let offset = [point.x as f64 - mean[0], point.y as f64 - mean[1], point.z as f64 - mean[2]];
+            for row in 0..3 {
+                for col in 0..3 {
+                    covariance[row][col] += offset[row] * offset[col] / count;
+                }
+            }
+        }
+
+        let axes = symmetric_eigenvectors(&covariance);
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for point in points {
+            let offset = [point.x as f64 - mean[0], point.y as f64 - mean[1], point.z as f64 - mean[2]];
+            for axis in 0..3 {
+                let projection = (0..3).map(|k| offset[k] * axes[axis][k]).sum::<f64>();
+                min[axis] = min[axis].min(projection);
+                max[axis] = max[axis].max(projection);
+            }
+        }
+        let center: Vec<f64> = (0..3)
+            .map(|k| mean[k] + (0..3).map(|axis| 0.5 * (min[axis] + max[axis]) * axes[axis][k]).sum::<f64>())
+            .collect();
+        let column = |axis: usize| Vec3 { x: axes[axis][0] as f32, y: axes[axis][1] as f32, z: axes[axis][2] as f32 };
+        let (x, y, z) = (column(0), column(1), column(2));
+        Some(Obb {
+            center: Vec3 { x: center[0] as f32, y: center[1] as f32, z: center[2] as f32 },
+            orientation: Mat3::new_from_col_array(&[x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z]),
+            half_extents: Vec3 {
+                x: (0.5 * (max[0] - min[0])) as f32,
+                y: (0.5 * (max[1] - min[1])) as f32,
+                z: (0.5 * (max[2] - min[2])) as f32,
+            },
+        })
+    }
+
+    /// Returns the directions of the Obb's edges, the columns of its
+    /// orientation.
+    pub fn axes(&self) -> [Vec3; 3] {
+        self.orientation.to_vec3_array()
+    }
+
+    /// Returns the 8 corners of the Obb, ordered like `Aabb::corners` with
+    /// the Obb's axes in place of x, y and z.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let [x, y, z] = self.axes();
+        let (x, y, z) = (self.half_extents.x * x, self.half_extents.y * y, self.half_extents.z * z);
+        let mut corners = [self.center; 8];
+        for (index, corner) in corners.iter_mut().enumerate() {
+            let sign = |bit: usize| if index & bit == 0 { -1.0 } else { 1.0 };
+            *corner = self.center + sign(1) * x + sign(2) * y + sign(4) * z;
+        }
+        corners
+    }
+
+    /// Returns true if the point lies inside or on the surface of the Obb.
+    pub fn contains_point(&self, point: &Vec3) -> bool {
+        let offset = *point - self.center;
+        let [x, y, z] = self.axes();
+        (offset * x).abs() <= self.half_extents.x
+            && (offset * y).abs() <= self.half_extents.y
+            && (offset * z).abs() <= self.half_extents.z
+    }
+
+    /// Returns the smallest Aabb containing the Obb.
+    pub fn aabb(&self) -> Aabb {
+        let [x, y, z] = self.axes();
+        let extent = |k: usize| {
+            let component = |v: &Vec3| [v.x, v.y, v.z][k].abs();
+            self.half_extents.x * component(&x) + self.half_extents.y * component(&y) + self.half_extents.z * component(&z)
+        };
+        let extents = Vec3 { x: extent(0), y: extent(1), z: extent(2) };
+        Aabb { min: self.center - extents, max: self.center + extents }
+    }
+
+    /// Returns true if the two Obbs overlap or touch, using the separating
+    /// axis theorem.
+    ///
+    /// Two boxes are apart exactly when their projections onto one of 15
+    /// axes don't overlap: the 3 face normals of each box and the 9 cross
+    /// products of an edge of one with an edge of the other. Everything is
+    /// computed in the first Obb's frame, following Christer Ericson's
+    /// "Real-Time Collision Detection".
+    pub fn intersects_obb(&self, other: &Obb) -> bool {
+        let (a_axes, b_axes) = (self.axes(), other.axes());
+        let a = [self.half_extents.x, self.half_extents.y, self.half_extents.z];
+        let b = [other.half_extents.x, other.half_extents.y, other.half_extents.z];
+
+        // The rotation taking the other Obb into this one's frame.
+        let mut r = [[0.0f32; 3]; 3];
+        let mut abs_r = [[0.0f32; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a_axes[i] * b_axes[j];
+                abs_r[i][j] = r[i][j].abs() + PARALLEL_EPSILON;
+            }
+        }
+        let offset = other.center - self.center;
+        let t = [offset * a_axes[0], offset * a_axes[1], offset * a_axes[2]];
+
+        for i in 0..3 {
+            let radius_b = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+            if t[i].abs() > a[i] + radius_b {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let radius_a = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+            let distance = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if distance.abs() > radius_a + b[j] {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let radius_a = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                let radius_b = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                let distance = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if distance.abs() > radius_a + radius_b {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Returns the eigenvectors of a symmetric matrix as the rows of the result,
+/// ordered from the largest eigenvalue to the smallest and forming a
+/// right-handed basis, using cyclic Jacobi rotations.
+fn symmetric_eigenvectors(matrix: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut a = *matrix;
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..32 {
+        let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off_diagonal <= 1e-30 * diagonal || off_diagonal == 0.0 {
+            break;
+        }
+        for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            // The rotation in the p-q plane that zeroes a[p][q].
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            for (k, (pk, qk)) in row_p.iter().zip(&row_q).enumerate() {
+                a[p][k] = c * pk - s * qk;
+                a[q][k] = s * pk + c * qk;
+            }
+            for row in v.iter_mut() {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|i, j| a[*j][*j].partial_cmp(&a[*i][*i]).unwrap_or(std::cmp::Ordering::Equal));
+    let mut vectors = [[0.0; 3]; 3];
+    for (row, column) in order.iter().enumerate() {
+        vectors[row] = [v[0][*column], v[1][*column], v[2][*column]];
+    }
+    let [x, y, _] = vectors;
+    vectors[2] = [x[1] * y[2] - x[2] * y[1], x[2] * y[0] - x[0] * y[2], x[0] * y[1] - x[1] * y[0]];
+    vectors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aabb, Mat3, Obb, Vec3};
+    use crate::test_values::values;
+
+    fn rotation(seed: u32) -> Mat3 {
+        let angles = values(3, seed);
+        Mat3::new_from_rotation_z(3.0 * angles[0]) * Mat3::new_from_rotation_y(3.0 * angles[1]) * Mat3::new_from_rotation_x(3.0 * angles[2])
+    }
+
+    #[test]
+    fn obb_fits_rotated_box() {
+        let box_obb = Obb {
+            center: Vec3 { x: 1.0, y: -2.0, z: 3.0 },
+            orientation: rotation(1),
+            half_extents: Vec3 { x: 4.0, y: 2.0, z: 0.5 },
+        };
+        // Points spread through the box, which has three distinct spreads.
+        let coordinates = values(3 * 2000, 2);
+        let mut points: Vec<Vec3> = coordinates
+            .chunks(3)
+            .map(|c| {
+                let [x, y, z] = box_obb.axes();
+                box_obb.center + (c[0] * 4.0) * x + (c[1] * 2.0) * y + (c[2] * 0.5) * z
+            })
+            .collect();
+        points.extend_from_slice(&box_obb.corners());
+        let obb = Obb::new_from_points(&points).unwrap();
+        assert!((obb.center - box_obb.center).length() < 1e-4);
+        // Sampling tilts the covariance axes slightly, widening the fit.
+        assert!((obb.half_extents - box_obb.half_extents).length() < 0.1);
+        for (axis, expected) in obb.axes().iter().zip(box_obb.axes().iter()) {
+            assert!((*axis * *expected).abs() > 0.999);
+        }
+        assert!((obb.orientation.determinant() - 1.0).abs() < 1e-5);
+        for point in &points {
+            let grown = Obb { half_extents: obb.half_extents + Vec3 { x: 1e-4, y: 1e-4, z: 1e-4 }, ..obb };
+            assert!(grown.contains_point(point));
+        }
+        assert_eq!(Obb::new_from_points(&[]), None);
+    }
+
+    #[test]
+    fn obb_aabb_and_corners() {
+        let aabb = Aabb { min: Vec3 { x: -1.0, y: 0.0, z: 2.0 }, max: Vec3 { x: 3.0, y: 2.0, z: 4.0 } };
+        let obb = Obb::new_from_aabb(&aabb);
+        assert_eq!(obb.corners(), aabb.corners());
+        assert_eq!(obb.aabb(), aabb);
+
+        let rotated = Obb { orientation: Mat3::new_from_rotation_z(std::f32::consts::FRAC_PI_4), ..obb };
+        let bounds = rotated.aabb();
+        let expected = 3.0 / 2.0f32.sqrt();
+        assert!((bounds.half_extents().x - expected).abs() < 1e-5);
+        assert!((bounds.half_extents().y - expected).abs() < 1e-5);
+        assert!((bounds.half_extents().z - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests the 15 axes directly by projecting every corner onto them.
+    fn separated_by_projection(a: &Obb, b: &Obb) -> bool {
+        let mut axes = Vec::new();
+        axes.extend_from_slice(&a.axes());
+        axes.extend_from_slice(&b.axes());
+        for i in a.axes().iter() {
+            for j in b.axes().iter() {
+                axes.push(i.cross_product(j));
+            }
+        }
+        axes.iter().filter(|axis| axis.length() > 1e-3).any(|axis| {
+            let project = |obb: &Obb| {
+                let values: Vec<f32> = obb.corners().iter().map(|corner| *corner * *axis).collect();
+                (values.iter().cloned().fold(f32::INFINITY, f32::min), values.iter().cloned().fold(f32::NEG_INFINITY, f32::max))
+            };
+            let ((a_min, a_max), (b_min, b_max)) = (project(a), project(b));
+            a_max < b_min || b_max < a_min
+        })
+    }
+
+    #[test]
+    fn obb_overlap_matches_projection() {
+        let (mut overlapping, mut separate) = (0, 0);
+        for seed in 0..500 {
+            let v = values(9, seed + 100);
+            let a = Obb {
+                center: Vec3::new(),
+                orientation: rotation(2 * seed),
+                half_extents: Vec3 { x: 1.0 + v[0].abs(), y: 0.5 + v[1].abs(), z: 0.2 + v[2].abs() },
+            };
+            let b = Obb {
+                center: Vec3 { x: 3.0 * v[3], y: 3.0 * v[4], z: 3.0 * v[5] },
+                orientation: rotation(2 * seed + 1),
+                half_extents: Vec3 { x: 0.3 + v[6].abs(), y: 1.0 + v[7].abs(), z: 0.1 + v[8].abs() },
+            };
+            let intersects = a.intersects_obb(&b);
+            assert_eq!(intersects, !separated_by_projection(&a, &b), "seed {}", seed);
+            assert_eq!(intersects, b.intersects_obb(&a));
+            if intersects {
+                overlapping += 1;
+            } else {
+                separate += 1;
+            }
+        }
+        assert!(overlapping > 50 && separate > 50);
+    }
+
+    #[test]
+    fn obb_separated_only_by_edge_axis() {
+        // Two cubes turned 45 degrees about different axes, placed so their
+        // face normals all overlap but the cross product of their edges
+        // separates them.
+        let a = Obb {
+            center: Vec3::new(),
+            orientation: Mat3::new_from_rotation_z(std::f32::consts::FRAC_PI_4),
+            half_extents: Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+        };
+        let b = Obb {
+            center: Vec3 { x: 2.3, y: 0.5, z: 2.3 },
+            orientation: Mat3::new_from_rotation_x(std::f32::consts::FRAC_PI_4),
+            half_extents: Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+        };
+        assert!(!a.intersects_obb(&b));
+        assert!(separated_by_projection(&a, &b));
+        let closer = Obb { center: Vec3 { x: 1.6, y: 0.0, z: 1.6 }, ..b };
+        assert!(a.intersects_obb(&closer));
+    }
+}
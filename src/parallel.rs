@@ -0,0 +1,157 @@
+//! Parallel versions of the batch operations, enabled with the `rayon`
+//! feature.
+//!
+//! Slices are split into chunks of a fixed size rather than one per thread,
+//! and reductions combine the per-chunk results in order on the calling
+//! thread. Results are therefore identical no matter how many threads the
+//! rayon pool has, down to the rounding of floating point sums.
+
+use rayon::prelude::*;
+
+use super::{Aabb, Mat4, Vec3};
+
+/// The number of elements each rayon task works on.
+const CHUNK_SIZE: usize = 4096;
+
+impl Mat4 {
+    /// Transforms every position in `input` with `transform_point`, writing
+    /// the results to the same index of `output`, using the rayon thread
+    /// pool.
+    ///
+    /// Panics if the slices have different lengths.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Mat3, Mat4, Vec3};
+    /// let translation = Mat4::new_from_trs(
+    ///     &Vec3 { x: 1.0, y: 2.0, z: 3.0 },
+    ///     &Mat3::identity(),
+    ///     &Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+    /// );
+    /// let points = vec![Vec3::new(); 100_000];
+    /// let mut output = vec![Vec3::new(); points.len()];
+    /// translation.par_transform_points(&points, &mut output);
+    /// assert_eq!(output[99_999], Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+    /// ```
+    pub fn par_transform_points(&self, input: &[Vec3], output: &mut [Vec3]) {
+        assert_eq!(input.len(), output.len(), "input and output must be the same length");
+        input
+            .par_chunks(CHUNK_SIZE)
+            .zip(output.par_chunks_mut(CHUNK_SIZE))
+            .for_each(|(input, output)| self.transform_points(input, output));
+    }
+
+    /// Multiplies each matrix in `lhs` by the matrix at the same index of
+    /// `rhs`, writing the products to the same index of `output`, using the
+    /// rayon thread pool.
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn par_multiply(lhs: &[Mat4], rhs: &[Mat4], output: &mut [Mat4]) {
+        assert_eq!(lhs.len(), rhs.len(), "lhs and rhs must be the same length");
+        assert_eq!(lhs.len(), output.len(), "inputs and output must be the same length");
+        output
+            .par_chunks_mut(CHUNK_SIZE)
+            .zip(lhs.par_chunks(CHUNK_SIZE).zip(rhs.par_chunks(CHUNK_SIZE)))
+            .for_each(|(output, (lhs, rhs))| {
+                for (result, (a, b)) in output.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+                    *result = *a * *b;
+                }
+            });
+    }
+}
+
+impl Vec3 {
+    /// Returns the sum of every vector in the slice, using the rayon thread
+    /// pool.
+    pub fn par_sum(vectors: &[Vec3]) -> Vec3 {
+        let partial_sums: Vec<Vec3> = vectors.par_chunks(CHUNK_SIZE).map(|chunk| chunk.iter().sum()).collect();
+        partial_sums.iter().sum()
+    }
+}
+
+impl Aabb {
+    /// Returns the smallest box containing every point in the slice, using
+    /// the rayon thread pool, or `None` if the slice is empty.
+    pub fn par_new_from_points(points: &[Vec3]) -> Option<Aabb> {
+        let partial_bounds: Vec<Vec3> = points
+            .par_chunks(CHUNK_SIZE)
+            .flat_map_iter(|chunk| {
+                let aabb = Aabb::new_from_points(chunk).unwrap();
+                vec![aabb.min, aabb.max]
+            })
+            .collect();
+        Aabb::new_from_points(&partial_bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Mat3;
+    use super::{Aabb, Mat4, Vec3, CHUNK_SIZE};
+
+    fn points(count: usize) -> Vec<Vec3> {
+        (0..count)
+            .map(|index| {
+                let t = index as f32 * 0.001;
+                Vec3 { x: t.sin() * 10.0, y: t.cos() * 3.0, z: t }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_sequential_transform() {
+        let input = points(3 * CHUNK_SIZE + 17);
+        let matrix = Mat4::new_from_array(&[
+            0.0, -1.0, 0.0, 2.0,
+            1.0,  0.0, 0.0, 3.0,
+            0.0,  0.0, 2.0, 4.0,
+            0.0,  0.0, 0.0, 1.0,
+        ]);
+        let mut expected = vec![Vec3::new(); input.len()];
+        let mut output = vec![Vec3::new(); input.len()];
+        matrix.transform_points(&input, &mut expected);
+        matrix.par_transform_points(&input, &mut output);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn multiply_pairwise() {
+        let lhs: Vec<Mat4> = (0..CHUNK_SIZE + 5).map(|index| index as f32 * Mat4::identity()).collect();
+        let scale = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+        let rhs: Vec<Mat4> = (0..CHUNK_SIZE + 5)
+            .map(|index| Mat4::new_from_trs(&Vec3 { x: index as f32, y: 0.0, z: 0.0 }, &Mat3::identity(), &scale))
+            .collect();
+        let mut output = vec![Mat4::new(); lhs.len()];
+        Mat4::par_multiply(&lhs, &rhs, &mut output);
+        for index in 0..lhs.len() {
+            assert_eq!(output[index], lhs[index] * rhs[index]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiply_mismatched_lengths() {
+        let mut output = vec![Mat4::new(); 2];
+        Mat4::par_multiply(&[Mat4::identity(); 2], &[Mat4::identity(); 3], &mut output);
+    }
+
+    #[test]
+    fn deterministic_reductions() {
+        let input = points(10 * CHUNK_SIZE + 3);
+        let sum = Vec3::par_sum(&input);
+        let aabb = Aabb::par_new_from_points(&input).unwrap();
+        for threads in 1..5 {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            assert_eq!(pool.install(|| Vec3::par_sum(&input)), sum);
+            assert_eq!(pool.install(|| Aabb::par_new_from_points(&input)), Some(aabb));
+        }
+        assert_eq!(aabb, Aabb::new_from_points(&input).unwrap());
+        assert!((sum - input.iter().sum::<Vec3>()).length() < 1e-2 * sum.length());
+    }
+
+    #[test]
+    fn empty_reductions() {
+        assert_eq!(Vec3::par_sum(&[]), Vec3::new());
+        assert_eq!(Aabb::par_new_from_points(&[]), None);
+    }
+}
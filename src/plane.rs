@@ -0,0 +1,101 @@
+//! Implementation of a Plane and its associated functions and methods.
+
+use super::{Vec3, Vec4};
+
+/// A plane in 3D space satisfying the equation `normal · p + d = 0`.
+///
+/// Points for which `normal · p + d` is positive lie on the side of the plane
+/// the normal points towards.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Returns a new Plane with a zero normal and a `d` of 0.
+    pub fn new() -> Plane {
+        Plane { normal: Vec3::new(), d: 0.0 }
+    }
+
+    /// Returns a new Plane using the given normal and distance term.
+    pub fn new_from_values(normal: &Vec3, d: &f32) -> Plane {
+        Plane { normal: *normal, d: *d }
+    }
+
+    /// Returns a new Plane from the `(a, b, c, d)` coefficients of the plane
+    /// equation `ax + by + cz + d = 0` stored in a Vec4.
+    pub fn new_from_vec4(coefficients: &Vec4) -> Plane {
+        Plane {
+            normal: Vec3 { x: coefficients.x, y: coefficients.y, z: coefficients.z },
+            d: coefficients.w,
+        }
+    }
+
+    /// Returns a new Plane passing through `point` with the given normal.
+    pub fn new_from_point_normal(point: &Vec3, normal: &Vec3) -> Plane {
+        Plane { normal: *normal, d: -(*normal * *point) }
+    }
+
+    /// Returns a Plane describing the same surface with a unit length normal.
+    ///
+    /// Once normalized, `distance_to_point` returns true Euclidean distances.
+    pub fn normalize(&self) -> Plane {
+        let length = self.normal.length();
+        Plane {
+            normal: (1.0 / length) * self.normal,
+            d: self.d / length,
+        }
+    }
+
+    /// Returns the signed distance from the plane to the given point.
+    ///
+    /// The result is only a true distance if the plane has been normalized,
+    /// otherwise it is scaled by the length of the normal.
+    pub fn distance_to_point(&self, point: &Vec3) -> f32 {
+        (self.normal * *point) + self.d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Plane, Vec3, Vec4};
+
+    #[test]
+    fn create_new_plane() {
+        assert_eq!(Plane::new(), Plane { normal: Vec3::new(), d: 0.0 });
+    }
+
+    #[test]
+    fn create_new_plane_from_vec4() {
+        let vec4 = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(
+            Plane::new_from_vec4(&vec4),
+            Plane { normal: Vec3 { x: 1.0, y: 2.0, z: 3.0 }, d: 4.0 }
+        );
+    }
+
+    #[test]
+    fn create_new_plane_from_point_normal() {
+        let point = Vec3 { x: 0.0, y: 2.0, z: 0.0 };
+        let normal = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+        let plane = Plane::new_from_point_normal(&point, &normal);
+        assert_eq!(plane, Plane { normal, d: -2.0 });
+    }
+
+    #[test]
+    fn normalize_plane() {
+        let plane = Plane { normal: Vec3 { x: 0.0, y: 0.0, z: 4.0 }, d: 10.0 };
+        assert_eq!(
+            plane.normalize(),
+            Plane { normal: Vec3 { x: 0.0, y: 0.0, z: 1.0 }, d: 2.5 }
+        );
+    }
+
+    #[test]
+    fn get_distance_to_point() {
+        let plane = Plane { normal: Vec3 { x: 0.0, y: 1.0, z: 0.0 }, d: -2.0 };
+        assert_eq!(plane.distance_to_point(&Vec3 { x: 5.0, y: 5.0, z: 1.0 }), 3.0);
+        assert_eq!(plane.distance_to_point(&Vec3 { x: 5.0, y: 0.0, z: 1.0 }), -2.0);
+    }
+}
@@ -0,0 +1,509 @@
+//! Robust geometric predicates with exact signs, after Jonathan Shewchuk's
+//! "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric
+//! Predicates".
+//!
+//! Each predicate first evaluates its determinant in f64 and returns
+//! straight away if the result is further from 0 than Shewchuk's bound on
+//! the rounding error, which is almost always. Otherwise it evaluates the
+//! determinant again exactly, using expansion arithmetic, in which a number
+//! is held as a sum of non-overlapping f64 components. Shewchuk's
+//! intermediate stages between the two are not implemented.
+//!
+//! The f32 inputs are converted to f64 exactly, and an f64 has room for
+//! every product the predicates form, so the signs are exact for all finite
+//! inputs.
+
+use super::{Vec2, Vec3};
+
+/// The sign of a determinant computed by one of the robust predicates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+impl Sign {
+    fn of(value: f64) -> Sign {
+        if value > 0.0 {
+            Sign::Positive
+        } else if value < 0.0 {
+            Sign::Negative
+        } else {
+            Sign::Zero
+        }
+    }
+}
+
+/// Half the distance between 1 and the next f64, the largest relative error
+/// of a single rounded operation.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const INSPHERE_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Returns the sign of `det` if it is further from 0 than the error bound
+/// for a determinant whose terms have absolute values summing to
+/// `permanent`.
+fn filter(det: f64, permanent: f64, bound: f64) -> Option<Sign> {
+    let error = bound * permanent;
+    if det > error || -det > error {
+        Some(Sign::of(det))
+    } else {
+        None
+    }
+}
+
+/// Returns whether `c` is to the left of, to the right of, or on the
+/// directed line from `a` to `b`.
+///
+/// The result is `Positive` if `a`, `b` and `c` are in counter-clockwise
+/// order, `Negative` if they are clockwise and `Zero` if they are collinear.
+///
+/// Example:
+/// ```
+/// # use matriarch::{orient2d, Sign, Vec2};
+/// let a = Vec2 { x: 0.5, y: 0.5 };
+/// let b = Vec2 { x: 12.0, y: 12.0 };
+/// let c = Vec2 { x: 24.0, y: 24.0 };
+/// assert_eq!(orient2d(&a, &b, &c), Sign::Zero);
+/// ```
+pub fn orient2d(a: &Vec2, b: &Vec2, c: &Vec2) -> Sign {
+    let (ax, ay) = (a.x as f64, a.y as f64);
+    let (bx, by) = (b.x as f64, b.y as f64);
+    let (cx, cy) = (c.x as f64, c.y as f64);
+
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    if let Some(sign) = filter(left - right, left.abs() + right.abs(), ORIENT2D_BOUND) {
+        return sign;
+    }
+
+    let acx = difference(ax, cx);
+    let acy = difference(ay, cy);
+    let bcx = difference(bx, cx);
+    let bcy = difference(by, cy);
+    sign(&sum(&product(&acx, &bcy), &negate(&product(&acy, &bcx))))
+}
+
+/// Returns which side of the plane through `a`, `b` and `c` the point `d`
+/// is on.
+///
+/// The result is `Positive` if `d` is below the plane, where above is the
+/// side from which `a`, `b` and `c` appear in counter-clockwise order,
+/// `Negative` if it is above and `Zero` if the four points are coplanar.
+pub fn orient3d(a: &Vec3, b: &Vec3, c: &Vec3, d: &Vec3) -> Sign {
+    let (adx, ady, adz) = (a.x as f64 - d.x as f64, a.y as f64 - d.y as f64, a.z as f64 - d.z as f64);
+    let (bdx, bdy, bdz) = (b.x as f64 - d.x as f64, b.y as f64 - d.y as f64, b.z as f64 - d.z as f64);
+    let (cdx, cdy, cdz) = (c.x as f64 - d.x as f64, c.y as f64 - d.y as f64, c.z as f64 - d.z as f64);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+        + (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+        + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+    if let Some(sign) = filter(det, permanent, ORIENT3D_BOUND) {
+        return sign;
+    }
+
+    let [adx, ady, adz] = differences3(a, d);
+    let [bdx, bdy, bdz] = differences3(b, d);
+    let [cdx, cdy, cdz] = differences3(c, d);
+    let bc = cross_term(&bdx, &cdy, &cdx, &bdy);
+    let ca = cross_term(&cdx, &ady, &adx, &cdy);
+    let ab = cross_term(&adx, &bdy, &bdx, &ady);
+    sign(&sum(&sum(&product(&adz, &bc), &product(&bdz, &ca)), &product(&cdz, &ab)))
+}
+
+/// Returns whether `d` is inside, outside or on the circle through `a`, `b`
+/// and `c`, which must be in counter-clockwise order.
+///
+/// The result is `Positive` if `d` is inside, `Negative` if it is outside
+/// and `Zero` if the four points are cocircular. The signs are reversed if
+/// `a`, `b` and `c` are clockwise.
+pub fn incircle(a: &Vec2, b: &Vec2, c: &Vec2, d: &Vec2) -> Sign {
+    let (adx, ady) = (a.x as f64 - d.x as f64, a.y as f64 - d.y as f64);
+    let (bdx, bdy) = (b.x as f64 - d.x as f64, b.y as f64 - d.y as f64);
+    let (cdx, cdy) = (c.x as f64 - d.x as f64, c.y as f64 - d.y as f64);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if let Some(sign) = filter(det, permanent, INCIRCLE_BOUND) {
+        return sign;
+    }
+
+    let [adx, ady] = differences2(a, d);
+    let [bdx, bdy] = differences2(b, d);
+    let [cdx, cdy] = differences2(c, d);
+    let alift = sum(&product(&adx, &adx), &product(&ady, &ady));
+    let blift = sum(&product(&bdx, &bdx), &product(&bdy, &bdy));
+    let clift = sum(&product(&cdx, &cdx), &product(&cdy, &cdy));
+    let bc = cross_term(&bdx, &cdy, &cdx, &bdy);
+    let ca = cross_term(&cdx, &ady, &adx, &cdy);
+    let ab = cross_term(&adx, &bdy, &bdx, &ady);
+    sign(&sum(&sum(&product(&alift, &bc), &product(&blift, &ca)), &product(&clift, &ab)))
+}
+
+/// Returns whether `e` is inside, outside or on the sphere through `a`,
+/// `b`, `c` and `d`, which must be positively oriented according to
+/// `orient3d`.
+///
+/// The result is `Positive` if `e` is inside, `Negative` if it is outside
+/// and `Zero` if the five points are cospherical. The signs are reversed if
+/// `a`, `b`, `c` and `d` are negatively oriented.
+pub fn insphere(a: &Vec3, b: &Vec3, c: &Vec3, d: &Vec3, e: &Vec3) -> Sign {
+    let (aex, aey, aez) = (a.x as f64 - e.x as f64, a.y as f64 - e.y as f64, a.z as f64 - e.z as f64);
+    let (bex, bey, bez) = (b.x as f64 - e.x as f64, b.y as f64 - e.y as f64, b.z as f64 - e.z as f64);
+    let (cex, cey, cez) = (c.x as f64 - e.x as f64, c.y as f64 - e.y as f64, c.z as f64 - e.z as f64);
+    let (dex, dey, dez) = (d.x as f64 - e.x as f64, d.y as f64 - e.y as f64, d.z as f64 - e.z as f64);
+
+    let (aexbey, bexaey) = (aex * bey, bex * aey);
+    let (bexcey, cexbey) = (bex * cey, cex * bey);
+    let (cexdey, dexcey) = (cex * dey, dex * cey);
+    let (dexaey, aexdey) = (dex * aey, aex * dey);
+    let (aexcey, cexaey) = (aex * cey, cex * aey);
+    let (bexdey, dexbey) = (bex * dey, dex * bey);
+    let ab = aexbey - bexaey;
+    let bc = bexcey - cexbey;
+    let cd = cexdey - dexcey;
+    let da = dexaey - aexdey;
+    let ac = aexcey - cexaey;
+    let bd = bexdey - dexbey;
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (aez.abs(), bez.abs(), cez.abs(), dez.abs());
+    let permanent = ((cexdey.abs() + dexcey.abs()) * bez
+        + (dexbey.abs() + bexdey.abs()) * cez
+        + (bexcey.abs() + cexbey.abs()) * dez)
+        * alift
+        + ((dexaey.abs() + aexdey.abs()) * cez
+            + (aexcey.abs() + cexaey.abs()) * dez
+            + (cexdey.abs() + dexcey.abs()) * aez)
+            * blift
+        + ((aexbey.abs() + bexaey.abs()) * dez
+            + (bexdey.abs() + dexbey.abs()) * aez
+            + (dexaey.abs() + aexdey.abs()) * bez)
+            * clift
+        + ((bexcey.abs() + cexbey.abs()) * aez
+            + (cexaey.abs() + aexcey.abs()) * bez
+            + (aexbey.abs() + bexaey.abs()) * cez)
+            * dlift;
+    if let Some(sign) = filter(det, permanent, INSPHERE_BOUND) {
+        return sign;
+    }
+
+    let [aex, aey, aez] = differences3(a, e);
+    let [bex, bey, bez] = differences3(b, e);
+    let [cex, cey, cez] = differences3(c, e);
+    let [dex, dey, dez] = differences3(d, e);
+    let ab = cross_term(&aex, &bey, &bex, &aey);
+    let bc = cross_term(&bex, &cey, &cex, &bey);
+    let cd = cross_term(&cex, &dey, &dex, &cey);
+    let da = cross_term(&dex, &aey, &aex, &dey);
+    let ac = cross_term(&aex, &cey, &cex, &aey);
+    let bd = cross_term(&bex, &dey, &dex, &bey);
+    let abc = sum(&sum(&product(&aez, &bc), &negate(&product(&bez, &ac))), &product(&cez, &ab));
+    let bcd = sum(&sum(&product(&bez, &cd), &negate(&product(&cez, &bd))), &product(&dez, &bc));
+    let cda = sum(&sum(&product(&cez, &da), &product(&dez, &ac)), &product(&aez, &cd));
+    let dab = sum(&sum(&product(&dez, &ab), &product(&aez, &bd)), &product(&bez, &da));
+    let lift = |x: &[f64], y: &[f64], z: &[f64]| sum(&sum(&product(x, x), &product(y, y)), &product(z, z));
+    let alift = lift(&aex, &aey, &aez);
+    let blift = lift(&bex, &bey, &bez);
+    let clift = lift(&cex, &cey, &cez);
+    let dlift = lift(&dex, &dey, &dez);
+    let left = sum(&product(&dlift, &abc), &negate(&product(&clift, &dab)));
+    let right = sum(&product(&blift, &cda), &negate(&product(&alift, &bcd)));
+    sign(&sum(&left, &right))
+}
+
+// Expansion arithmetic. An expansion is a Vec of f64 components ordered by
+// increasing magnitude, none of which overlap in the bits they use and none
+// of which are 0, whose exact sum is the number represented.
+
+/// Returns `a + b` as a rounded sum and the exact rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Returns `a * b` as a rounded product and the exact rounding error, which
+/// a fused multiply-add computes in one step.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// Returns the exact difference `a - b` as an expansion.
+fn difference(a: f64, b: f64) -> Vec<f64> {
+    let (difference, error) = two_sum(a, -b);
+    let mut expansion = Vec::with_capacity(2);
+    if error != 0.0 {
+        expansion.push(error);
+    }
+    if difference != 0.0 {
+        expansion.push(difference);
+    }
+    expansion
+}
+
+fn differences2(a: &Vec2, b: &Vec2) -> [Vec<f64>; 2] {
+    [difference(a.x as f64, b.x as f64), difference(a.y as f64, b.y as f64)]
+}
+
+fn differences3(a: &Vec3, b: &Vec3) -> [Vec<f64>; 3] {
+    [
+        difference(a.x as f64, b.x as f64),
+        difference(a.y as f64, b.y as f64),
+        difference(a.z as f64, b.z as f64),
+    ]
+}
+
+/// Returns the expansion plus a single f64.
+fn grow(expansion: &[f64], value: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(expansion.len() + 1);
+    let mut carry = value;
+    for component in expansion {
+        let (sum, error) = two_sum(carry, *component);
+        if error != 0.0 {
+            result.push(error);
+        }
+        carry = sum;
+    }
+    if carry != 0.0 {
+        result.push(carry);
+    }
+    result
+}
+
+/// Returns the exact sum of two expansions.
+fn sum(a: &[f64], b: &[f64]) -> Vec<f64> {
+    b.iter().fold(a.to_vec(), |total, component| grow(&total, *component))
+}
+
+/// Returns the expansion multiplied by a single f64.
+fn scale(expansion: &[f64], value: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(2 * expansion.len());
+    let mut carry = 0.0;
+    for component in expansion {
+        let (product, product_error) = two_product(*component, value);
+        let (sum, sum_error) = two_sum(carry, product_error);
+        if sum_error != 0.0 {
+            result.push(sum_error);
+        }
+        let (next, error) = two_sum(product, sum);
+        if error != 0.0 {
+            result.push(error);
+        }
+        carry = next;
+    }
+    if carry != 0.0 {
+        result.push(carry);
+    }
+    result
+}
+
+/// Returns the exact product of two expansions.
+fn product(a: &[f64], b: &[f64]) -> Vec<f64> {
+    b.iter().fold(Vec::new(), |total, component| sum(&total, &scale(a, *component)))
+}
+
+fn negate(expansion: &[f64]) -> Vec<f64> {
+    expansion.iter().map(|component| -component).collect()
+}
+
+/// Returns `a * b - c * d` exactly.
+fn cross_term(a: &[f64], b: &[f64], c: &[f64], d: &[f64]) -> Vec<f64> {
+    sum(&product(a, b), &negate(&product(c, d)))
+}
+
+/// Returns the sign of an expansion, which is the sign of its largest
+/// component.
+fn sign(expansion: &[f64]) -> Sign {
+    Sign::of(expansion.last().cloned().unwrap_or(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incircle, insphere, orient2d, orient3d, Sign, Vec2, Vec3};
+    use crate::test_values::integers;
+
+    fn sign_of(value: i128) -> Sign {
+        match value {
+            v if v > 0 => Sign::Positive,
+            v if v < 0 => Sign::Negative,
+            _ => Sign::Zero,
+        }
+    }
+
+    /// Converts integer coordinates to floats scaled by a power of two, so
+    /// that the exact sign can be computed with the integers.
+    fn to_float(value: i64) -> f32 {
+        value as f32 / 1024.0
+    }
+
+    #[test]
+    fn orient2d_near_collinear() {
+        // Points just off the line y = x, one f32 unit apart. Shewchuk's
+        // classic example that f32 arithmetic gets wrong.
+        let b = Vec2 { x: 12.0, y: 12.0 };
+        let c = Vec2 { x: 24.0, y: 24.0 };
+        let ulp = f32::EPSILON / 2.0;
+        let mut naive_mistakes = 0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let a = Vec2 { x: 0.5 + i as f32 * ulp, y: 0.5 + j as f32 * ulp };
+                let expected = sign_of(j as i128 - i as i128);
+                assert_eq!(orient2d(&a, &b, &c), expected, "i = {}, j = {}", i, j);
+                let naive = (a - c).cross_product(&(b - c)).z;
+                if super::Sign::of(-naive as f64) != expected {
+                    naive_mistakes += 1;
+                }
+            }
+        }
+        assert!(naive_mistakes > 0);
+    }
+
+    #[test]
+    fn orient2d_matches_integer_arithmetic() {
+        let values = integers(6 * 200, 1, 1 << 20);
+        for p in values.chunks(6) {
+            // Make half the triangles exactly degenerate.
+            let (cx, cy) = if p[0] % 2 == 0 { (2 * p[2] - p[0], 2 * p[3] - p[1]) } else { (p[4], p[5]) };
+            let a = Vec2 { x: to_float(p[0]), y: to_float(p[1]) };
+            let b = Vec2 { x: to_float(p[2]), y: to_float(p[3]) };
+            let c = Vec2 { x: to_float(cx), y: to_float(cy) };
+            let (ax, ay, bx, by, cx, cy) = (p[0] as i128, p[1] as i128, p[2] as i128, p[3] as i128, cx as i128, cy as i128);
+            let expected = (ax - cx) * (by - cy) - (ay - cy) * (bx - cx);
+            assert_eq!(orient2d(&a, &b, &c), sign_of(expected));
+        }
+    }
+
+    #[test]
+    fn orient3d_matches_integer_arithmetic() {
+        let values = integers(12 * 200, 2, 1 << 20);
+        for p in values.chunks(12) {
+            let q: Vec<i128> = p.iter().map(|v| *v as i128).collect();
+            // Make half the tetrahedra exactly flat by putting d on the plane.
+            let d: [i128; 3] = if p[0] % 2 == 0 {
+                [q[0] + q[3] - q[6], q[1] + q[4] - q[7], q[2] + q[5] - q[8]]
+            } else {
+                [q[9], q[10], q[11]]
+            };
+            let point = |x: i128, y: i128, z: i128| Vec3 { x: to_float(x as i64), y: to_float(y as i64), z: to_float(z as i64) };
+            let (a, b, c) = (point(q[0], q[1], q[2]), point(q[3], q[4], q[5]), point(q[6], q[7], q[8]));
+            let (adx, ady, adz) = (q[0] - d[0], q[1] - d[1], q[2] - d[2]);
+            let (bdx, bdy, bdz) = (q[3] - d[0], q[4] - d[1], q[5] - d[2]);
+            let (cdx, cdy, cdz) = (q[6] - d[0], q[7] - d[1], q[8] - d[2]);
+            let expected = adx * (bdy * cdz - bdz * cdy) + bdx * (cdy * adz - cdz * ady) + cdx * (ady * bdz - adz * bdy);
+            assert_eq!(orient3d(&a, &b, &c, &point(d[0], d[1], d[2])), sign_of(expected));
+        }
+        let a = Vec3 { x: 0.0, y: 0.0, z: 0.0 };
+        let b = Vec3 { x: 1.0, y: 0.0, z: 0.0 };
+        let c = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+        assert_eq!(orient3d(&a, &b, &c, &Vec3 { x: 0.0, y: 0.0, z: -1.0 }), Sign::Positive);
+    }
+
+    #[test]
+    fn incircle_cocircular_points() {
+        // Points on a circle of radius 5 with integer coordinates, shifted
+        // so that their differences are inexact in f32 arithmetic.
+        let offset = Vec2 { x: 1000.125, y: -300.0625 };
+        let points: Vec<Vec2> = [(3.0, 4.0), (-4.0, 3.0), (-5.0, 0.0), (0.0, -5.0), (4.0, -3.0)]
+            .iter()
+            .map(|(x, y)| Vec2 { x: x + offset.x, y: y + offset.y })
+            .collect();
+        for d in &points[3..] {
+            assert_eq!(incircle(&points[0], &points[1], &points[2], d), Sign::Zero);
+        }
+        assert_eq!(incircle(&points[0], &points[1], &points[2], &offset), Sign::Positive);
+        let outside = Vec2 { x: offset.x + 5.0, y: offset.y + 0.001 };
+        assert_eq!(incircle(&points[0], &points[1], &points[2], &outside), Sign::Negative);
+    }
+
+    #[test]
+    fn incircle_matches_integer_arithmetic() {
+        let values = integers(8 * 200, 3, 1 << 20);
+        for p in values.chunks(8) {
+            let q: Vec<i128> = p.iter().map(|v| *v as i128).collect();
+            let point = |x: i128, y: i128| Vec2 { x: to_float(x as i64), y: to_float(y as i64) };
+            let (adx, ady) = (q[0] - q[6], q[1] - q[7]);
+            let (bdx, bdy) = (q[2] - q[6], q[3] - q[7]);
+            let (cdx, cdy) = (q[4] - q[6], q[5] - q[7]);
+            let expected = (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy)
+                + (bdx * bdx + bdy * bdy) * (cdx * ady - adx * cdy)
+                + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady);
+            let sign = incircle(&point(q[0], q[1]), &point(q[2], q[3]), &point(q[4], q[5]), &point(q[6], q[7]));
+            assert_eq!(sign, sign_of(expected));
+        }
+    }
+
+    #[test]
+    fn insphere_cospherical_points() {
+        // Points on a sphere of radius 3 with integer coordinates, shifted so
+        // that their differences are inexact in f32 arithmetic.
+        let offset = Vec3 { x: 512.25, y: -77.125, z: 3000.5 };
+        let points: Vec<Vec3> = [(1.0, 2.0, 2.0), (2.0, -1.0, 2.0), (-2.0, 2.0, 1.0), (2.0, 2.0, -1.0), (0.0, 0.0, -3.0)]
+            .iter()
+            .map(|(x, y, z)| Vec3 { x: x + offset.x, y: y + offset.y, z: z + offset.z })
+            .collect();
+        let (a, b, c, d) = if orient3d(&points[0], &points[1], &points[2], &points[3]) == Sign::Positive {
+            (points[0], points[1], points[2], points[3])
+        } else {
+            (points[1], points[0], points[2], points[3])
+        };
+        assert_eq!(insphere(&a, &b, &c, &d, &points[4]), Sign::Zero);
+        assert_eq!(insphere(&a, &b, &c, &d, &offset), Sign::Positive);
+        let outside = Vec3 { x: offset.x, y: offset.y, z: offset.z - 3.001 };
+        assert_eq!(insphere(&a, &b, &c, &d, &outside), Sign::Negative);
+    }
+
+    #[test]
+    fn insphere_matches_integer_arithmetic() {
+        let values = integers(15 * 100, 4, 1 << 16);
+        for p in values.chunks(15) {
+            let q: Vec<i128> = p.iter().map(|v| *v as i128).collect();
+            let point = |i: usize| Vec3 { x: to_float(p[i]), y: to_float(p[i + 1]), z: to_float(p[i + 2]) };
+            let e = |i: usize, axis: usize| q[i + axis] - q[12 + axis];
+            let (aex, aey, aez) = (e(0, 0), e(0, 1), e(0, 2));
+            let (bex, bey, bez) = (e(3, 0), e(3, 1), e(3, 2));
+            let (cex, cey, cez) = (e(6, 0), e(6, 1), e(6, 2));
+            let (dex, dey, dez) = (e(9, 0), e(9, 1), e(9, 2));
+            let ab = aex * bey - bex * aey;
+            let bc = bex * cey - cex * bey;
+            let cd = cex * dey - dex * cey;
+            let da = dex * aey - aex * dey;
+            let ac = aex * cey - cex * aey;
+            let bd = bex * dey - dex * bey;
+            let abc = aez * bc - bez * ac + cez * ab;
+            let bcd = bez * cd - cez * bd + dez * bc;
+            let cda = cez * da + dez * ac + aez * cd;
+            let dab = dez * ab + aez * bd + bez * da;
+            let lift = |x: i128, y: i128, z: i128| x * x + y * y + z * z;
+            let expected = (lift(dex, dey, dez) * abc - lift(cex, cey, cez) * dab)
+                + (lift(bex, bey, bez) * cda - lift(aex, aey, aez) * bcd);
+            let sign = insphere(&point(0), &point(3), &point(6), &point(9), &point(12));
+            assert_eq!(sign, sign_of(expected));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Implementation of a rotation Quaternion and its associated functions and
+//! methods.
+
+use std::ops;
+
+use super::{Mat3, Vec3};
+
+/// A Quaternion `w + xi + yj + zk` with elements x, y, z, and w.
+///
+/// Unit quaternions represent rotations, and multiplying two of them composes
+/// the rotations in the same order as multiplying their Mat3s.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// Returns a new Quat at [0, 0, 0, 0].
+    pub fn new() -> Quat {
+        Quat { x: 0.0, y: 0.0, z: 0.0, w: 0.0 }
+    }
+
+    /// Returns the Quat representing no rotation, [0, 0, 0, 1].
+    pub fn identity() -> Quat {
+        Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }
+    }
+
+    /// Returns a new Quat using the given values for x, y, z, and w.
+    pub fn new_from_values(x: &f32, y: &f32, z: &f32, w: &f32) -> Quat {
+        Quat { x: *x, y: *y, z: *z, w: *w }
+    }
+
+    /// Returns a Quat rotating counter-clockwise by `angle` radians about the
+    /// given axis, which must have a length of 1.
+    pub fn new_from_axis_angle(axis: &Vec3, angle: f32) -> Quat {
+        let (sin, cos) = (0.5 * angle).sin_cos();
+        Quat { x: axis.x * sin, y: axis.y * sin, z: axis.z * sin, w: cos }
+    }
+
+    /// Returns the unit Quat with the same rotation as the given rotation
+    /// matrix.
+    ///
+    /// The matrix must be orthonormal with a determinant of 1.
+    pub fn new_from_mat3(mat3: &Mat3) -> Quat {
+        // Shepperd's method, dividing by the largest of the four
+        // candidates to keep the result accurate for every rotation.
+        let trace = mat3.a + mat3.e + mat3.i;
+        let quat = if trace > 0.0 {
+            let s = 2.0 * (trace + 1.0).sqrt();
+            Quat { x: (mat3.h - mat3.f) / s, y: (mat3.c - mat3.g) / s, z: (mat3.d - mat3.b) / s, w: 0.25 * s }
+        } else if mat3.a > mat3.e && mat3.a > mat3.i {
+            let s = 2.0 * (1.0 + mat3.a - mat3.e - mat3.i).sqrt();
+            Quat { x: 0.25 * s, y: (mat3.b + mat3.d) / s, z: (mat3.c + mat3.g) / s, w: (mat3.h - mat3.f) / s }
+        } else if mat3.e > mat3.i {
+            let s = 2.0 * (1.0 + mat3.e - mat3.a - mat3.i).sqrt();
+            Quat { x: (mat3.b + mat3.d) / s, y: 0.25 * s, z: (mat3.f + mat3.h) / s, w: (mat3.c - mat3.g) / s }
+        } else {
+            let s = 2.0 * (1.0 + mat3.i - mat3.a - mat3.e).sqrt();
+            Quat { x: (mat3.c + mat3.g) / s, y: (mat3.f + mat3.h) / s, z: 0.25 * s, w: (mat3.d - mat3.b) / s }
+        };
+        quat.normalize()
+    }
+
+    /// Returns the rotation matrix of a unit Quat.
+    pub fn to_mat3(&self) -> Mat3 {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        Mat3 {
+            a: 1.0 - 2.0 * (y * y + z * z), b: 2.0 * (x * y - w * z),       c: 2.0 * (x * z + w * y),
+            d: 2.0 * (x * y + w * z),       e: 1.0 - 2.0 * (x * x + z * z), f: 2.0 * (y * z - w * x),
+            g: 2.0 * (x * z - w * y),       h: 2.0 * (y * z + w * x),       i: 1.0 - 2.0 * (x * x + y * y)
+        }
+    }
+
+    /// Returns the x, y and z components as a Vec3.
+    pub fn vector_part(&self) -> Vec3 {
+        Vec3 { x: self.x, y: self.y, z: self.z }
+    }
+
+    /// Returns the conjugate of the Quat, which for a unit Quat is the
+    /// inverse rotation.
+    pub fn conjugate(&self) -> Quat {
+        Quat { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// Returns the dot product of the two Quats' components.
+    pub fn dot(&self, other: &Quat) -> f32 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+    }
+
+    /// Returns the length of the Quat.
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the Quat scaled to a length of 1.
+    pub fn normalize(&self) -> Quat {
+        (1.0 / self.length()) * *self
+    }
+
+    /// Spherically interpolates between two unit Quats along the shortest
+    /// path, returning `self` at `t = 0` and `other` at `t = 1`.
+    pub fn slerp(&self, other: &Quat, t: f32) -> Quat {
+        let mut cos = self.dot(other);
+        let mut other = *other;
+        if cos < 0.0 {
+            cos = -cos;
+            other = -other;
+        }
+        // Nearly parallel rotations fall back to a normalized lerp, as the
+        // sine of the angle between them is too small to divide by.
+        if cos > 1.0 - 1e-6 {
+            return (*self + t * (other - *self)).normalize();
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let from = ((1.0 - t) * angle).sin() / sin;
+        let to = (t * angle).sin() / sin;
+        from * *self + to * other
+    }
+}
+
+impl ops::Add<Quat> for Quat {
+    type Output = Quat;
+
+    fn add(self, other: Quat) -> Quat {
+        Quat { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z, w: self.w + other.w }
+    }
+}
+
+impl ops::Sub<Quat> for Quat {
+    type Output = Quat;
+
+    fn sub(self, other: Quat) -> Quat {
+        Quat { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z, w: self.w - other.w }
+    }
+}
+
+impl ops::Neg for Quat {
+    type Output = Quat;
+
+    fn neg(self) -> Quat {
+        Quat { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+impl ops::Mul<Quat> for f32 {
+    type Output = Quat;
+
+    /// Multiplies a scalar value by each component of the Quat.
+    fn mul(self, quat: Quat) -> Quat {
+        Quat { x: self * quat.x, y: self * quat.y, z: self * quat.z, w: self * quat.w }
+    }
+}
+
+impl ops::Mul<Quat> for Quat {
+    type Output = Quat;
+
+    /// Returns the Hamilton product of two Quats. For unit Quats, `a * b`
+    /// rotates by `b` and then by `a`.
+    fn mul(self, other: Quat) -> Quat {
+        Quat {
+            x: (self.w * other.x) + (self.x * other.w) + (self.y * other.z) - (self.z * other.y),
+            y: (self.w * other.y) - (self.x * other.z) + (self.y * other.w) + (self.z * other.x),
+            z: (self.w * other.z) + (self.x * other.y) - (self.y * other.x) + (self.z * other.w),
+            w: (self.w * other.w) - (self.x * other.x) - (self.y * other.y) - (self.z * other.z),
+        }
+    }
+}
+
+impl ops::Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    /// Rotates the Vec3 by a unit Quat.
+    fn mul(self, vec3: Vec3) -> Vec3 {
+        let axis = self.vector_part();
+        let t = 2.0 * axis.cross_product(&vec3);
+        vec3 + self.w * t + axis.cross_product(&t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{Mat3, Quat, Vec3};
+    use crate::test_values::assert_mat3_near;
+
+    #[test]
+    fn axis_angle_matches_mat3() {
+        let quat = Quat::new_from_axis_angle(&Vec3 { x: 0.0, y: 0.0, z: 1.0 }, 0.5 * PI);
+        assert_mat3_near(&quat.to_mat3(), &Mat3::new_from_rotation_z(0.5 * PI));
+        let rotated = quat * Vec3 { x: 1.0, y: 0.0, z: 0.0 };
+        assert!((rotated - Vec3 { x: 0.0, y: 1.0, z: 0.0 }).length() < 1e-6);
+    }
+
+    #[test]
+    fn mat3_round_trip() {
+        // Each rotation exercises a different branch of Shepperd's method.
+        for mat3 in [
+            Mat3::new_from_rotation_x(0.3) * Mat3::new_from_rotation_y(-1.1),
+            Mat3::new_from_rotation_x(3.0),
+            Mat3::new_from_rotation_y(3.0),
+            Mat3::new_from_rotation_z(3.0),
+        ]
+        .iter()
+        {
+            assert_mat3_near(&Quat::new_from_mat3(mat3).to_mat3(), mat3);
+        }
+    }
+
+    #[test]
+    fn multiplication_composes_rotations() {
+        let a = Mat3::new_from_rotation_x(0.4);
+        let b = Mat3::new_from_rotation_y(1.2);
+        let product = Quat::new_from_mat3(&a) * Quat::new_from_mat3(&b);
+        assert_mat3_near(&product.to_mat3(), &(a * b));
+        let inverse = product * product.conjugate();
+        assert!((inverse - Quat::identity()).length() < 1e-6);
+    }
+
+    #[test]
+    fn slerp_takes_shortest_path() {
+        let axis = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
+        let a = Quat::new_from_axis_angle(&axis, 0.2);
+        let b = Quat::new_from_axis_angle(&axis, 1.0);
+        let halfway = Quat::new_from_axis_angle(&axis, 0.6);
+        assert!((a.slerp(&b, 0.5) - halfway).length() < 1e-6);
+        assert!((a.slerp(&-b, 0.5) - halfway).length() < 1e-6);
+        assert_eq!(a.slerp(&a, 0.5), a);
+    }
+}
@@ -0,0 +1,82 @@
+//! Implementation of a Ray and its associated functions and methods.
+
+use super::{Mat4, Vec2, Vec3, Vec4};
+
+/// A half-line starting at `origin` and extending along `direction`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Returns a new Ray using the given origin and direction.
+    pub fn new_from_values(origin: &Vec3, direction: &Vec3) -> Ray {
+        Ray { origin: *origin, direction: *direction }
+    }
+
+    /// Returns the point at distance `t` along the Ray, measured in multiples
+    /// of the direction's length.
+    pub fn point_at(&self, t: f32) -> Vec3 {
+        self.origin + (t * self.direction)
+    }
+}
+
+/// Returns the world space Ray passing through a point on the screen, which is
+/// useful for picking objects with the mouse.
+///
+/// `window_coord` uses the same bottom-left origin as `Mat4::unproject`, so
+/// window systems with a top-left origin need to flip y first. The Ray starts
+/// on the near plane and has a normalized direction pointing towards the far
+/// plane. Returns `None` if `projection * view` can't be inverted.
+///
+/// Example:
+/// ```
+/// # use matriarch::{screen_point_to_ray, Mat4, Vec2, Vec4};
+/// let viewport = Vec4 { x: 0.0, y: 0.0, z: 800.0, w: 600.0 };
+/// let mouse = Vec2 { x: 400.0, y: 300.0 };
+/// let ray = screen_point_to_ray(&mouse, &viewport, &Mat4::identity(), &Mat4::identity());
+/// ```
+pub fn screen_point_to_ray(window_coord: &Vec2, viewport: &Vec4, view: &Mat4, projection: &Mat4) -> Option<Ray> {
+    let view_projection = *projection * *view;
+    let near = view_projection.unproject(&Vec3 { x: window_coord.x, y: window_coord.y, z: 0.0 }, viewport)?;
+    let far = view_projection.unproject(&Vec3 { x: window_coord.x, y: window_coord.y, z: 1.0 }, viewport)?;
+
+    Some(Ray { origin: near, direction: (far - near).normalize() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{screen_point_to_ray, Mat4, Ray, Vec2, Vec3, Vec4};
+
+    #[test]
+    fn get_point_along_ray() {
+        let ray = Ray {
+            origin: Vec3 { x: 1.0, y: 0.0, z: 0.0 },
+            direction: Vec3 { x: 0.0, y: 0.0, z: -1.0 },
+        };
+        assert_eq!(ray.point_at(2.5), Vec3 { x: 1.0, y: 0.0, z: -2.5 });
+    }
+
+    #[test]
+    fn screen_point_to_ray_through_perspective() {
+        let projection = Mat4 {
+            a: 1.0, b: 0.0, c:  0.0,        d:  0.0,
+            e: 0.0, f: 1.0, g:  0.0,        h:  0.0,
+            i: 0.0, j: 0.0, k: -11.0 / 9.0, l: -20.0 / 9.0,
+            m: 0.0, n: 0.0, o: -1.0,        p:  0.0
+        };
+        let viewport = Vec4 { x: 0.0, y: 0.0, z: 800.0, w: 600.0 };
+
+        let center = Vec2 { x: 400.0, y: 300.0 };
+        let ray = screen_point_to_ray(&center, &viewport, &Mat4::identity(), &projection).unwrap();
+        assert!((ray.origin - Vec3 { x: 0.0, y: 0.0, z: -1.0 }).length() < 1e-4);
+        assert!((ray.direction - Vec3 { x: 0.0, y: 0.0, z: -1.0 }).length() < 1e-4);
+
+        // The right edge of a 90 degree frustum is at 45 degrees.
+        let right_edge = Vec2 { x: 800.0, y: 300.0 };
+        let ray = screen_point_to_ray(&right_edge, &viewport, &Mat4::identity(), &projection).unwrap();
+        let expected = Vec3 { x: 1.0, y: 0.0, z: -1.0 }.normalize();
+        assert!((ray.direction - expected).length() < 1e-4);
+    }
+}
@@ -0,0 +1,70 @@
+//! The trait for the component types of vectors and matrices.
+
+use std::fmt::Debug;
+use std::ops;
+
+/// A number that vectors and matrices can be built from.
+///
+/// Every vector and matrix defaults to `f32` components, and their
+/// operators, constructors and methods work for any `Scalar`, such as `Dual`
+/// or `Interval`. The exceptions are `new` and `identity`, which take no
+/// arguments and so are only implemented for `f32` to keep `Vec3::new()`
+/// from needing a type annotation, and the rotation constructors, which need
+/// trigonometry that `Scalar` doesn't provide. Use `Default` or `to_dual`
+/// and `to_interval` to build the others.
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Neg<Output = Self>
+{
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Returns the given `f32` as a constant of this type.
+    fn from_f32(value: f32) -> Self;
+
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+
+    /// Returns true if dividing by the value is undefined.
+    fn is_zero(self) -> bool;
+
+    /// Returns `1 / self`.
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+}
+
+impl Scalar for f32 {
+    fn zero() -> f32 {
+        0.0
+    }
+
+    fn one() -> f32 {
+        1.0
+    }
+
+    fn from_f32(value: f32) -> f32 {
+        value
+    }
+
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+
+    fn is_zero(self) -> bool {
+        self == 0.0
+    }
+
+    fn recip(self) -> f32 {
+        f32::recip(self)
+    }
+}
@@ -0,0 +1,303 @@
+//! Implementation of a bounding Sphere and its associated functions and
+//! methods.
+
+use super::{Aabb, Vec3};
+
+/// A sphere with the given center and radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Returns a new Sphere using the given center and radius.
+    pub fn new_from_values(center: &Vec3, radius: &f32) -> Sphere {
+        Sphere { center: *center, radius: *radius }
+    }
+
+    /// Returns the smallest Sphere containing all of the given points, or
+    /// `None` if the slice is empty.
+    ///
+    /// This uses Welzl's algorithm, in the iterative form that grows the
+    /// Sphere one point at a time and, whenever a point falls outside it,
+    /// rebuilds it with that point on its surface. The points are visited
+    /// in a shuffled order, which makes the expected running time linear.
+    /// The work is done in f64 and the radius is rounded up afterwards, so
+    /// every point is inside the result.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Sphere, Vec3};
+    /// let points = [
+    ///     Vec3 { x: -1.0, y: 0.0, z: 0.0 },
+    ///     Vec3 { x: 1.0, y: 0.0, z: 0.0 },
+    ///     Vec3 { x: 0.0, y: 0.5, z: 0.0 },
+    /// ];
+    /// let sphere = Sphere::new_from_points(&points).unwrap();
+    /// assert_eq!(sphere.center, Vec3::new());
+    /// assert!((sphere.radius - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn new_from_points(points: &[Vec3]) -> Option<Sphere> {
+        if points.is_empty() {
+            return None;
+        }
+        let mut shuffled: Vec<[f64; 3]> = points.iter().map(|p| [p.x as f64, p.y as f64, p.z as f64]).collect();
+        // A fixed seed keeps the result reproducible.
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for i in (1..shuffled.len()).rev() {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            shuffled.swap(i, (state >> 33) as usize % (i + 1));
+        }
+
+        let p = &shuffled;
+        let mut sphere = Ball { center: p[0], radius_squared: 0.0 };
+        for i in 1..p.len() {
+            if sphere.contains(&p[i]) {
+                continue;
+            }
+            sphere = Ball { center: p[i], radius_squared: 0.0 };
+            for j in 0..i {
+                if sphere.contains(&p[j]) {
+                    continue;
+                }
+                sphere = Ball::through_two(&p[i], &p[j]);
+                for k in 0..j {
+                    if sphere.contains(&p[k]) {
+                        continue;
+                    }
+                    sphere = Ball::through_three(&p[i], &p[j], &p[k]).unwrap_or_else(|| sphere.grown(&p[k]));
+                    for l in 0..k {
+                        if !sphere.contains(&p[l]) {
+                            sphere = Ball::through_four(&p[i], &p[j], &p[k], &p[l]).unwrap_or_else(|| sphere.grown(&p[l]));
+                        }
+                    }
+                }
+            }
+        }
+        let center = Vec3 { x: sphere.center[0] as f32, y: sphere.center[1] as f32, z: sphere.center[2] as f32 };
+        Some(Sphere::enclosing(points, &center))
+    }
+
+    /// Returns a Sphere containing all of the given points, or `None` if the
+    /// slice is empty, using Ritter's algorithm.
+    ///
+    /// This is faster than `new_from_points` but the Sphere is usually a few
+    /// percent larger than the smallest one. It starts from two points far
+    /// apart and grows the Sphere just enough to take in each point outside
+    /// it. Points with a NaN coordinate don't panic, but make the result
+    /// meaningless.
+    pub fn new_from_points_ritter(points: &[Vec3]) -> Option<Sphere> {
+        let first = points.first()?;
+        let farthest_from = |from: &Vec3| {
+            *points.iter().max_by(|a, b| (**a - *from).length().total_cmp(&(**b - *from).length())).unwrap()
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(&a);
+        let mut center = 0.5 * (a + b);
+        let mut radius = 0.5 * (b - a).length();
+        for point in points {
+            let distance = (*point - center).length();
+            if distance > radius {
+                // Move the center towards the point so the far side of the
+                // Sphere stays put.
+                let new_radius = 0.5 * (radius + distance);
+                center = center + ((new_radius - radius) / distance) * (*point - center);
+                radius = new_radius;
+            }
+        }
+        Some(Sphere::enclosing(points, &center))
+    }
+
+    /// Returns the Sphere with the given center just large enough to contain
+    /// every point.
+    fn enclosing(points: &[Vec3], center: &Vec3) -> Sphere {
+        let radius = points
+            .iter()
+            .map(|point| {
+                let (x, y, z) = (point.x as f64 - center.x as f64, point.y as f64 - center.y as f64, point.z as f64 - center.z as f64);
+                (x * x + y * y + z * z).sqrt()
+            })
+            .fold(0.0, f64::max);
+        let rounded = radius as f32;
+        let rounded = if (rounded as f64) < radius { rounded.next_up() } else { rounded };
+        // `contains_point` measures in f32, which can round past the exact
+        // distance.
+        let measured = points.iter().map(|point| (*point - *center).length()).fold(0.0, f32::max);
+        Sphere { center: *center, radius: rounded.max(measured) }
+    }
+
+    /// Returns true if the point lies inside or on the surface of the
+    /// Sphere.
+    pub fn contains_point(&self, point: &Vec3) -> bool {
+        (*point - self.center).length() <= self.radius
+    }
+
+    /// Returns true if the two Spheres overlap or touch.
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        (other.center - self.center).length() <= self.radius + other.radius
+    }
+
+    /// Returns the smallest Aabb containing the Sphere.
+    pub fn aabb(&self) -> Aabb {
+        let extent = Vec3 { x: self.radius, y: self.radius, z: self.radius };
+        Aabb { min: self.center - extent, max: self.center + extent }
+    }
+}
+
+/// A Sphere in f64, with the squared radius, used while building.
+#[derive(Clone, Copy, Debug)]
+struct Ball {
+    center: [f64; 3],
+    radius_squared: f64,
+}
+
+fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn add_scaled(point: &[f64; 3], scale: f64, direction: &[f64; 3]) -> [f64; 3] {
+    [point[0] + scale * direction[0], point[1] + scale * direction[1], point[2] + scale * direction[2]]
+}
+
+impl Ball {
+    /// Returns true if the point is inside, allowing for rounding.
+    fn contains(&self, point: &[f64; 3]) -> bool {
+        let offset = sub(point, &self.center);
+        dot(&offset, &offset) <= self.radius_squared * (1.0 + 1e-12) + 1e-30
+    }
+
+    /// Returns the Ball with the same center, grown to contain the point.
+    fn grown(&self, point: &[f64; 3]) -> Ball {
+        let offset = sub(point, &self.center);
+        Ball { center: self.center, radius_squared: dot(&offset, &offset) }
+    }
+
+    fn through_two(a: &[f64; 3], b: &[f64; 3]) -> Ball {
+        let center = add_scaled(a, 0.5, &sub(b, a));
+        let offset = sub(a, &center);
+        Ball { center, radius_squared: dot(&offset, &offset) }
+    }
+
+    /// Returns the smallest Ball with the three points on its surface, or
+    /// `None` if they're collinear.
+    fn through_three(a: &[f64; 3], b: &[f64; 3], c: &[f64; 3]) -> Option<Ball> {
+        let (ab, ac) = (sub(b, a), sub(c, a));
+        let normal = cross(&ab, &ac);
+        let denominator = 2.0 * dot(&normal, &normal);
+        if denominator == 0.0 {
+            return None;
+        }
+        // The circumcenter of the triangle, relative to a.
+        let to_center = {
+            let first = cross(&normal, &ab);
+            let second = cross(&ac, &normal);
+            let (ab_squared, ac_squared) = (dot(&ab, &ab), dot(&ac, &ac));
+            [
+                (ac_squared * first[0] + ab_squared * second[0]) / denominator,
+                (ac_squared * first[1] + ab_squared * second[1]) / denominator,
+                (ac_squared * first[2] + ab_squared * second[2]) / denominator,
+            ]
+        };
+        Some(Ball { center: add_scaled(a, 1.0, &to_center), radius_squared: dot(&to_center, &to_center) })
+    }
+
+    /// Returns the Ball with the four points on its surface, or `None` if
+    /// they're coplanar.
+    fn through_four(a: &[f64; 3], b: &[f64; 3], c: &[f64; 3], d: &[f64; 3]) -> Option<Ball> {
+        let (ab, ac, ad) = (sub(b, a), sub(c, a), sub(d, a));
+        let denominator = 2.0 * dot(&ab, &cross(&ac, &ad));
+        if denominator == 0.0 {
+            return None;
+        }
+        let (first, second, third) = (cross(&ac, &ad), cross(&ad, &ab), cross(&ab, &ac));
+        let (ab_squared, ac_squared, ad_squared) = (dot(&ab, &ab), dot(&ac, &ac), dot(&ad, &ad));
+        let to_center = [
+            (ab_squared * first[0] + ac_squared * second[0] + ad_squared * third[0]) / denominator,
+            (ab_squared * first[1] + ac_squared * second[1] + ad_squared * third[1]) / denominator,
+            (ab_squared * first[2] + ac_squared * second[2] + ad_squared * third[2]) / denominator,
+        ];
+        Some(Ball { center: add_scaled(a, 1.0, &to_center), radius_squared: dot(&to_center, &to_center) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sphere, Vec3};
+    use crate::test_values::values;
+
+    #[test]
+    fn sphere_around_cube_corners() {
+        let mut points = Vec::new();
+        for i in 0..8 {
+            points.push(Vec3 { x: (i & 1) as f32 * 2.0 + 1.0, y: ((i >> 1) & 1) as f32 * 2.0, z: (i >> 2) as f32 * 2.0 - 5.0 });
+        }
+        // Interior points don't change it.
+        points.push(Vec3 { x: 2.0, y: 1.0, z: -4.0 });
+        let sphere = Sphere::new_from_points(&points).unwrap();
+        assert!((sphere.center - Vec3 { x: 2.0, y: 1.0, z: -4.0 }).length() < 1e-6);
+        assert!((sphere.radius - 3.0f32.sqrt()).abs() < 1e-6);
+        assert_eq!(Sphere::new_from_points(&[]), None);
+        let single = Sphere::new_from_points(&points[..1]).unwrap();
+        assert_eq!(single, Sphere { center: points[0], radius: 0.0 });
+    }
+
+    #[test]
+    fn sphere_through_three_points() {
+        // An acute triangle's smallest sphere is its circumscribed circle.
+        let points = [
+            Vec3 { x: 0.0, y: 0.0, z: 1.0 },
+            Vec3 { x: 4.0, y: 0.0, z: 1.0 },
+            Vec3 { x: 2.0, y: 3.0, z: 1.0 },
+        ];
+        let sphere = Sphere::new_from_points(&points).unwrap();
+        // The circumcenter is at (2, 5 / 6) with radius 13 / 6.
+        assert!((sphere.center - Vec3 { x: 2.0, y: 5.0 / 6.0, z: 1.0 }).length() < 1e-6);
+        assert!((sphere.radius - 13.0 / 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn welzl_is_tight_and_ritter_is_bounding() {
+        let coordinates = values(3 * 1000, 5);
+        let points: Vec<Vec3> = coordinates.chunks(3).map(|c| Vec3 { x: 3.0 * c[0], y: c[1], z: 2.0 * c[2] }).collect();
+        let welzl = Sphere::new_from_points(&points).unwrap();
+        let ritter = Sphere::new_from_points_ritter(&points).unwrap();
+        for point in &points {
+            assert!(welzl.contains_point(point));
+            assert!(ritter.contains_point(point));
+        }
+        assert!(welzl.radius <= ritter.radius);
+        // A NaN coordinate gives a meaningless Sphere rather than a panic.
+        let mut with_nan = points.clone();
+        with_nan[10].y = f32::NAN;
+        Sphere::new_from_points_ritter(&with_nan).unwrap();
+        // The smallest sphere touches at least two points.
+        let touching = points.iter().filter(|point| (**point - welzl.center).length() > welzl.radius * (1.0 - 1e-5)).count();
+        assert!(touching >= 2);
+        // And moving its center in any direction must leave a point outside.
+        for axis in &[Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 0.0, y: 1.0, z: 0.0 }, Vec3 { x: 0.0, y: 0.0, z: 1.0 }] {
+            for sign in &[-1.0, 1.0] {
+                let moved = Sphere { center: welzl.center + (0.01 * sign) * *axis, radius: welzl.radius };
+                assert!(points.iter().any(|point| !moved.contains_point(point)));
+            }
+        }
+    }
+
+    #[test]
+    fn sphere_overlap_and_aabb() {
+        let a = Sphere { center: Vec3::new(), radius: 1.0 };
+        let b = Sphere { center: Vec3 { x: 1.5, y: 0.0, z: 0.0 }, radius: 0.5 };
+        let c = Sphere { center: Vec3 { x: 0.0, y: 2.0, z: 0.0 }, radius: 0.5 };
+        assert!(a.intersects_sphere(&b));
+        assert!(!a.intersects_sphere(&c));
+        assert_eq!(c.aabb().min, Vec3 { x: -0.5, y: 1.5, z: -0.5 });
+    }
+}
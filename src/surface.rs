@@ -0,0 +1,459 @@
+//! Implementation of tensor-product surfaces: bicubic Bezier patches and
+//! NURBS surfaces, with normals and tessellation into triangle meshes.
+
+use super::{BSpline, Vec2, Vec3, Vec4};
+
+/// A surface mapping a rectangle of (u, v) parameters to points.
+///
+/// The provided methods work on any surface from its evaluation and
+/// partial derivatives.
+pub trait Surface {
+    /// Returns the ranges of u and v the surface is defined over.
+    fn domain(&self) -> ((f32, f32), (f32, f32));
+
+    /// Returns the point at (u, v), which are clamped to the domain.
+    fn evaluate(&self, u: f32, v: f32) -> Vec3;
+
+    /// Returns the partial derivative with respect to u at (u, v).
+    fn partial_u(&self, u: f32, v: f32) -> Vec3;
+
+    /// Returns the partial derivative with respect to v at (u, v).
+    fn partial_v(&self, u: f32, v: f32) -> Vec3;
+
+    /// Returns the point at (u, v) together with the partial derivatives
+    /// with respect to u and v.
+    ///
+    /// Surfaces that share work between the three should override this.
+    fn evaluate_with_partials(&self, u: f32, v: f32) -> (Vec3, Vec3, Vec3) {
+        (self.evaluate(u, v), self.partial_u(u, v), self.partial_v(u, v))
+    }
+
+    /// Returns the unit normal at (u, v), pointing along the cross product of
+    /// the u and v partial derivatives.
+    ///
+    /// Where the surface is degenerate, such as at the pole of a sphere
+    /// where a whole edge of the control net meets, the normal is taken from
+    /// slightly inside the domain instead. A surface that is degenerate
+    /// there too has a zero normal.
+    fn normal(&self, u: f32, v: f32) -> Vec3 {
+        let (_, partial_u, partial_v) = self.evaluate_with_partials(u, v);
+        let cross = partial_u.cross_product(&partial_v);
+        if cross.length() > 1e-12 {
+            return cross.normalize();
+        }
+        let ((u_start, u_end), (v_start, v_end)) = self.domain();
+        let nudge = |t: f32, start: f32, end: f32| t + 1e-3 * (0.5 * (start + end) - t);
+        let (u, v) = (nudge(u, u_start, u_end), nudge(v, v_start, v_end));
+        let (_, partial_u, partial_v) = self.evaluate_with_partials(u, v);
+        let cross = partial_u.cross_product(&partial_v);
+        if cross.length() > 0.0 { cross.normalize() } else { Vec3::new() }
+    }
+
+    /// Returns a mesh of the surface sampled on a uniform grid of
+    /// `u_segments` by `v_segments` quads in its domain, each split into 2
+    /// triangles.
+    fn tessellate(&self, u_segments: usize, v_segments: usize) -> SurfaceMesh {
+        let (u_segments, v_segments) = (u_segments.max(1), v_segments.max(1));
+        let ((u_start, u_end), (v_start, v_end)) = self.domain();
+        let mut mesh = SurfaceMesh::default();
+        for row in 0..=v_segments {
+            let v = v_start + (v_end - v_start) * row as f32 / v_segments as f32;
+            for column in 0..=u_segments {
+                let u = u_start + (u_end - u_start) * column as f32 / u_segments as f32;
+                let (position, partial_u, partial_v) = self.evaluate_with_partials(u, v);
+                let cross = partial_u.cross_product(&partial_v);
+                mesh.positions.push(position);
+                mesh.normals.push(if cross.length() > 1e-12 { cross.normalize() } else { self.normal(u, v) });
+                mesh.parameters.push(Vec2 { x: u, y: v });
+            }
+        }
+        let width = u_segments + 1;
+        for row in 0..v_segments {
+            for column in 0..u_segments {
+                let corner = row * width + column;
+                mesh.triangles.push([corner, corner + 1, corner + width + 1]);
+                mesh.triangles.push([corner, corner + width + 1, corner + width]);
+            }
+        }
+        mesh
+    }
+}
+
+/// A triangle mesh sampled from a surface.
+///
+/// The positions, normals and parameters have one entry per vertex, and the
+/// triangles index into them, wound counterclockwise when seen from the side
+/// the normals point to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SurfaceMesh {
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub parameters: Vec<Vec2>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// Returns the cubic Bernstein polynomials and their derivatives at `t`.
+fn bernstein(t: f32) -> ([f32; 4], [f32; 4]) {
+    let s = 1.0 - t;
+    (
+        [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t],
+        [-3.0 * s * s, 3.0 * s * (s - 2.0 * t), 3.0 * t * (2.0 * s - t), 3.0 * t * t],
+    )
+}
+
+/// A bicubic Bezier patch over [0, 1] in u and v, which passes through its
+/// 4 corner points and is pulled towards the other 12.
+///
+/// `points[i][j]` is the i-th control point along u and the j-th along v.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BezierPatch {
+    pub points: [[Vec3; 4]; 4],
+}
+
+impl BezierPatch {
+    /// Returns a new BezierPatch using the given control points.
+    pub fn new_from_points(points: &[[Vec3; 4]; 4]) -> BezierPatch {
+        BezierPatch { points: *points }
+    }
+
+    /// Returns the sum of the control points weighted by the products of
+    /// the given u and v weights.
+    fn combine(&self, u_weights: &[f32; 4], v_weights: &[f32; 4]) -> Vec3 {
+        let mut total = Vec3::new();
+        for (row, u_weight) in self.points.iter().zip(u_weights) {
+            for (point, v_weight) in row.iter().zip(v_weights) {
+                total += (u_weight * v_weight) * *point;
+            }
+        }
+        total
+    }
+
+    /// Returns the same surface as a NurbsSurface.
+    pub fn to_nurbs(&self) -> NurbsSurface {
+        let points: Vec<Vec<Vec4>> = self.points.iter().map(|row| row.iter().map(|point| point.extend(1.0)).collect()).collect();
+        let knots = [0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        NurbsSurface::new_from_weighted_points(&points, &knots, 3, &knots, 3)
+    }
+}
+
+impl Surface for BezierPatch {
+    fn domain(&self) -> ((f32, f32), (f32, f32)) {
+        ((0.0, 1.0), (0.0, 1.0))
+    }
+
+    fn evaluate(&self, u: f32, v: f32) -> Vec3 {
+        let ((u_weights, _), (v_weights, _)) = (bernstein(u.clamp(0.0, 1.0)), bernstein(v.clamp(0.0, 1.0)));
+        self.combine(&u_weights, &v_weights)
+    }
+
+    fn partial_u(&self, u: f32, v: f32) -> Vec3 {
+        let ((_, u_weights), (v_weights, _)) = (bernstein(u.clamp(0.0, 1.0)), bernstein(v.clamp(0.0, 1.0)));
+        self.combine(&u_weights, &v_weights)
+    }
+
+    fn partial_v(&self, u: f32, v: f32) -> Vec3 {
+        let ((u_weights, _), (_, v_weights)) = (bernstein(u.clamp(0.0, 1.0)), bernstein(v.clamp(0.0, 1.0)));
+        self.combine(&u_weights, &v_weights)
+    }
+
+    fn evaluate_with_partials(&self, u: f32, v: f32) -> (Vec3, Vec3, Vec3) {
+        let ((u_weights, u_derivatives), (v_weights, v_derivatives)) = (bernstein(u.clamp(0.0, 1.0)), bernstein(v.clamp(0.0, 1.0)));
+        (
+            self.combine(&u_weights, &v_weights),
+            self.combine(&u_derivatives, &v_weights),
+            self.combine(&u_weights, &v_derivatives),
+        )
+    }
+}
+
+/// Returns the index of the first control point affecting `t` in a B-spline
+/// with the given knots, degree and number of control points, followed by
+/// the `degree + 1` basis functions that are nonzero there and their
+/// derivatives.
+///
+/// `t` is clamped to the domain, and the spans are found like
+/// `BSpline::evaluate` does, so the last span covers the end of the domain.
+fn basis(knots: &[f32], degree: usize, count: usize, t: f32) -> (usize, Vec<f32>, Vec<f32>) {
+    let t = t.clamp(knots[degree], knots[count]);
+    let span = knots.partition_point(|knot| *knot <= t).saturating_sub(1).clamp(degree, count - 1);
+    // Builds the functions up one degree at a time with the Cox-de Boor
+    // recursion, keeping the degree below for the derivatives.
+    let mut values = vec![1.0; degree + 1];
+    let mut lower = vec![0.0; degree];
+    for level in 1..=degree {
+        lower[..level].copy_from_slice(&values[..level]);
+        let mut carried = 0.0;
+        for index in 0..level {
+            let (low, high) = (knots[span + 1 + index - level], knots[span + 1 + index]);
+            let share = if high > low { lower[index] / (high - low) } else { 0.0 };
+            values[index] = carried + (high - t) * share;
+            carried = (t - low) * share;
+        }
+        values[level] = carried;
+    }
+    let mut derivatives = vec![0.0; degree + 1];
+    for index in 0..degree {
+        let (low, high) = (knots[span + 1 + index - degree], knots[span + 1 + index]);
+        let slope = if high > low { degree as f32 * lower[index] / (high - low) } else { 0.0 };
+        derivatives[index] -= slope;
+        derivatives[index + 1] += slope;
+    }
+    (span - degree, values, derivatives)
+}
+
+/// A non-uniform rational B-spline surface.
+///
+/// The control points are in homogeneous coordinates, a point at `p` with
+/// weight `w` being stored as `[w * p.x, w * p.y, w * p.z, w]`, so that
+/// the surface is the tensor product B-spline of the points divided by its
+/// `w`. `points[i][j]` is the i-th control point along u and the j-th along
+/// v. Each direction has knots and a degree like a `BSpline`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NurbsSurface {
+    pub points: Vec<Vec<Vec4>>,
+    pub u_knots: Vec<f32>,
+    pub u_degree: usize,
+    pub v_knots: Vec<f32>,
+    pub v_degree: usize,
+}
+
+impl NurbsSurface {
+    /// Returns a new NurbsSurface using the given homogeneous control points,
+    /// knots and degrees.
+    ///
+    /// Panics if there are no points, if the rows of points are empty or
+    /// differ in length, or if the points along either direction don't make
+    /// a valid `BSpline` with its knots and degree.
+    pub fn new_from_weighted_points(
+        points: &[Vec<Vec4>],
+        u_knots: &[f32],
+        u_degree: usize,
+        v_knots: &[f32],
+        v_degree: usize,
+    ) -> NurbsSurface {
+        let v_count = points.first().map_or(0, |row| row.len());
+        assert!(v_count > 0, "a NURBS surface needs at least one row of control points, and points in each row");
+        assert!(points.iter().all(|row| row.len() == v_count), "every row of NURBS control points needs the same length");
+        // Check that each direction makes a valid B-spline.
+        BSpline::new_from_points_and_knots(points.iter().map(|row| row[0]).collect::<Vec<Vec4>>().as_slice(), u_knots, u_degree);
+        BSpline::new_from_points_and_knots(&points[0], v_knots, v_degree);
+        NurbsSurface {
+            points: points.to_vec(),
+            u_knots: u_knots.to_vec(),
+            u_degree,
+            v_knots: v_knots.to_vec(),
+            v_degree,
+        }
+    }
+
+    /// Returns a new NurbsSurface with every control point given a weight of
+    /// 1, making it a plain B-spline surface.
+    ///
+    /// Panics in the same cases as `new_from_weighted_points`.
+    pub fn new_from_points(points: &[Vec<Vec3>], u_knots: &[f32], u_degree: usize, v_knots: &[f32], v_degree: usize) -> NurbsSurface {
+        let points: Vec<Vec<Vec4>> = points.iter().map(|row| row.iter().map(|point| point.extend(1.0)).collect()).collect();
+        NurbsSurface::new_from_weighted_points(&points, u_knots, u_degree, v_knots, v_degree)
+    }
+
+    /// Returns the homogeneous point at (u, v) and its partial derivatives,
+    /// summing the control points affecting (u, v) weighted by products of
+    /// the basis functions along u and v and their derivatives.
+    fn homogeneous(&self, u: f32, v: f32) -> (Vec4, Vec4, Vec4) {
+        let (u_first, u_values, u_derivatives) = basis(&self.u_knots, self.u_degree, self.points.len(), u);
+        let (v_first, v_values, v_derivatives) = basis(&self.v_knots, self.v_degree, self.points[0].len(), v);
+        let (mut point, mut partial_u, mut partial_v) = (Vec4::new(), Vec4::new(), Vec4::new());
+        for (row, (u_value, u_derivative)) in self.points[u_first..].iter().zip(u_values.iter().zip(&u_derivatives)) {
+            for (control, (v_value, v_derivative)) in row[v_first..].iter().zip(v_values.iter().zip(&v_derivatives)) {
+                point += (u_value * v_value) * *control;
+                partial_u += (u_derivative * v_value) * *control;
+                partial_v += (u_value * v_derivative) * *control;
+            }
+        }
+        (point, partial_u, partial_v)
+    }
+}
+
+impl Surface for NurbsSurface {
+    fn domain(&self) -> ((f32, f32), (f32, f32)) {
+        (
+            (self.u_knots[self.u_degree], self.u_knots[self.points.len()]),
+            (self.v_knots[self.v_degree], self.v_knots[self.points[0].len()]),
+        )
+    }
+
+    fn evaluate(&self, u: f32, v: f32) -> Vec3 {
+        self.evaluate_with_partials(u, v).0
+    }
+
+    fn partial_u(&self, u: f32, v: f32) -> Vec3 {
+        self.evaluate_with_partials(u, v).1
+    }
+
+    fn partial_v(&self, u: f32, v: f32) -> Vec3 {
+        self.evaluate_with_partials(u, v).2
+    }
+
+    /// Uses the quotient rule to undo the weights of the homogeneous point
+    /// and partial derivatives.
+    fn evaluate_with_partials(&self, u: f32, v: f32) -> (Vec3, Vec3, Vec3) {
+        let (point, partial_u, partial_v) = self.homogeneous(u, v);
+        let position = (1.0 / point.w) * point.truncate();
+        let rational = |partial: Vec4| (1.0 / point.w) * (partial.truncate() - partial.w * position);
+        (position, rational(partial_u), rational(partial_v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BSpline, BezierPatch, NurbsSurface, Surface, Vec3, Vec4};
+    use crate::Curve;
+
+    fn curved_patch() -> BezierPatch {
+        let mut points = [[Vec3::new(); 4]; 4];
+        for (i, row) in points.iter_mut().enumerate() {
+            for (j, point) in row.iter_mut().enumerate() {
+                let height = [0.0, 1.0, -0.5, 0.25][i] * [0.5, 1.0, 2.0, 0.0][j];
+                *point = Vec3 { x: i as f32 + 0.1 * j as f32, y: j as f32, z: height };
+            }
+        }
+        BezierPatch::new_from_points(&points)
+    }
+
+    /// Checks the partial derivatives of a surface against central
+    /// differences.
+    fn assert_partials<S: Surface>(surface: &S) {
+        let ((u_start, u_end), (v_start, v_end)) = surface.domain();
+        let h = 1e-3;
+        for i in 1..8 {
+            for j in 1..8 {
+                let u = u_start + (u_end - u_start) * i as f32 / 8.0;
+                let v = v_start + (v_end - v_start) * j as f32 / 8.0;
+                let partial_u = (1.0 / (2.0 * h)) * (surface.evaluate(u + h, v) - surface.evaluate(u - h, v));
+                let partial_v = (1.0 / (2.0 * h)) * (surface.evaluate(u, v + h) - surface.evaluate(u, v - h));
+                assert!((partial_u - surface.partial_u(u, v)).length() < 1e-2 * (1.0 + partial_u.length()));
+                assert!((partial_v - surface.partial_v(u, v)).length() < 1e-2 * (1.0 + partial_v.length()));
+            }
+        }
+    }
+
+    #[test]
+    fn bezier_patch_evaluation() {
+        // A patch with evenly spaced points in a plane is that plane.
+        let mut points = [[Vec3::new(); 4]; 4];
+        for (i, row) in points.iter_mut().enumerate() {
+            for (j, point) in row.iter_mut().enumerate() {
+                *point = Vec3 { x: i as f32, y: 2.0 * j as f32, z: 1.0 };
+            }
+        }
+        let flat = BezierPatch::new_from_points(&points);
+        assert!((flat.evaluate(0.5, 0.25) - Vec3 { x: 1.5, y: 1.5, z: 1.0 }).length() < 1e-6);
+        assert!((flat.partial_u(0.3, 0.6) - Vec3 { x: 3.0, y: 0.0, z: 0.0 }).length() < 1e-6);
+        assert!((flat.partial_v(0.3, 0.6) - Vec3 { x: 0.0, y: 6.0, z: 0.0 }).length() < 1e-6);
+        assert!((flat.normal(0.3, 0.6) - Vec3 { x: 0.0, y: 0.0, z: 1.0 }).length() < 1e-6);
+
+        let patch = curved_patch();
+        assert_eq!(patch.evaluate(0.0, 0.0), patch.points[0][0]);
+        assert_eq!(patch.evaluate(1.0, 0.0), patch.points[3][0]);
+        assert_eq!(patch.evaluate(0.0, 1.0), patch.points[0][3]);
+        assert_partials(&patch);
+    }
+
+    #[test]
+    fn bezier_patch_as_nurbs() {
+        let patch = curved_patch();
+        let nurbs = patch.to_nurbs();
+        assert_eq!(nurbs.domain(), patch.domain());
+        assert_partials(&nurbs);
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let (u, v) = (i as f32 / 4.0, j as f32 / 4.0);
+                assert!((nurbs.evaluate(u, v) - patch.evaluate(u, v)).length() < 1e-5);
+                assert!((nurbs.partial_u(u, v) - patch.partial_u(u, v)).length() < 1e-4);
+                assert!((nurbs.normal(u, v) - patch.normal(u, v)).length() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn nurbs_cylinder_is_exact() {
+        // A quarter of a unit cylinder: a rational quadratic arc along u,
+        // swept along z.
+        let weight = std::f32::consts::FRAC_1_SQRT_2;
+        let arc = [Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 1.0, y: 1.0, z: 0.0 }, Vec3 { x: 0.0, y: 1.0, z: 0.0 }];
+        let points: Vec<Vec<Vec4>> = arc
+            .iter()
+            .zip(&[1.0, weight, 1.0])
+            .map(|(point, w)| (0..2).map(|height| (*w * Vec3 { x: point.x, y: point.y, z: 2.0 * height as f32 }).extend(*w)).collect())
+            .collect();
+        let cylinder = NurbsSurface::new_from_weighted_points(&points, &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0], 2, &[0.0, 0.0, 1.0, 1.0], 1);
+        assert_partials(&cylinder);
+        for i in 0..=10 {
+            for j in 0..=4 {
+                let (u, v) = (i as f32 / 10.0, j as f32 / 4.0);
+                let point = cylinder.evaluate(u, v);
+                assert!(((point.x * point.x + point.y * point.y).sqrt() - 1.0).abs() < 1e-6);
+                assert!((point.z - 2.0 * v).abs() < 1e-6);
+                // The normal points straight out from the axis.
+                assert!((cylinder.normal(u, v) - Vec3 { x: point.x, y: point.y, z: 0.0 }).length() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn nurbs_matches_bsplines_through_rows() {
+        // A cubic by quadratic surface with uneven and repeated knots, to
+        // check the basis functions against evaluating B-splines along v
+        // for each row and then along u through the results.
+        let points: Vec<Vec<Vec4>> = (0..6)
+            .map(|i| (0..4).map(|j| Vec4 { x: i as f32, y: j as f32, z: ((i * 3 + j * 5) % 7) as f32 - 3.0, w: 1.0 + 0.25 * ((i + j) % 3) as f32 }).collect())
+            .collect();
+        let u_knots = [0.0, 0.0, 0.0, 0.0, 0.4, 0.4, 1.5, 1.5, 1.5, 1.5];
+        let v_knots = [-1.0, -1.0, -1.0, 0.2, 1.0, 1.0, 1.0];
+        let surface = NurbsSurface::new_from_weighted_points(&points, &u_knots, 3, &v_knots, 2);
+        assert_partials(&surface);
+        let mesh = surface.tessellate(6, 5);
+        for (index, parameters) in mesh.parameters.iter().enumerate() {
+            let (u, v) = (parameters.x, parameters.y);
+            let rows: Vec<Vec4> = points.iter().map(|row| BSpline::new_from_points_and_knots(row, &v_knots, 2).evaluate(v)).collect();
+            let expected = BSpline::new_from_points_and_knots(&rows, &u_knots, 3).evaluate(u);
+            assert!((surface.evaluate(u, v) - (1.0 / expected.w) * expected.truncate()).length() < 1e-5);
+            assert_eq!(mesh.positions[index], surface.evaluate(u, v));
+            assert!((mesh.normals[index] - surface.normal(u, v)).length() < 1e-6);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "points in each row")]
+    fn nurbs_with_empty_rows_panics() {
+        NurbsSurface::new_from_weighted_points(&[vec![], vec![]], &[0.0, 0.0, 1.0, 1.0], 1, &[0.0, 1.0], 0);
+    }
+
+    #[test]
+    fn nurbs_degenerate_normal() {
+        // A triangle made from a control net with one edge collapsed to a
+        // point.
+        let points = vec![vec![Vec3 { x: 0.0, y: 0.0, z: 1.0 }, Vec3 { x: 0.0, y: 0.0, z: 1.0 }], vec![Vec3 { x: 1.0, y: 0.0, z: 0.0 }, Vec3 { x: 0.0, y: 1.0, z: 0.0 }]];
+        let triangle = NurbsSurface::new_from_points(&points, &[0.0, 0.0, 1.0, 1.0], 1, &[0.0, 0.0, 1.0, 1.0], 1);
+        let tip = triangle.normal(0.0, 0.5);
+        assert!((tip.length() - 1.0).abs() < 1e-5);
+        assert!((tip - triangle.normal(0.5, 0.5)).length() < 1e-3);
+    }
+
+    #[test]
+    fn tessellation_grid() {
+        let patch = curved_patch();
+        let mesh = patch.tessellate(4, 3);
+        assert_eq!(mesh.positions.len(), 5 * 4);
+        assert_eq!(mesh.normals.len(), 5 * 4);
+        assert_eq!(mesh.triangles.len(), 2 * 4 * 3);
+        assert_eq!(mesh.positions[0], patch.points[0][0]);
+        assert_eq!(mesh.positions[4], patch.points[3][0]);
+        assert_eq!(mesh.positions[19], patch.points[3][3]);
+        assert_eq!(mesh.parameters[6].x, 0.25);
+        for [a, b, c] in &mesh.triangles {
+            let (pa, pb, pc) = (mesh.positions[*a], mesh.positions[*b], mesh.positions[*c]);
+            let face = (pb - pa).cross_product(&(pc - pa));
+            assert!(face * (mesh.normals[*a] + mesh.normals[*b] + mesh.normals[*c]) > 0.0);
+        }
+    }
+}
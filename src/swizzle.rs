@@ -0,0 +1,38 @@
+//! Shader style swizzle accessors for Vec2, Vec3 and Vec4, such as `xy()`,
+//! `zyx()` and `xyzz()`.
+//!
+//! These are generated by `build.rs` and only compiled with the `swizzle`
+//! feature enabled.
+
+use super::{Vec2, Vec3, Vec4};
+
+include!(concat!(env!("OUT_DIR"), "/swizzle.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn swizzle_vec2() {
+        let vec2 = Vec2 { x: 1.0, y: 2.0 };
+        assert_eq!(vec2.yx(), Vec2 { x: 2.0, y: 1.0 });
+        assert_eq!(vec2.xyx(), Vec3 { x: 1.0, y: 2.0, z: 1.0 });
+        assert_eq!(vec2.yyxx(), Vec4 { x: 2.0, y: 2.0, z: 1.0, w: 1.0 });
+    }
+
+    #[test]
+    fn swizzle_vec3() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_eq!(vec3.xz(), Vec2 { x: 1.0, y: 3.0 });
+        assert_eq!(vec3.zyx(), Vec3 { x: 3.0, y: 2.0, z: 1.0 });
+        assert_eq!(vec3.xyzz(), Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 3.0 });
+    }
+
+    #[test]
+    fn swizzle_vec4() {
+        let vec4 = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(vec4.wx(), Vec2 { x: 4.0, y: 1.0 });
+        assert_eq!(vec4.xyz(), Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+        assert_eq!(vec4.wzyx(), Vec4 { x: 4.0, y: 3.0, z: 2.0, w: 1.0 });
+    }
+}
@@ -0,0 +1,67 @@
+//! Reproducible pseudo-random inputs and approximate assertions shared by
+//! the tests.
+
+use super::{Mat2, Mat3, Mat4, Vec2, Vec3};
+
+/// Returns the states of a linear congruential generator, without the low
+/// bits, which repeat quickly.
+fn states(count: usize, seed: u32) -> impl Iterator<Item = u32> {
+    let mut state = seed;
+    (0..count).map(move |_| {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        state >> 8
+    })
+}
+
+/// Returns values spread evenly over [-1, 1).
+pub fn values(count: usize, seed: u32) -> Vec<f32> {
+    states(count, seed).map(|state| state as f32 / (1 << 24) as f32 * 2.0 - 1.0).collect()
+}
+
+/// Returns whole numbers below `steps`, so that points built from them lie
+/// on a grid and are often collinear, coplanar or cocircular.
+pub fn grid_values(count: usize, seed: u32, steps: u32) -> Vec<f32> {
+    states(count, seed).map(|state| (state % steps) as f32).collect()
+}
+
+/// Returns whole numbers in [-range, range), where `range` is at most
+/// 2^23.
+pub fn integers(count: usize, seed: u32, range: i64) -> Vec<i64> {
+    states(count, seed).map(|state| (state as i64 % (2 * range)) - range).collect()
+}
+
+/// Asserts that `actual` is within `tolerance` of `expected`.
+pub fn assert_near(actual: f32, expected: f32, tolerance: f32) {
+    assert!((actual - expected).abs() < tolerance, "{} != {}", actual, expected);
+}
+
+/// Asserts that 2 Vec2s are within 1e-5 of each other.
+pub fn assert_vec2_near(actual: &Vec2, expected: &Vec2) {
+    assert!((*actual - *expected).length() < 1e-5, "{:?} != {:?}", actual, expected);
+}
+
+/// Asserts that 2 Vec3s are within 1e-5 of each other.
+pub fn assert_vec3_near(actual: &Vec3, expected: &Vec3) {
+    assert!((*actual - *expected).length() < 1e-5, "{:?} != {:?}", actual, expected);
+}
+
+/// Asserts that every element of 2 Mat2s is within 1e-5.
+pub fn assert_mat2_near(actual: &Mat2, expected: &Mat2) {
+    for (x, y) in actual.to_array().iter().zip(expected.to_array().iter()) {
+        assert!((x - y).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// Asserts that every element of 2 Mat3s is within 1e-5.
+pub fn assert_mat3_near(actual: &Mat3, expected: &Mat3) {
+    for (x, y) in actual.to_array().iter().zip(expected.to_array().iter()) {
+        assert!((x - y).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// Asserts that every element of 2 Mat4s is within 1e-5.
+pub fn assert_mat4_near(actual: &Mat4, expected: &Mat4) {
+    for (x, y) in actual.to_array().iter().zip(expected.to_array().iter()) {
+        assert!((x - y).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
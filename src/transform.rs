@@ -0,0 +1,419 @@
+//! Implementation of compact transform types for poses that don't need a full
+//! homogeneous matrix.
+//!
+//! Each type stores only its linear part and a translation, so composing and
+//! inverting them skips the constant bottom row of a Mat4 (or Mat3 in 2D).
+//! Isometries and similarities store their rotation as a unit Quat (or
+//! Complex in 2D), which is renormalized after every composition so it can't
+//! drift away from a rotation, and invert exactly using its conjugate rather
+//! than a general matrix inverse.
+
+use std::ops;
+
+use super::{Complex, Mat2, Mat3, Mat4, Quat, Vec2, Vec3};
+
+/// Returns the homogeneous Mat4 applying `linear` and then `translation`.
+fn homogeneous_mat4(linear: &Mat3, translation: &Vec3) -> Mat4 {
+    Mat4::new_from_trs(translation, linear, &Vec3 { x: 1.0, y: 1.0, z: 1.0 })
+}
+
+/// Returns the homogeneous Mat3 applying `linear` and then `translation`.
+fn homogeneous_mat3(linear: &Mat2, translation: &Vec2) -> Mat3 {
+    Mat3 {
+        a: linear.a, b: linear.b, c: translation.x,
+        d: linear.c, e: linear.d, f: translation.y,
+        g: 0.0,      h: 0.0,      i: 1.0
+    }
+}
+
+macro_rules! transforms {
+    (
+        $isometry:ident, $similarity:ident, $affine:ident,
+        $rotation:ident, $to_mat:ident, $mat:ident, $vec:ident,
+        $homogeneous:ident, $to_homogeneous:ident, $make_homogeneous:ident
+    ) => {
+        impl $isometry {
+            /// Returns the transform that leaves every point in place.
+            pub fn identity() -> $isometry {
+                $isometry { rotation: $rotation::identity(), translation: $vec::new() }
+            }
+
+            /// Returns a transform that rotates and then translates. The
+            /// rotation must have a length of 1.
+            pub fn new_from_rotation_translation(rotation: &$rotation, translation: &$vec) -> $isometry {
+                $isometry { rotation: *rotation, translation: *translation }
+            }
+
+            /// Returns the inverse transform, using the conjugate of the
+            /// rotation.
+            pub fn inverse(&self) -> $isometry {
+                let rotation = self.rotation.conjugate();
+                $isometry { rotation, translation: -(rotation * self.translation) }
+            }
+
+            /// Transforms a position, rotating and then translating it.
+            pub fn transform_point(&self, point: &$vec) -> $vec {
+                self.rotation * *point + self.translation
+            }
+
+            /// Transforms a direction, which is only rotated.
+            pub fn transform_vector(&self, vector: &$vec) -> $vec {
+                self.rotation * *vector
+            }
+
+            /// Interpolates between two transforms, slerping the rotation and
+            /// lerping the translation, returning `self` at `t = 0` and
+            /// `other` at `t = 1`.
+            pub fn interpolate(&self, other: &$isometry, t: f32) -> $isometry {
+                $isometry {
+                    rotation: self.rotation.slerp(&other.rotation, t),
+                    translation: self.translation + t * (other.translation - self.translation),
+                }
+            }
+
+            /// Returns the same transform as an affine transform.
+            pub fn to_affine(&self) -> $affine {
+                $affine { linear: self.rotation.$to_mat(), translation: self.translation }
+            }
+
+            /// Returns the homogeneous matrix of the transform.
+            pub fn $to_homogeneous(&self) -> $homogeneous {
+                $make_homogeneous(&self.rotation.$to_mat(), &self.translation)
+            }
+        }
+
+        impl ops::Mul<$isometry> for $isometry {
+            type Output = $isometry;
+
+            /// Composes two transforms in the same order as multiplying their
+            /// matrices, so `a * b` applies `b` and then `a`.
+            fn mul(self, other: $isometry) -> $isometry {
+                $isometry {
+                    rotation: (self.rotation * other.rotation).normalize(),
+                    translation: self.transform_point(&other.translation),
+                }
+            }
+        }
+
+        impl Default for $isometry {
+            fn default() -> $isometry {
+                $isometry::identity()
+            }
+        }
+
+        impl $similarity {
+            /// Returns the transform that leaves every point in place.
+            pub fn identity() -> $similarity {
+                $similarity { rotation: $rotation::identity(), translation: $vec::new(), scale: 1.0 }
+            }
+
+            /// Returns a transform that scales, then rotates, then
+            /// translates. The rotation must have a length of 1.
+            pub fn new_from_rotation_translation_scale(rotation: &$rotation, translation: &$vec, scale: f32) -> $similarity {
+                $similarity { rotation: *rotation, translation: *translation, scale }
+            }
+
+            /// Returns the inverse transform, using the conjugate of the
+            /// rotation, or `None` if the scale is 0.
+            pub fn inverse(&self) -> Option<$similarity> {
+                if self.scale == 0.0 {
+                    return None;
+                }
+                let rotation = self.rotation.conjugate();
+                let scale = 1.0 / self.scale;
+                Some($similarity { rotation, translation: -scale * (rotation * self.translation), scale })
+            }
+
+            /// Transforms a position, scaling, rotating and then translating
+            /// it.
+            pub fn transform_point(&self, point: &$vec) -> $vec {
+                self.scale * (self.rotation * *point) + self.translation
+            }
+
+            /// Transforms a direction, which is only scaled and rotated.
+            pub fn transform_vector(&self, vector: &$vec) -> $vec {
+                self.scale * (self.rotation * *vector)
+            }
+
+            /// Interpolates between two transforms, slerping the rotation and
+            /// lerping the translation and scale, returning `self` at `t = 0`
+            /// and `other` at `t = 1`.
+            pub fn interpolate(&self, other: &$similarity, t: f32) -> $similarity {
+                $similarity {
+                    rotation: self.rotation.slerp(&other.rotation, t),
+                    translation: self.translation + t * (other.translation - self.translation),
+                    scale: self.scale + t * (other.scale - self.scale),
+                }
+            }
+
+            /// Returns the same transform as an affine transform.
+            pub fn to_affine(&self) -> $affine {
+                $affine { linear: self.scale * self.rotation.$to_mat(), translation: self.translation }
+            }
+
+            /// Returns the homogeneous matrix of the transform.
+            pub fn $to_homogeneous(&self) -> $homogeneous {
+                $make_homogeneous(&(self.scale * self.rotation.$to_mat()), &self.translation)
+            }
+        }
+
+        impl ops::Mul<$similarity> for $similarity {
+            type Output = $similarity;
+
+            /// Composes two transforms in the same order as multiplying their
+            /// matrices, so `a * b` applies `b` and then `a`.
+            fn mul(self, other: $similarity) -> $similarity {
+                $similarity {
+                    rotation: (self.rotation * other.rotation).normalize(),
+                    translation: self.transform_point(&other.translation),
+                    scale: self.scale * other.scale,
+                }
+            }
+        }
+
+        impl Default for $similarity {
+            fn default() -> $similarity {
+                $similarity::identity()
+            }
+        }
+
+        impl $affine {
+            /// Returns the transform that leaves every point in place.
+            pub fn identity() -> $affine {
+                $affine { linear: $mat::identity(), translation: $vec::new() }
+            }
+
+            /// Returns a transform that applies the linear map and then
+            /// translates.
+            pub fn new_from_linear_translation(linear: &$mat, translation: &$vec) -> $affine {
+                $affine { linear: *linear, translation: *translation }
+            }
+
+            /// Returns the inverse transform, or `None` if the linear part is
+            /// singular.
+            pub fn inverse(&self) -> Option<$affine> {
+                let linear = self.linear.inverse()?;
+                Some($affine { linear, translation: -(linear * self.translation) })
+            }
+
+            /// Transforms a position, applying the linear map and then
+            /// translating it.
+            pub fn transform_point(&self, point: &$vec) -> $vec {
+                self.linear * *point + self.translation
+            }
+
+            /// Transforms a direction, which only has the linear map applied.
+            pub fn transform_vector(&self, vector: &$vec) -> $vec {
+                self.linear * *vector
+            }
+
+            /// Interpolates component-wise between two transforms, returning
+            /// `self` at `t = 0` and `other` at `t = 1`.
+            ///
+            /// Unlike the other transforms, intermediate results of
+            /// interpolating two rotations are not rotations.
+            pub fn interpolate(&self, other: &$affine, t: f32) -> $affine {
+                $affine {
+                    linear: self.linear + t * (other.linear - self.linear),
+                    translation: self.translation + t * (other.translation - self.translation),
+                }
+            }
+
+            /// Returns the homogeneous matrix of the transform.
+            pub fn $to_homogeneous(&self) -> $homogeneous {
+                $make_homogeneous(&self.linear, &self.translation)
+            }
+        }
+
+        impl ops::Mul<$affine> for $affine {
+            type Output = $affine;
+
+            /// Composes two transforms in the same order as multiplying their
+            /// matrices, so `a * b` applies `b` and then `a`.
+            fn mul(self, other: $affine) -> $affine {
+                $affine {
+                    linear: self.linear * other.linear,
+                    translation: self.transform_point(&other.translation),
+                }
+            }
+        }
+
+        impl Default for $affine {
+            fn default() -> $affine {
+                $affine::identity()
+            }
+        }
+    };
+}
+
+/// A 3D rigid transform: a rotation followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry3 {
+    pub rotation: Quat,
+    pub translation: Vec3,
+}
+
+/// A 3D rigid transform with uniform scale: a scale, then a rotation, then a
+/// translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Similarity3 {
+    pub rotation: Quat,
+    pub translation: Vec3,
+    pub scale: f32,
+}
+
+/// A 3D affine transform: any linear map followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine3 {
+    pub linear: Mat3,
+    pub translation: Vec3,
+}
+
+/// A 2D rigid transform: a rotation followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry2 {
+    pub rotation: Complex,
+    pub translation: Vec2,
+}
+
+/// A 2D rigid transform with uniform scale: a scale, then a rotation, then a
+/// translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Similarity2 {
+    pub rotation: Complex,
+    pub translation: Vec2,
+    pub scale: f32,
+}
+
+/// A 2D affine transform: any linear map followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine2 {
+    pub linear: Mat2,
+    pub translation: Vec2,
+}
+
+transforms!(
+    Isometry3, Similarity3, Affine3,
+    Quat, to_mat3, Mat3, Vec3,
+    Mat4, to_mat4, homogeneous_mat4
+);
+transforms!(
+    Isometry2, Similarity2, Affine2,
+    Complex, to_mat2, Mat2, Vec2,
+    Mat3, to_mat3, homogeneous_mat3
+);
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{Affine2, Affine3, Isometry2, Isometry3, Similarity2, Similarity3};
+    use super::{Complex, Mat2, Mat3, Quat, Vec2, Vec3};
+    use crate::test_values::{assert_vec2_near, assert_vec3_near};
+
+    fn isometry3() -> Isometry3 {
+        let rotation = Quat::new_from_mat3(&(Mat3::new_from_rotation_x(0.7) * Mat3::new_from_rotation_z(-1.2)));
+        Isometry3::new_from_rotation_translation(&rotation, &Vec3 { x: 1.0, y: -2.0, z: 3.0 })
+    }
+
+    #[test]
+    fn isometry3_matches_mat4() {
+        let a = isometry3();
+        let b = Isometry3::new_from_rotation_translation(&Quat::new_from_mat3(&Mat3::new_from_rotation_y(2.0)), &Vec3 { x: 0.5, y: 0.0, z: -1.0 });
+        let point = Vec3 { x: 2.0, y: 1.0, z: -1.0 };
+        assert_vec3_near(&a.transform_point(&point), &a.to_mat4().transform_point(&point));
+        assert_vec3_near(&(a * b).transform_point(&point), &(a.to_mat4() * b.to_mat4()).transform_point(&point));
+        assert_vec3_near(&a.transform_vector(&point), &a.to_mat4().transform_vector(&point));
+    }
+
+    #[test]
+    fn isometry3_inverse() {
+        let a = isometry3();
+        let point = Vec3 { x: 2.0, y: 1.0, z: -1.0 };
+        assert_vec3_near(&a.inverse().transform_point(&a.transform_point(&point)), &point);
+        assert_vec3_near(&(a * a.inverse()).translation, &Vec3::new());
+    }
+
+    #[test]
+    fn isometry3_interpolate() {
+        let start = Isometry3::identity();
+        let end = Isometry3::new_from_rotation_translation(&Quat::new_from_mat3(&Mat3::new_from_rotation_z(0.5 * PI)), &Vec3 { x: 4.0, y: 0.0, z: 0.0 });
+        let halfway = start.interpolate(&end, 0.5);
+        let expected = Isometry3::new_from_rotation_translation(&Quat::new_from_mat3(&Mat3::new_from_rotation_z(0.25 * PI)), &Vec3 { x: 2.0, y: 0.0, z: 0.0 });
+        let point = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
+        assert_vec3_near(&halfway.transform_point(&point), &expected.transform_point(&point));
+    }
+
+    #[test]
+    fn similarity3_composition_and_inverse() {
+        let a = Similarity3::new_from_rotation_translation_scale(&isometry3().rotation, &Vec3 { x: 1.0, y: 0.0, z: 2.0 }, 2.0);
+        let b = Similarity3::new_from_rotation_translation_scale(&Quat::new_from_mat3(&Mat3::new_from_rotation_y(0.3)), &Vec3 { x: 0.0, y: 3.0, z: 0.0 }, 0.5);
+        let point = Vec3 { x: 2.0, y: 1.0, z: -1.0 };
+        assert_vec3_near(&a.transform_point(&point), &a.to_mat4().transform_point(&point));
+        assert_vec3_near(&(a * b).transform_point(&point), &(a.to_mat4() * b.to_mat4()).transform_point(&point));
+        let inverse = a.inverse().unwrap();
+        assert_vec3_near(&inverse.transform_point(&a.transform_point(&point)), &point);
+        assert_eq!(inverse.scale, 0.5);
+        assert_eq!(Similarity3 { scale: 0.0, ..a }.inverse(), None);
+        assert_eq!(a.interpolate(&b, 0.5).scale, 1.25);
+    }
+
+    #[test]
+    fn affine3_composition_and_inverse() {
+        let linear = Mat3::new_from_array(&[2.0, 1.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0]);
+        let a = Affine3::new_from_linear_translation(&linear, &Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+        let b = isometry3().to_affine();
+        let point = Vec3 { x: 2.0, y: 1.0, z: -1.0 };
+        assert_vec3_near(&(a * b).transform_point(&point), &(a.to_mat4() * b.to_mat4()).transform_point(&point));
+        let inverse = a.inverse().unwrap();
+        assert_vec3_near(&inverse.transform_point(&a.transform_point(&point)), &point);
+        assert_eq!(Affine3::new_from_linear_translation(&Mat3::new(), &Vec3::new()).inverse(), None);
+        assert_eq!(a.interpolate(&a, 0.3), a);
+    }
+
+    #[test]
+    fn transforms_2d_match_mat3() {
+        let rotation = Complex::new_from_mat2(&Mat2 { a: 0.0, b: -1.0, c: 1.0, d: 0.0 });
+        let isometry = Isometry2::new_from_rotation_translation(&rotation, &Vec2 { x: 1.0, y: 2.0 });
+        let similarity = Similarity2::new_from_rotation_translation_scale(&rotation, &Vec2 { x: -1.0, y: 0.0 }, 3.0);
+        let affine = Affine2::new_from_linear_translation(&Mat2 { a: 2.0, b: 1.0, c: 0.0, d: 1.0 }, &Vec2 { x: 0.0, y: 1.0 });
+        let point = Vec2 { x: 3.0, y: -1.0 };
+        let homogeneous = |mat3: Mat3| {
+            let result = mat3 * point.extend(1.0);
+            Vec2 { x: result.x, y: result.y }
+        };
+        assert_vec2_near(&isometry.transform_point(&point), &homogeneous(isometry.to_mat3()));
+        assert_vec2_near(&similarity.transform_point(&point), &homogeneous(similarity.to_mat3()));
+        assert_vec2_near(&affine.transform_point(&point), &homogeneous(affine.to_mat3()));
+        assert_vec2_near(&isometry.inverse().transform_point(&isometry.transform_point(&point)), &point);
+        assert_vec2_near(&affine.inverse().unwrap().transform_point(&affine.transform_point(&point)), &point);
+
+        let halfway = Isometry2::identity().interpolate(&isometry, 0.5);
+        let expected = Vec2 { x: (0.25 * PI).cos(), y: (0.25 * PI).sin() };
+        assert_vec2_near(&halfway.transform_vector(&Vec2 { x: 1.0, y: 0.0 }), &expected);
+    }
+
+    #[test]
+    fn default_is_identity() {
+        assert_eq!(Isometry3::default(), Isometry3::identity());
+        assert_eq!(Similarity3::default(), Similarity3::identity());
+        assert_eq!(Affine3::default(), Affine3::identity());
+        assert_eq!(Isometry2::default(), Isometry2::identity());
+        assert_eq!(Similarity2::default(), Similarity2::identity());
+        assert_eq!(Affine2::default(), Affine2::identity());
+    }
+
+    #[test]
+    fn repeated_composition_stays_rigid() {
+        let step = isometry3();
+        let mut total = Isometry3::identity();
+        for _ in 0..10_000 {
+            total = step * total;
+        }
+        assert!((total.rotation.length() - 1.0).abs() < 1e-6);
+        let point = Vec3 { x: 2.0, y: 1.0, z: -1.0 };
+        let moved = total.transform_point(&point) - total.transform_point(&Vec3::new());
+        assert!((moved.length() - point.length()).abs() < 1e-3);
+        assert_vec3_near(&((total * total.inverse()).rotation * point), &point);
+    }
+}
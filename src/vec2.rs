@@ -2,13 +2,14 @@
 
 use std::ops;
 
-use super::Vec3;
+use super::{Scalar, Vec3};
 
 /// A 2D Vector with elements x and y
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Vec2 {
-    pub x: f32,
-    pub y: f32,
+#[repr(C)]
+pub struct Vec2<T = f32> {
+    pub x: T,
+    pub y: T,
 }
 
 impl Vec2 {
@@ -22,7 +23,9 @@ impl Vec2 {
     pub fn new() -> Vec2 {
         Vec2 { x: 0.0, y: 0.0 }
     }
+}
 
+impl<T: Scalar> Vec2<T> {
     /// Returns a new Vec2 using the given values for x and y.
     /// 
     /// Example:
@@ -32,7 +35,7 @@ impl Vec2 {
     /// let y: f32 = 2.0;
     /// let vec2 = Vec2::new_from_values(&x, &y);
     /// ```
-    pub fn new_from_values(x: &f32, y: &f32) -> Vec2 {
+    pub fn new_from_values(x: &T, y: &T) -> Vec2<T> {
         Vec2 { x: *x, y: *y }
     }
 
@@ -45,7 +48,7 @@ impl Vec2 {
     /// let input = [ 1.0, 2.0 ];
     /// let vec2 = Vec2::new_from_array(&input);
     /// ```
-    pub fn new_from_array(input: &[f32; 2]) -> Vec2 {
+    pub fn new_from_array(input: &[T; 2]) -> Vec2<T> {
         Vec2 { x: input[0], y: input[1] }
     }
 
@@ -57,10 +60,22 @@ impl Vec2 {
     /// # let some_vec2 = Vec2::new();
     /// let array = some_vec2.to_array();
     /// ```
-    pub fn to_array(&self) -> [f32; 2] {
+    pub fn to_array(&self) -> [T; 2] {
         [self.x, self.y]
     }
 
+    /// Returns a Vec3 with the Vec2's x and y and the given z.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Vec2, Vec3};
+    /// let vec2 = Vec2 { x: 1.0, y: 2.0 };
+    /// assert_eq!(vec2.extend(3.0), Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+    /// ```
+    pub fn extend(&self, z: T) -> Vec3<T> {
+        Vec3 { x: self.x, y: self.y, z }
+    }
+
     /// Returns the cross product of 2 Vec2s as if they were Vec3s with a z
     /// component of 0.
     /// 
@@ -77,25 +92,25 @@ impl Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// let vec3: Vec3 = some_vec2.cross_product(&some_other_vec2);
     /// ```
-    pub fn cross_product(&self, other_vec2: &Vec2) -> Vec3 {
+    pub fn cross_product(&self, other_vec2: &Vec2<T>) -> Vec3<T> {
         Vec3 {
             // Since the formula would then be multiplying 0s for both the x 
-            // and y, we can short circuit this by just passing a 0.0 to
+            // and y, we can short circuit this by just passing a 0 to
             // x and y instead.
-            x: 0.0,
-            y: 0.0,
+            x: T::zero(),
+            y: T::zero(),
             z: (self.x * other_vec2.y) - (self.y * other_vec2.x),
         }
     }
 
     /// Returns the length of the Vec2.
-    pub fn length(&self) -> f32 {
-        f32::sqrt(self.x.powi(2) + self.y.powi(2))
+    pub fn length(&self) -> T {
+        T::sqrt((self.x * self.x) + (self.y * self.y))
     }
 }
 
-impl ops::Add<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Add<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
     /// Adds one Vec2 to another Vec2 and returns a new Vec2.
     /// 
@@ -106,7 +121,7 @@ impl ops::Add<Vec2> for Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// let vec2 = some_vec2 + some_other_vec2;
     /// ```
-    fn add(self, other_vec2: Vec2) -> Vec2 {
+    fn add(self, other_vec2: Vec2<T>) -> Vec2<T> {
         Vec2 {
             x: self.x + other_vec2.x,
             y: self.y + other_vec2.y,
@@ -114,7 +129,7 @@ impl ops::Add<Vec2> for Vec2 {
     }
 }
 
-impl ops::AddAssign for Vec2 {
+impl<T: Scalar> ops::AddAssign for Vec2<T> {
     /// Adds one Vec2 to another Vec2 and re-assigns the first Vec2 to the
     /// new Vec2.
     /// 
@@ -125,7 +140,7 @@ impl ops::AddAssign for Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// some_vec2 += some_other_vec2;
     /// ```
-    fn add_assign(&mut self, other_vec2: Vec2) {
+    fn add_assign(&mut self, other_vec2: Vec2<T>) {
         *self = Vec2 {
             x: self.x + other_vec2.x,
             y: self.y + other_vec2.y,
@@ -133,8 +148,8 @@ impl ops::AddAssign for Vec2 {
     }
 }
 
-impl ops::Mul<Vec2> for f32 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Mul<Vec2<T>> for f32 {
+    type Output = Vec2<T>;
 
     /// Multiplies a scalar value by a Vec2 and returns a Vec2.
     /// 
@@ -145,16 +160,17 @@ impl ops::Mul<Vec2> for f32 {
     /// # let some_vec2 = Vec2::new();
     /// let scaled_vec2: Vec2 = some_scalar * some_vec2;
     /// ```
-    fn mul(self, other_vec2: Vec2) -> Vec2 {
+    fn mul(self, other_vec2: Vec2<T>) -> Vec2<T> {
+        let scalar = T::from_f32(self);
         Vec2 {
-            x: self * other_vec2.x,
-            y: self * other_vec2.y,
+            x: scalar * other_vec2.x,
+            y: scalar * other_vec2.y,
         }
     }
 }
 
-impl ops::Mul<Vec2> for Vec2 {
-    type Output = f32;
+impl<T: Scalar> ops::Mul<Vec2<T>> for Vec2<T> {
+    type Output = T;
 
     /// Returns the dot product of 2 Vec2s, which is a scalar floating point.
     /// 
@@ -165,13 +181,13 @@ impl ops::Mul<Vec2> for Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// let scalar: f32 = some_vec2 * some_other_vec2;
     /// ```
-    fn mul(self, other_vec2: Vec2) -> f32 {
+    fn mul(self, other_vec2: Vec2<T>) -> T {
         (self.x * other_vec2.x) + (self.y * other_vec2.y)
     }
 }
 
-impl ops::Neg for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Neg for Vec2<T> {
+    type Output = Vec2<T>;
 
     /// Negates the values of Vec2, which in turn negates the Vec2
     /// 
@@ -181,13 +197,13 @@ impl ops::Neg for Vec2 {
     /// # let some_vec2 = Vec2::new();
     /// let negated_vec2 = -some_vec2;
     /// ```
-    fn neg(self) -> Vec2 {
+    fn neg(self) -> Vec2<T> {
         Vec2 { x: -self.x, y: -self.y }
     }
 }
 
-impl ops::Sub<Vec2> for Vec2 {
-    type Output = Vec2;
+impl<T: Scalar> ops::Sub<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
 
     /// Subtracts one Vec2 from another Vec2 and returns a new Vec2.
     /// 
@@ -198,7 +214,7 @@ impl ops::Sub<Vec2> for Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// let vec2 = some_vec2 - some_other_vec2;
     /// ```
-    fn sub(self, other_vec2: Vec2) -> Vec2 {
+    fn sub(self, other_vec2: Vec2<T>) -> Vec2<T> {
         Vec2 {
             x: self.x - other_vec2.x,
             y: self.y - other_vec2.y,
@@ -206,7 +222,7 @@ impl ops::Sub<Vec2> for Vec2 {
     }
 }
 
-impl ops::SubAssign for Vec2 {
+impl<T: Scalar> ops::SubAssign for Vec2<T> {
     /// Subtracts one Vec2 from another Vec2 and re-assigns the first Vec2 to
     /// the new Vec2.
     /// 
@@ -217,7 +233,7 @@ impl ops::SubAssign for Vec2 {
     /// # let some_other_vec2 = Vec2::new();
     /// some_vec2 -= some_other_vec2;
     /// ```
-    fn sub_assign(&mut self, other_vec2: Vec2) {
+    fn sub_assign(&mut self, other_vec2: Vec2<T>) {
         *self = Vec2 {
             x: self.x - other_vec2.x,
             y: self.y - other_vec2.y,
@@ -269,6 +285,12 @@ mod tests {
         assert_eq!(vec2.length(), 5.0);
     }
 
+    #[test]
+    fn extend_vec2() {
+        let vec2 = Vec2 { x: 1.0, y: 2.0 };
+        assert_eq!(vec2.extend(-1.0), Vec3 { x: 1.0, y: 2.0, z: -1.0 });
+    }
+
     #[test]
     fn add_2_vec2s_together() {
         let v1 = Vec2 { x: 1.0, y: 0.0 };
@@ -2,12 +2,15 @@
 
 use std::ops;
 
+use super::{Mat3, Scalar, Vec4};
+
 /// A 3D vector with elements x, y, and z
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Vec3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[repr(C)]
+pub struct Vec3<T = f32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
 impl Vec3 {
@@ -21,7 +24,9 @@ impl Vec3 {
     pub fn new() -> Vec3 {
         Vec3 { x: 0.0, y: 0.0, z: 0.0 }
     }
+}
 
+impl<T: Scalar> Vec3<T> {
     /// Returns a new Vec3 using the given values for x, y, and z.
     ///
     /// Example:
@@ -32,7 +37,7 @@ impl Vec3 {
     /// let z: f32 = 3.0;
     /// let vec3 = Vec3::new_from_values(&x, &y, &z);
     /// ```
-    pub fn new_from_values(x: &f32, y: &f32, z: &f32) -> Vec3 {
+    pub fn new_from_values(x: &T, y: &T, z: &T) -> Vec3<T> {
         Vec3 { x: *x, y: *y, z: *z }
     }
 
@@ -45,7 +50,7 @@ impl Vec3 {
     /// let input = [ 1.0, 2.0, 3.0 ];
     /// let vec3 = Vec3::new_from_array(&input);
     /// ```
-    pub fn new_from_array(input: &[f32; 3]) -> Vec3 {
+    pub fn new_from_array(input: &[T; 3]) -> Vec3<T> {
         Vec3 { x: input[0], y: input[1], z: input[2] }
     }
 
@@ -58,10 +63,54 @@ impl Vec3 {
     /// # let some_vec3 = Vec3::new();
     /// let array = some_vec3.to_array();
     /// ```
-    pub fn to_array(&self) -> [f32; 3] {
+    pub fn to_array(&self) -> [T; 3] {
         [self.x, self.y, self.z]
     }
 
+    /// Returns the skew-symmetric Mat3 `[v]×` for which `[v]× * u` equals
+    /// `v.cross_product(&u)`, also known as the hat operator.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::Vec3;
+    /// let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+    /// let u = Vec3 { x: -1.0, y: 0.5, z: 2.0 };
+    /// assert_eq!(v.to_skew_matrix() * u, v.cross_product(&u));
+    /// ```
+    pub fn to_skew_matrix(&self) -> Mat3<T> {
+        let zero = T::zero();
+        Mat3 {
+            a:    zero, b: -self.z, c:  self.y,
+            d:  self.z, e:    zero, f: -self.x,
+            g: -self.y, h:  self.x, i:    zero
+        }
+    }
+
+    /// Returns a Vec4 with the Vec3's x, y and z and the given w.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::{Vec3, Vec4};
+    /// let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+    /// assert_eq!(vec3.extend(1.0), Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+    /// ```
+    pub fn extend(&self, w: T) -> Vec4<T> {
+        Vec4 { x: self.x, y: self.y, z: self.z, w }
+    }
+
+    /// Returns a Vec3 pointing in the same direction with a length of 1.
+    ///
+    /// Example:
+    /// ```
+    /// # use matriarch::Vec3;
+    /// let vec3 = Vec3 { x: 0.0, y: 3.0, z: 0.0 };
+    /// assert_eq!(vec3.normalize(), Vec3 { x: 0.0, y: 1.0, z: 0.0 });
+    /// ```
+    pub fn normalize(&self) -> Vec3<T> {
+        let inverse_length = self.length().recip();
+        Vec3 { x: self.x * inverse_length, y: self.y * inverse_length, z: self.z * inverse_length }
+    }
+
     /// Returns the cross product of 2 Vec3s as a Vec3.
     ///
     /// The cross product of 2 Vec3s is defined as a Vec3 that is perpendicular
@@ -74,7 +123,7 @@ impl Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// let cross_product = some_vec3.cross_product(&some_other_vec3);
     /// ```
-    pub fn cross_product(&self, other_vec3: &Vec3) -> Vec3 {
+    pub fn cross_product(&self, other_vec3: &Vec3<T>) -> Vec3<T> {
         Vec3 {
             x: (self.y * other_vec3.z) - (self.z * other_vec3.y),
             y: (self.z * other_vec3.x) - (self.x * other_vec3.z),
@@ -83,13 +132,13 @@ impl Vec3 {
     }
 
     /// Returns the length of the Vec3.
-    pub fn length(&self) -> f32 {
-        f32::sqrt(self.x.powi(2) + self.y.powi(2) + self.z.powi(2))
+    pub fn length(&self) -> T {
+        T::sqrt((self.x * self.x) + (self.y * self.y) + (self.z * self.z))
     }
 }
 
-impl ops::Add<Vec3> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Add<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
     /// Adds one Vec3 to another Vec3 and returns a new Vec3.
     ///
@@ -100,7 +149,7 @@ impl ops::Add<Vec3> for Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// let vec3 = some_vec3 + some_other_vec3;
     /// ```
-    fn add(self, other_vec3: Vec3) -> Vec3 {
+    fn add(self, other_vec3: Vec3<T>) -> Vec3<T> {
         Vec3 {
             x: self.x + other_vec3.x,
             y: self.y + other_vec3.y,
@@ -109,7 +158,7 @@ impl ops::Add<Vec3> for Vec3 {
     }
 }
 
-impl ops::AddAssign for Vec3 {
+impl<T: Scalar> ops::AddAssign for Vec3<T> {
     /// Adds one Vec3 to another Vec3 and re-assigns the first Vec3 to the new
     /// Vec3
     ///
@@ -120,7 +169,7 @@ impl ops::AddAssign for Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// some_vec3 += some_other_vec3;
     /// ```
-    fn add_assign(&mut self, other_vec3: Vec3) {
+    fn add_assign(&mut self, other_vec3: Vec3<T>) {
         *self = Vec3 {
             x: self.x + other_vec3.x,
             y: self.y + other_vec3.y,
@@ -129,8 +178,8 @@ impl ops::AddAssign for Vec3 {
     }
 }
 
-impl ops::Mul<Vec3> for f32 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Mul<Vec3<T>> for f32 {
+    type Output = Vec3<T>;
 
     /// Multiplies a scalar value by a Vec3 and returns a Vec3.
     ///
@@ -141,17 +190,18 @@ impl ops::Mul<Vec3> for f32 {
     /// # let some_vec3 = Vec3::new();
     /// let scaled_vec3: Vec3 = some_scalar * some_vec3;
     /// ```
-    fn mul(self, other_vec3: Vec3) -> Vec3 {
+    fn mul(self, other_vec3: Vec3<T>) -> Vec3<T> {
+        let scalar = T::from_f32(self);
         Vec3 {
-            x: self * other_vec3.x,
-            y: self * other_vec3.y,
-            z: self * other_vec3.z,
+            x: scalar * other_vec3.x,
+            y: scalar * other_vec3.y,
+            z: scalar * other_vec3.z,
         }
     }
 }
 
-impl ops::Mul<Vec3> for Vec3 {
-    type Output = f32;
+impl<T: Scalar> ops::Mul<Vec3<T>> for Vec3<T> {
+    type Output = T;
 
     /// Retuns the dot product of 2 Vec3s, which is a scalar floating point.
     ///
@@ -162,22 +212,22 @@ impl ops::Mul<Vec3> for Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// let scalar: f32 = some_vec3 * some_other_vec3;
     /// ```
-    fn mul(self, other_vec3: Vec3) -> f32 {
+    fn mul(self, other_vec3: Vec3<T>) -> T {
         (self.x * other_vec3.x) + (self.y * other_vec3.y) + (self.z * other_vec3.z)
     }
 }
 
-impl ops::Neg for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Neg for Vec3<T> {
+    type Output = Vec3<T>;
 
     /// Negates the values of Vec3, which in turn negates the Vec3.
-    fn neg(self) -> Vec3 {
+    fn neg(self) -> Vec3<T> {
         Vec3 { x: -self.x, y: -self.y, z: -self.z }
     }
 }
 
-impl ops::Sub<Vec3> for Vec3 {
-    type Output = Vec3;
+impl<T: Scalar> ops::Sub<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
 
     /// Subtracts one Vec3 from another Vec3 and returns a new Vec3.
     ///
@@ -188,7 +238,7 @@ impl ops::Sub<Vec3> for Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// let vec3 = some_vec3 - some_other_vec3;
     /// ```
-    fn sub(self, other_vec3: Vec3) -> Vec3 {
+    fn sub(self, other_vec3: Vec3<T>) -> Vec3<T> {
         Vec3 {
             x: self.x - other_vec3.x,
             y: self.y - other_vec3.y,
@@ -197,7 +247,7 @@ impl ops::Sub<Vec3> for Vec3 {
     }
 }
 
-impl ops::SubAssign for Vec3 {
+impl<T: Scalar> ops::SubAssign for Vec3<T> {
     /// Subtracts one Vec3 from another Vec3 and re-assigns the first Vec3 to
     /// the new Vec3.
     ///
@@ -208,7 +258,7 @@ impl ops::SubAssign for Vec3 {
     /// # let some_other_vec3 = Vec3::new();
     /// some_vec3 -= some_other_vec3;
     /// ```
-    fn sub_assign(&mut self, other_vec3: Vec3) {
+    fn sub_assign(&mut self, other_vec3: Vec3<T>) {
         *self = Vec3 {
             x: self.x - other_vec3.x,
             y: self.y - other_vec3.y,
@@ -219,7 +269,7 @@ impl ops::SubAssign for Vec3 {
 
 #[cfg(test)]
 mod tests {
-    use super::Vec3;
+    use super::{Mat3, Vec3, Vec4};
 
     #[test]
     fn create_new_vec3() {
@@ -256,12 +306,32 @@ mod tests {
         assert_eq!(v1.cross_product(&v2), Vec3 { x: 18.0, y: -8.0, z: -10.5 })
     }
 
+    #[test]
+    fn get_skew_matrix() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        let skew = vec3.to_skew_matrix();
+        assert_eq!(skew, Mat3::new_from_array(&[0.0, -3.0, 2.0, 3.0, 0.0, -1.0, -2.0, 1.0, 0.0]));
+        assert_eq!(skew.vee(), vec3);
+    }
+
     #[test]
     fn get_vec3_length() {
         let vec3 = Vec3 { x: 2.0, y: 3.0, z: 6.0 };
         assert_eq!(vec3.length(), 7.0);
     }
 
+    #[test]
+    fn extend_vec3() {
+        let vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+        assert_eq!(vec3.extend(0.0), Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 0.0 });
+    }
+
+    #[test]
+    fn normalize_vec3() {
+        let vec3 = Vec3 { x: 2.0, y: 0.0, z: 0.0 };
+        assert_eq!(vec3.normalize(), Vec3 { x: 1.0, y: 0.0, z: 0.0 });
+    }
+
     #[test]
     fn add_2_vec3s_together() {
         let v1 = Vec3 { x: 1.0, y: 0.0, z: 2.0 };
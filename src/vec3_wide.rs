@@ -0,0 +1,306 @@
+//! Implementation of structure-of-arrays Vec3s, which hold several Vec3s in
+//! separate x, y and z lanes so that one operation is applied to all of them
+//! at once.
+//!
+//! Every operation is a plain loop over the lanes, which the compiler turns
+//! into SIMD instructions. Algorithms written against Vec3 port across by
+//! swapping in the wide type and treating scalar results as arrays of lanes.
+
+use std::ops;
+
+use super::Vec3;
+
+macro_rules! vec3_wide {
+    ($(#[$doc:meta])* $name:ident, $lanes:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $name {
+            pub x: [f32; $lanes],
+            pub y: [f32; $lanes],
+            pub z: [f32; $lanes],
+        }
+
+        impl $name {
+            /// Returns a new value with every lane at [0, 0, 0].
+            pub fn new() -> $name {
+                $name { x: [0.0; $lanes], y: [0.0; $lanes], z: [0.0; $lanes] }
+            }
+
+            /// Returns a new value with every lane set to the given Vec3.
+            pub fn splat(vec3: &Vec3) -> $name {
+                $name { x: [vec3.x; $lanes], y: [vec3.y; $lanes], z: [vec3.z; $lanes] }
+            }
+
+            /// Returns a new value with one lane for each of the given Vec3s.
+            pub fn new_from_array(input: &[Vec3; $lanes]) -> $name {
+                $name::gather(input)
+            }
+
+            /// Loads the first lanes' worth of Vec3s from the slice, filling
+            /// any lanes past the end of the slice with zeroes.
+            ///
+            /// Example:
+            /// ```
+            /// # use matriarch::{Vec3, Vec3x4};
+            /// let points = vec![Vec3 { x: 1.0, y: 2.0, z: 3.0 }; 10];
+            /// for chunk in points.chunks(4) {
+            ///     let wide = Vec3x4::gather(chunk);
+            ///     let lengths = wide.length();
+            /// }
+            /// ```
+            pub fn gather(input: &[Vec3]) -> $name {
+                let mut result = $name::new();
+                for (lane, vec3) in input.iter().take($lanes).enumerate() {
+                    result.x[lane] = vec3.x;
+                    result.y[lane] = vec3.y;
+                    result.z[lane] = vec3.z;
+                }
+                result
+            }
+
+            /// Writes the lanes out to the slice, stopping early if the slice
+            /// is shorter than the number of lanes.
+            pub fn scatter(&self, output: &mut [Vec3]) {
+                for (lane, vec3) in output.iter_mut().take($lanes).enumerate() {
+                    *vec3 = self.lane(lane);
+                }
+            }
+
+            /// Returns the Vec3s in each lane as an array.
+            pub fn to_array(&self) -> [Vec3; $lanes] {
+                let mut result = [Vec3::new(); $lanes];
+                self.scatter(&mut result);
+                result
+            }
+
+            /// Returns the Vec3 in the given lane.
+            ///
+            /// Panics if `lane` is out of range.
+            pub fn lane(&self, lane: usize) -> Vec3 {
+                Vec3 { x: self.x[lane], y: self.y[lane], z: self.z[lane] }
+            }
+
+            /// Replaces the Vec3 in the given lane.
+            ///
+            /// Panics if `lane` is out of range.
+            pub fn set_lane(&mut self, lane: usize, vec3: &Vec3) {
+                self.x[lane] = vec3.x;
+                self.y[lane] = vec3.y;
+                self.z[lane] = vec3.z;
+            }
+
+            /// Returns the cross product of each pair of lanes.
+            pub fn cross_product(&self, other: &$name) -> $name {
+                let mut result = $name::new();
+                for lane in 0..$lanes {
+                    result.x[lane] = (self.y[lane] * other.z[lane]) - (self.z[lane] * other.y[lane]);
+                    result.y[lane] = (self.z[lane] * other.x[lane]) - (self.x[lane] * other.z[lane]);
+                    result.z[lane] = (self.x[lane] * other.y[lane]) - (self.y[lane] * other.x[lane]);
+                }
+                result
+            }
+
+            /// Returns the length of each lane.
+            pub fn length(&self) -> [f32; $lanes] {
+                let mut result = *self * *self;
+                for length in result.iter_mut() {
+                    *length = length.sqrt();
+                }
+                result
+            }
+
+            /// Returns each lane scaled to a length of 1.
+            pub fn normalize(&self) -> $name {
+                let mut inverse_length = self.length();
+                for length in inverse_length.iter_mut() {
+                    *length = 1.0 / *length;
+                }
+                inverse_length * *self
+            }
+        }
+
+        impl ops::Add<$name> for $name {
+            type Output = $name;
+
+            /// Adds each pair of lanes together.
+            fn add(self, other: $name) -> $name {
+                let mut result = self;
+                result += other;
+                result
+            }
+        }
+
+        impl ops::AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                for lane in 0..$lanes {
+                    self.x[lane] += other.x[lane];
+                    self.y[lane] += other.y[lane];
+                    self.z[lane] += other.z[lane];
+                }
+            }
+        }
+
+        impl ops::Sub<$name> for $name {
+            type Output = $name;
+
+            /// Subtracts each pair of lanes.
+            fn sub(self, other: $name) -> $name {
+                let mut result = self;
+                result -= other;
+                result
+            }
+        }
+
+        impl ops::SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                for lane in 0..$lanes {
+                    self.x[lane] -= other.x[lane];
+                    self.y[lane] -= other.y[lane];
+                    self.z[lane] -= other.z[lane];
+                }
+            }
+        }
+
+        impl ops::Mul<$name> for f32 {
+            type Output = $name;
+
+            /// Multiplies every lane by the same scalar.
+            fn mul(self, other: $name) -> $name {
+                [self; $lanes] * other
+            }
+        }
+
+        impl ops::Mul<$name> for [f32; $lanes] {
+            type Output = $name;
+
+            /// Multiplies each lane by its own scalar.
+            fn mul(self, other: $name) -> $name {
+                let mut result = other;
+                for lane in 0..$lanes {
+                    result.x[lane] *= self[lane];
+                    result.y[lane] *= self[lane];
+                    result.z[lane] *= self[lane];
+                }
+                result
+            }
+        }
+
+        impl ops::Mul<$name> for $name {
+            type Output = [f32; $lanes];
+
+            /// Returns the dot product of each pair of lanes.
+            fn mul(self, other: $name) -> [f32; $lanes] {
+                let mut result = [0.0; $lanes];
+                for (lane, dot) in result.iter_mut().enumerate() {
+                    *dot = (self.x[lane] * other.x[lane])
+                        + (self.y[lane] * other.y[lane])
+                        + (self.z[lane] * other.z[lane]);
+                }
+                result
+            }
+        }
+
+        impl ops::Neg for $name {
+            type Output = $name;
+
+            /// Negates every lane.
+            fn neg(self) -> $name {
+                -1.0 * self
+            }
+        }
+    };
+}
+
+vec3_wide!(
+    /// 4 Vec3s stored as separate arrays of x, y and z components.
+    Vec3x4,
+    4
+);
+vec3_wide!(
+    /// 8 Vec3s stored as separate arrays of x, y and z components.
+    Vec3x8,
+    8
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{Vec3, Vec3x4, Vec3x8};
+
+    fn points(offset: f32) -> Vec<Vec3> {
+        (0..8)
+            .map(|index| {
+                let t = index as f32 + offset;
+                Vec3 { x: t, y: 2.0 - t, z: 0.5 * t * t }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gather_and_scatter() {
+        let input = points(0.0);
+        let wide = Vec3x8::gather(&input);
+        assert_eq!(wide.lane(3), input[3]);
+        let mut output = vec![Vec3::new(); 8];
+        wide.scatter(&mut output);
+        assert_eq!(output, input);
+
+        // Missing lanes are filled with zeroes and ignored when scattering.
+        let wide = Vec3x4::gather(&input[..2]);
+        assert_eq!(wide.lane(2), Vec3::new());
+        let mut output = vec![Vec3::new(); 1];
+        wide.scatter(&mut output);
+        assert_eq!(output[0], input[0]);
+    }
+
+    #[test]
+    fn set_lane() {
+        let mut wide = Vec3x4::splat(&Vec3 { x: 1.0, y: 1.0, z: 1.0 });
+        wide.set_lane(2, &Vec3 { x: 3.0, y: 2.0, z: 1.0 });
+        assert_eq!(wide.x, [1.0, 1.0, 3.0, 1.0]);
+        assert_eq!(wide.y, [1.0, 1.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn matches_vec3_operations() {
+        let a = points(0.0);
+        let b = points(1.5);
+        let wide_a = Vec3x8::gather(&a);
+        let wide_b = Vec3x8::gather(&b);
+
+        let sum = (wide_a + wide_b).to_array();
+        let difference = (wide_a - wide_b).to_array();
+        let scaled = (2.0 * wide_a).to_array();
+        let negated = (-wide_a).to_array();
+        let cross = wide_a.cross_product(&wide_b).to_array();
+        let dot = wide_a * wide_b;
+        let length = wide_b.length();
+        for lane in 0..8 {
+            assert_eq!(sum[lane], a[lane] + b[lane]);
+            assert_eq!(difference[lane], a[lane] - b[lane]);
+            assert_eq!(scaled[lane], 2.0 * a[lane]);
+            assert_eq!(negated[lane], -a[lane]);
+            assert_eq!(cross[lane], a[lane].cross_product(&b[lane]));
+            assert_eq!(dot[lane], a[lane] * b[lane]);
+            assert_eq!(length[lane], b[lane].length());
+        }
+    }
+
+    #[test]
+    fn normalize_lanes() {
+        let wide = Vec3x4::gather(&points(1.0)[..4]).normalize();
+        for length in wide.length().iter() {
+            assert!((length - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn assign_operators() {
+        let mut wide = Vec3x4::splat(&Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+        wide += Vec3x4::splat(&Vec3 { x: 1.0, y: 1.0, z: 1.0 });
+        wide -= Vec3x4::splat(&Vec3 { x: 0.5, y: 0.5, z: 0.5 });
+        assert_eq!(wide, Vec3x4::splat(&Vec3 { x: 1.5, y: 2.5, z: 3.5 }));
+        let per_lane = [1.0, 2.0, 3.0, 4.0] * wide;
+        assert_eq!(per_lane.lane(3), Vec3 { x: 6.0, y: 10.0, z: 14.0 });
+    }
+}
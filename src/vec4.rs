@@ -2,13 +2,16 @@
 
 use std::ops;
 
+use super::{Scalar, Vec3};
+
 /// A 4D Vector with elements x, y, z, and w
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct Vec4 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+#[repr(C)]
+pub struct Vec4<T = f32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
 }
 
 impl Vec4 {
@@ -16,15 +19,17 @@ impl Vec4 {
     pub fn new() -> Vec4 {
         Vec4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 }
     }
+}
 
+impl<T: Scalar> Vec4<T> {
     /// Returns a new Vec4 using the given values for x, y, z, and w.
-    pub fn new_from_values(x: &f32, y: &f32, z: &f32, w: &f32) -> Vec4 {
+    pub fn new_from_values(x: &T, y: &T, z: &T, w: &T) -> Vec4<T> {
         Vec4 { x: *x, y: *y, z: *z, w: *w }
     }
 
     /// Returns a new Vec4 using the 0, 1, 2, and 3 indices of the given array,
     /// where [0] -> x, [1] -> y, [2] -> z, and [3] -> w.
-    pub fn new_from_array(input: &[f32; 4]) -> Vec4 {
+    pub fn new_from_array(input: &[T; 4]) -> Vec4<T> {
         Vec4 {
             x: input[0],
             y: input[1],
@@ -35,21 +40,26 @@ impl Vec4 {
 
     /// Returns an array of the Vec4's x, y, z, and w values where x -> [0],
     /// y -> [1], z -> [2], and w -> [3].
-    pub fn to_array(&self) -> [f32; 4] {
+    pub fn to_array(&self) -> [T; 4] {
         [self.x, self.y, self.z, self.w]
     }
 
+    /// Returns a Vec3 of the Vec4's x, y and z, dropping w.
+    pub fn truncate(&self) -> Vec3<T> {
+        Vec3 { x: self.x, y: self.y, z: self.z }
+    }
+
     /// Returns the length of the Vec4.
-    pub fn length(&self) -> f32 {
-        f32::sqrt(self.x.powi(2) + self.y.powi(2) + self.z.powi(2) + self.w.powi(2))
+    pub fn length(&self) -> T {
+        T::sqrt((self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w))
     }
 }
 
-impl ops::Add<Vec4> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Add<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
 
     /// Adds one Vec4 to another Vec4 and returns a new Vec4.
-    fn add(self, other_vec4: Vec4) -> Vec4 {
+    fn add(self, other_vec4: Vec4<T>) -> Vec4<T> {
         Vec4 {
             x: self.x + other_vec4.x,
             y: self.y + other_vec4.y,
@@ -59,10 +69,10 @@ impl ops::Add<Vec4> for Vec4 {
     }
 }
 
-impl ops::AddAssign for Vec4 {
+impl<T: Scalar> ops::AddAssign for Vec4<T> {
     /// Adds one Vec4 to another Vec4 and re-assigns the first Vec4 to the new
     /// Vec4.
-    fn add_assign(&mut self, other_vec4: Vec4) {
+    fn add_assign(&mut self, other_vec4: Vec4<T>) {
         *self = Vec4 {
             x: self.x + other_vec4.x,
             y: self.y + other_vec4.y,
@@ -72,44 +82,45 @@ impl ops::AddAssign for Vec4 {
     }
 }
 
-impl ops::Mul<Vec4> for f32 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Mul<Vec4<T>> for f32 {
+    type Output = Vec4<T>;
 
     /// Multiplise a scalar value by a Vec4 and returns a Vec4.
-    fn mul(self, other_vec4: Vec4) -> Vec4 {
+    fn mul(self, other_vec4: Vec4<T>) -> Vec4<T> {
+        let scalar = T::from_f32(self);
         Vec4 {
-            x: self * other_vec4.x,
-            y: self * other_vec4.y,
-            z: self * other_vec4.z,
-            w: self * other_vec4.w,
+            x: scalar * other_vec4.x,
+            y: scalar * other_vec4.y,
+            z: scalar * other_vec4.z,
+            w: scalar * other_vec4.w,
         }
     }
 }
 
-impl ops::Mul<Vec4> for Vec4 {
-    type Output = f32;
+impl<T: Scalar> ops::Mul<Vec4<T>> for Vec4<T> {
+    type Output = T;
 
     /// Returns the dot product of 2 Vec4s, which is a scalar floating point.
-    fn mul(self, other_vec4: Vec4) -> f32 {
+    fn mul(self, other_vec4: Vec4<T>) -> T {
         (self.x * other_vec4.x) + (self.y * other_vec4.y)
         + (self.z * other_vec4.z) + (self.w * other_vec4.w)
     }
 }
 
-impl ops::Neg for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Neg for Vec4<T> {
+    type Output = Vec4<T>;
 
     /// Negates the values of Vec4, which in turn negates the Vec4.
-    fn neg(self) -> Vec4 {
+    fn neg(self) -> Vec4<T> {
         Vec4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
     }
 }
 
-impl ops::Sub<Vec4> for Vec4 {
-    type Output = Vec4;
+impl<T: Scalar> ops::Sub<Vec4<T>> for Vec4<T> {
+    type Output = Vec4<T>;
 
     /// Subtracts one Vec4 from another Vec4 and returns a new Vec4.
-    fn sub(self, other_vec4: Vec4) -> Vec4 {
+    fn sub(self, other_vec4: Vec4<T>) -> Vec4<T> {
         Vec4 {
             x: self.x - other_vec4.x,
             y: self.y - other_vec4.y,
@@ -119,10 +130,10 @@ impl ops::Sub<Vec4> for Vec4 {
     }
 }
 
-impl ops::SubAssign for Vec4 {
+impl<T: Scalar> ops::SubAssign for Vec4<T> {
     /// Subtracts one Vec4 from another Vec4 and re-assigns the first Vec4 to
     /// the new Vec4.
-    fn sub_assign(&mut self, other_vec4: Vec4) {
+    fn sub_assign(&mut self, other_vec4: Vec4<T>) {
         *self = Vec4 {
             x: self.x - other_vec4.x,
             y: self.y - other_vec4.y,
@@ -134,7 +145,7 @@ impl ops::SubAssign for Vec4 {
 
 #[cfg(test)]
 mod tests {
-    use super::Vec4;
+    use super::{Vec3, Vec4};
 
     #[test]
     fn create_new_vec4() {
@@ -171,6 +182,12 @@ mod tests {
         assert_eq!(vec4.length(), 10.0);
     }
 
+    #[test]
+    fn truncate_vec4() {
+        let vec4 = Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(vec4.truncate(), Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+    }
+
     #[test]
     fn add_assign_2_vec4s() {
         let mut v1 = Vec4 { x: 1.0, y: 0.0, z: 0.5, w: 0.25 };